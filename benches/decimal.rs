@@ -1,7 +1,10 @@
 #![feature(test)]
 extern crate test;
 
-use decstr::Bitstring;
+use decstr::{
+    Bitstring,
+    Bitstring128,
+};
 
 fn finite_cases() -> &'static [&'static str] {
     &[
@@ -19,6 +22,79 @@ fn finite_cases() -> &'static [&'static str] {
     ]
 }
 
+fn short_finite_cases() -> &'static [&'static str] {
+    &[
+        "1", "-5", "42", "0.5", "-1.25", "9.99", "100", "0.01", "12.5", "-0",
+    ]
+}
+
+fn integer_cases() -> &'static [&'static str] {
+    &[
+        "123",
+        "-0",
+        "5334665",
+        "-2432",
+        "1",
+        "-47576535342",
+        "-673873458673",
+        "0",
+        "23265473443",
+        "673873458673",
+        "100000000000000000",
+    ]
+}
+
+#[bench]
+fn decimal_from_str_integer(b: &mut test::Bencher) {
+    b.iter(|| {
+        for case in integer_cases() {
+            test::black_box(Bitstring::try_parse_str(case).unwrap());
+        }
+    });
+}
+
+fn i128_cases() -> &'static [i128] {
+    &[
+        0,
+        1,
+        -2432,
+        673873458673,
+        -9223372036854775808,
+        18446744073709551616,
+        -1000000000000000000000000000000001,
+        9999999999999999999999999999999999,
+    ]
+}
+
+#[bench]
+fn decimal_from_i128(b: &mut test::Bencher) {
+    b.iter(|| {
+        for case in i128_cases() {
+            test::black_box(Bitstring128::from_i128(*case));
+        }
+    });
+}
+
+#[bench]
+fn decimal_from_i128_via_str(b: &mut test::Bencher) {
+    let mut buf = itoa::Buffer::new();
+
+    b.iter(|| {
+        for case in i128_cases() {
+            test::black_box(Bitstring128::try_parse_str(buf.format(*case)).unwrap());
+        }
+    });
+}
+
+#[bench]
+fn decimal_from_str_short_finite(b: &mut test::Bencher) {
+    b.iter(|| {
+        for case in short_finite_cases() {
+            test::black_box(Bitstring::try_parse_str(case).unwrap());
+        }
+    });
+}
+
 #[bench]
 fn decimal_from_str_finite(b: &mut test::Bencher) {
     b.iter(|| {
@@ -1,3 +1,4 @@
+#[cfg(feature = "float")]
 fn main() -> Result<(), decstr::Error> {
     let decimal = decstr::Bitstring::from(123.44);
 
@@ -12,3 +13,6 @@ fn main() -> Result<(), decstr::Error> {
 
     Ok(())
 }
+
+#[cfg(not(feature = "float"))]
+fn main() {}
@@ -8,8 +8,11 @@ a single byte. These buffers can be persisted or sent over networks to other pro
 This module is organized around _features_ of the encoded decimal.
 */
 
+use core::iter;
+
 mod buf;
 mod combination;
+mod constant;
 mod exponent;
 mod significand;
 
@@ -20,6 +23,8 @@ pub use self::{
     significand::*,
 };
 
+pub(crate) use self::constant::*;
+
 pub(crate) fn encode_max<D: BinaryBuf>(buf: &mut D, is_negative: bool) {
     let bit_width = buf.storage_width_bits();
     let max_digits = precision_digits(bit_width);
@@ -34,16 +39,80 @@ pub(crate) fn encode_max<D: BinaryBuf>(buf: &mut D, is_negative: bool) {
 }
 
 pub(crate) fn encode_min<D: BinaryBuf>(buf: &mut D, is_negative: bool) {
+    let max_digits = precision_digits(buf.storage_width_bits());
+
+    encode_subnormal(buf, is_negative, max_digits as u32 - 1);
+}
+
+/**
+Encode a subnormal with a significand of `1` preceded by `leading_zeros` zeroes, using the
+smallest exponent.
+
+The number of leading zeroes must be between `1` and one less than the precision of the decimal.
+*/
+pub(crate) fn encode_subnormal<D: BinaryBuf>(buf: &mut D, is_negative: bool, leading_zeros: u32) {
     let bit_width = buf.storage_width_bits();
     let max_digits = precision_digits(bit_width);
 
+    let leading_zeros = leading_zeros as usize;
+    assert!(
+        (1..max_digits).contains(&leading_zeros),
+        "a subnormal must have between 1 and {} leading zeroes",
+        max_digits - 1
+    );
+
     let exp = <D::Exponent>::emin(buf).raise(1).lower(max_digits);
 
-    let msd = encode_significand_trailing_digits(buf, [b"1"]);
+    // The digits are written least significant first, so the `1` follows its trailing zeroes
+    let msd = encode_significand_trailing_digits_rev(
+        buf,
+        iter::repeat_n(b'0', max_digits - leading_zeros - 1).chain(iter::once(b'1')),
+    );
 
     encode_combination_finite(buf, is_negative, exp, msd);
 }
 
+/**
+Re-encode a decimal using the canonical encoding of its value.
+
+Every declet is decoded and encoded again, so any non-canonical declets are replaced by the
+canonical ones for the same digits. Infinities and NaNs are identified by the first 5 bits of their
+combination field, and only their sign, signaling bit, and payload are kept, so any unused bits
+are cleared. Canonical decimals are returned unchanged.
+*/
+pub(crate) fn canonicalize<D: BinaryBuf>(decimal: &D) -> D {
+    let mut buf = D::try_with_exactly_storage_width_bytes(decimal.bytes().len())
+        .expect("the decimal's width is always supported");
+
+    let msb = decimal.bytes()[decimal.bytes().len() - 1];
+    let is_negative = is_sign_negative(decimal);
+
+    // ±Infinity
+    if msb & combination::NAN == combination::INFINITY {
+        encode_combination_infinity(&mut buf, is_negative);
+    }
+    // ±NaN123
+    else if msb & combination::NAN == combination::NAN {
+        encode_significand_trailing_digits_rev(
+            &mut buf,
+            decode_significand_trailing_digits_rev(decimal),
+        );
+        encode_combination_nan(&mut buf, is_negative, is_signaling_nan(decimal));
+    }
+    // ±123
+    else {
+        let (exponent, msd) = decode_combination_finite(decimal);
+
+        encode_significand_trailing_digits_rev(
+            &mut buf,
+            decode_significand_trailing_digits_rev(decimal),
+        );
+        encode_combination_finite(&mut buf, is_negative, exponent, msd);
+    }
+
+    buf
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,6 +135,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn canonicalize_non_canonical() {
+        for (bytes, expected) in [
+            // A `999` declet with its unused bits set
+            (
+                [0xff, 0b0000_0011, 0, 0b0010_0010],
+                [0xff, 0, 0, 0b0010_0010],
+            ),
+            // A NaN with a non-canonical payload and bits set in its combination field
+            (
+                [0xff, 0b0000_0011, 0, 0b1111_1111],
+                [0xff, 0, 0, 0b1111_1110],
+            ),
+            // An infinity with garbage in its trailing significand
+            ([0x01, 0, 0, 0b0111_1010], [0, 0, 0, 0b0111_1000]),
+        ] {
+            let mut decimal = DynamicBinaryBuf::<4>::ZERO;
+            decimal.bytes_mut().copy_from_slice(&bytes);
+
+            let canonical = canonicalize(&decimal);
+
+            assert_eq!(&expected, canonical.bytes(), "{:?}", bytes);
+            assert!(is_canonical(&canonical));
+        }
+    }
+
+    #[test]
+    fn canonicalize_canonical() {
+        for f in [
+            "0",
+            "-1.5",
+            "9.999999e96",
+            "1e-101",
+            "inf",
+            "-nan(999)",
+            "snan",
+        ] {
+            let decimal: DynamicBinaryBuf<4> =
+                crate::convert::decimal_from_str(f).expect("failed to parse decimal");
+
+            assert_eq!(decimal.bytes(), canonicalize(&decimal).bytes(), "{}", f);
+        }
+    }
+
     #[test]
     fn encode_min_max_32() {
         encode_max(&mut FixedBinaryBuf::<4, i32>::ZERO, false);
@@ -102,6 +215,29 @@ mod tests {
         encode_min(&mut DynamicBinaryBuf::<20>::ZERO, true);
     }
 
+    #[test]
+    fn encode_subnormal_min() {
+        let mut min = FixedBinaryBuf::<8, i32>::ZERO;
+        encode_min(&mut min, false);
+
+        let mut subnormal = FixedBinaryBuf::<8, i32>::ZERO;
+        encode_subnormal(&mut subnormal, false, 15);
+
+        assert_eq!(min.as_ref(), subnormal.as_ref());
+    }
+
+    #[test]
+    #[should_panic]
+    fn encode_subnormal_normal() {
+        encode_subnormal(&mut FixedBinaryBuf::<8, i32>::ZERO, false, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn encode_subnormal_too_many_zeroes() {
+        encode_subnormal(&mut FixedBinaryBuf::<8, i32>::ZERO, false, 16);
+    }
+
     #[test]
     fn decode() {
         // Ensure we don't panic reading potentially nonsense encodings
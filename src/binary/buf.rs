@@ -15,12 +15,17 @@ use core::cmp;
 mod arbitrary;
 mod dynamic;
 mod fixed;
+#[cfg(feature = "raw-encoding")]
+mod slice;
 
 pub(crate) use self::{
     dynamic::*,
     fixed::*,
 };
 
+#[cfg(feature = "raw-encoding")]
+pub(crate) use self::slice::*;
+
 #[cfg(feature = "arbitrary-precision")]
 pub(crate) use self::arbitrary::*;
 
@@ -142,7 +147,8 @@ pub trait BinaryBuf {
     fn trailing_significand_width_bits(&self) -> usize {
         let bit_width = self.storage_width_bits();
 
-        15 * bit_width / 16 - 10
+        // Buffers narrower than 32 bits can't encode any trailing digits
+        (15 * bit_width / 16).saturating_sub(10)
     }
 
     /**
@@ -255,6 +261,9 @@ pub(crate) fn minimum_storage_width_bits_for_integer_exponent<N: BinaryExponentM
         Some(emax) if (-1559..=1512).contains(&emax) => 96,
         Some(emax) if (-6176..=6111).contains(&emax) => 128,
         Some(emax) if (-24617..=24534).contains(&emax) => 160,
+        Some(emax) if (-98354..=98253).contains(&emax) => 192,
+        Some(emax) if (-393275..=393156).contains(&emax) => 224,
+        Some(emax) if (-1572932..=1572795).contains(&emax) => 256,
         // If the exponent is not small, compute an appropriate width
         _ => calculate_minimum_storage_width_bits_for_integer_exponent(emax),
     }
@@ -306,6 +315,9 @@ pub(crate) fn minimum_storage_width_bits_for_precision_digits(precision_digits:
         precision_digits if precision_digits <= 25 => 96,
         precision_digits if precision_digits <= 34 => 128,
         precision_digits if precision_digits <= 43 => 160,
+        precision_digits if precision_digits <= 52 => 192,
+        precision_digits if precision_digits <= 61 => 224,
+        precision_digits if precision_digits <= 70 => 256,
         // If the number of digits is not small, compute an appropriate width
         _ => calculate_minimum_storage_width_bits_for_precision_digits(precision_digits),
     }
@@ -409,6 +421,37 @@ mod tests {
         minimum_storage_case(160, Some(35), Some(-6177), Some(6112), 43, -24617, 24534);
     }
 
+    #[test]
+    fn minimum_storage_192() {
+        minimum_storage_case(192, Some(44), Some(-24618), Some(24535), 52, -98354, 98253);
+    }
+
+    #[test]
+    fn minimum_storage_224() {
+        minimum_storage_case(
+            224,
+            Some(53),
+            Some(-98355),
+            Some(98254),
+            61,
+            -393275,
+            393156,
+        );
+    }
+
+    #[test]
+    fn minimum_storage_256() {
+        minimum_storage_case(
+            256,
+            Some(62),
+            Some(-393276),
+            Some(393157),
+            70,
+            -1572932,
+            1572795,
+        );
+    }
+
     #[test]
     fn minimum_calculated_storage_from_exponent() {
         assert!(calculate_minimum_storage_width_bits_for_integer_exponent(90i32) >= 32);
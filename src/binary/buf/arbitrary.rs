@@ -20,7 +20,10 @@ use crate::{
     OverflowError,
 };
 
-use num_bigint::BigInt;
+use num_bigint::{
+    BigInt,
+    Sign,
+};
 use num_traits::{
     Signed,
     ToPrimitive,
@@ -32,6 +35,7 @@ A buffer that can represent any sized decimal.
 #[derive(Debug, Clone)]
 pub(crate) struct ArbitrarySizedBinaryBuf(Vec<u8>);
 
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct ArbitrarySizedBinaryExponent(BigInt);
 
 pub(crate) struct ArbitrarySizedBinaryExponentBytes(Vec<u8>);
@@ -124,7 +128,9 @@ impl Integer for BigInt {
     fn from_le_bytes<I: Iterator<Item = u8>>(bytes: I) -> Self {
         let buf = bytes.collect::<Vec<_>>();
 
-        BigInt::from_signed_bytes_le(&buf)
+        // The bytes are zero-extended, just like the fixed-size integers. Otherwise an
+        // exponent that fills its most significant byte would be read as negative
+        BigInt::from_bytes_le(Sign::Plus, &buf)
     }
 
     fn from_i32(n: i32) -> Self {
@@ -159,6 +165,14 @@ impl BinaryExponent for ArbitrarySizedBinaryExponent {
         ArbitrarySizedBinaryExponent(&self.0 - fractional_digits)
     }
 
+    fn checked_raise(&self, integer_digits: usize) -> Option<Self> {
+        Some(self.raise(integer_digits))
+    }
+
+    fn checked_lower(&self, fractional_digits: usize) -> Option<Self> {
+        Some(self.lower(fractional_digits))
+    }
+
     #[must_use]
     fn bias<D: BinaryBuf>(&self, decimal: &D) -> Self {
         ArbitrarySizedBinaryExponent(add_bias(decimal, self.0.clone()))
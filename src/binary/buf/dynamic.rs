@@ -23,6 +23,34 @@ pub(crate) struct DynamicBinaryBuf<const N: usize> {
 }
 
 impl<const N: usize> DynamicBinaryBuf<N> {
+    /**
+    Create a buffer from the bytes of a decimal in a `const` context.
+
+    The width of the decimal is the length of the array, which must be a non-zero multiple of
+    4 bytes, and no more than `N`.
+    */
+    pub(crate) const fn from_le_bytes<const M: usize>(bytes: [u8; M]) -> Self {
+        assert!(
+            M > 0 && M.is_multiple_of(4) && M <= N,
+            "decimals must be a non-zero multiple of 32 bits (4 bytes) that fits in the buffer"
+        );
+
+        let mut buf = [0; N];
+
+        let mut i = 0;
+        while i < M {
+            buf[i] = bytes[i];
+            i += 1;
+        }
+
+        DynamicBinaryBuf { buf, len: M as u32 }
+    }
+
+    #[inline]
+    pub(crate) const fn as_le_bytes(&self) -> &[u8] {
+        self.buf.split_at(self.len as usize).0
+    }
+
     #[cfg(test)]
     pub(crate) const ZERO: Self = DynamicBinaryBuf {
         buf: [0; N],
@@ -30,6 +58,7 @@ impl<const N: usize> DynamicBinaryBuf<N> {
     };
 }
 
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct DynamicBinaryExponent(i32);
 
 pub(crate) struct DynamicBinaryExponentBytes([u8; 4]);
@@ -125,6 +154,16 @@ impl BinaryExponent for DynamicBinaryExponent {
         DynamicBinaryExponent(self.0.lower(fractional_digits))
     }
 
+    fn checked_raise(&self, integer_digits: usize) -> Option<Self> {
+        Some(DynamicBinaryExponent(self.0.checked_raise(integer_digits)?))
+    }
+
+    fn checked_lower(&self, fractional_digits: usize) -> Option<Self> {
+        Some(DynamicBinaryExponent(
+            self.0.checked_lower(fractional_digits)?,
+        ))
+    }
+
     #[must_use]
     fn bias<D: BinaryBuf>(&self, decimal: &D) -> Self {
         DynamicBinaryExponent(self.0.bias(decimal))
@@ -0,0 +1,87 @@
+use crate::{
+    binary::{
+        try_with_at_least_precision,
+        BinaryBuf,
+    },
+    num::Integer,
+    OverflowError,
+};
+
+/**
+A borrowed buffer owned by the caller.
+
+Since the buffer is borrowed, it can't be created on-demand by the encoder. It's only
+useful for encoding into memory that's already been allocated with the right width.
+*/
+#[derive(Debug)]
+pub(crate) struct SliceBinaryBuf<'a>(&'a mut [u8]);
+
+impl<'a> SliceBinaryBuf<'a> {
+    /**
+    The widest buffer that can have its biased exponent represented as an `i64`.
+
+    This is 896 bits, where `emax` is `3 * 2^59`. The largest biased exponent is `2 * emax - 1`,
+    which fits in an `i64` at this width, but not at the next width of 928 bits.
+    */
+    pub(crate) const MAX_STORAGE_WIDTH_BYTES: usize = 112;
+
+    pub(crate) fn try_from_le_bytes(buf: &'a mut [u8]) -> Result<Self, OverflowError> {
+        if buf.is_empty() || !buf.len().is_multiple_of(4) {
+            Err(OverflowError::exact_size_mismatch(
+                buf.len(),
+                buf.len() + 4 - (buf.len() % 4),
+                "decimals must be a multiple of 32 bits (4 bytes)",
+            ))
+        } else if buf.len() > Self::MAX_STORAGE_WIDTH_BYTES {
+            Err(Self::too_wide(buf.len()))
+        } else {
+            Ok(SliceBinaryBuf(buf))
+        }
+    }
+
+    /**
+    The error returned for buffers wider than `MAX_STORAGE_WIDTH_BYTES`.
+    */
+    pub(crate) fn too_wide(width_bytes: usize) -> OverflowError {
+        OverflowError::would_overflow(Self::MAX_STORAGE_WIDTH_BYTES, width_bytes)
+    }
+}
+
+impl<'a> BinaryBuf for SliceBinaryBuf<'a> {
+    type Exponent = i64;
+
+    fn try_exponent_from_ascii<I: Iterator<Item = u8>>(
+        is_negative: bool,
+        ascii: I,
+    ) -> Result<i64, OverflowError>
+    where
+        Self::Exponent: Sized,
+    {
+        i64::try_from_ascii(is_negative, ascii).ok_or_else(|| {
+            OverflowError::exponent_out_of_range(8, "the exponent would overflow an `i64`")
+        })
+    }
+
+    fn try_with_at_least_storage_width_bytes(bytes: usize) -> Result<Self, OverflowError> {
+        // A borrowed buffer can't be conjured from nothing
+        Err(OverflowError::would_overflow(0, bytes))
+    }
+
+    fn try_with_at_least_precision(
+        integer_digits: usize,
+        integer_exponent: Option<&Self::Exponent>,
+    ) -> Result<Self, OverflowError>
+    where
+        Self: Sized,
+    {
+        try_with_at_least_precision(integer_digits, integer_exponent.cloned())
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        self.0
+    }
+
+    fn bytes(&self) -> &[u8] {
+        self.0
+    }
+}
@@ -15,6 +15,8 @@ For more details, see the respective encoding functions.
 
 use crate::{
     binary::{
+        decode_significand_trailing_declets,
+        is_canonical_trailing_significand,
         BinaryBuf,
         BinaryExponent,
         MostSignificantDigit,
@@ -26,30 +28,30 @@ use core::iter;
 // The sign is encoded into the most significant bit of the decimal number
 // For negative numbers, the bit is set to `1`
 // For positive numbers, the bit is left `0`
-const SIGN_NEGATIVE: u8 = 0b1000_0000u8;
+pub(crate) const SIGN_NEGATIVE: u8 = 0b1000_0000u8;
 
 // The bits that identify a decimal as encoding an infinity.
-const INFINITY: u8 = 0b0111_1000;
+pub(crate) const INFINITY: u8 = 0b0111_1000;
 
 // All bits needed to determine whether a decimal is an infinity.
-const INFINITY_COMBINATION: u8 = 0b0111_1110u8;
+pub(crate) const INFINITY_COMBINATION: u8 = 0b0111_1110u8;
 
 // Whether or not the NaN is "signaling".
 //
 // This flag determines whether or not the observance of a NaN should
 // immediately trap in hardware or raise an exception in software. They're
 // not treated consistently so are kind of niche.
-const SIGNALING: u8 = 0b0000_0010u8;
+pub(crate) const SIGNALING: u8 = 0b0000_0010u8;
 
 // The bits that identify a decimal as encoding a quiet (non-signaling) NaN.
-const NAN: u8 = 0b0111_1100u8;
+pub(crate) const NAN: u8 = 0b0111_1100u8;
 
 // All bits needed to determine whether a decimal is a NaN, and whether
 // it's quiet or signaling.
-const NAN_COMBINATION: u8 = NAN | SIGNALING;
+pub(crate) const NAN_COMBINATION: u8 = NAN | SIGNALING;
 
 // All bits needed to determine whether a decimal is finite.
-const FINITE_COMBINATION: u8 = 0b0111_1000u8;
+pub(crate) const FINITE_COMBINATION: u8 = 0b0111_1000u8;
 
 /**
 Mark the decimal as being an infinity.
@@ -85,6 +87,26 @@ pub fn encode_combination_nan<D: BinaryBuf>(
     buf[buf.len() - 1] = NAN | sign_bit | signaling_bit;
 }
 
+/**
+Set or clear the signaling bit of a NaN.
+
+Only the signaling bit is changed, so the NaN keeps its sign and payload.
+If the decimal isn't a NaN then it's left unchanged.
+*/
+pub fn set_nan_signaling<D: BinaryBuf>(decimal: &mut D, is_nan_signaling: bool) {
+    if !is_nan(decimal) {
+        return;
+    }
+
+    let buf = decimal.bytes_mut();
+
+    if is_nan_signaling {
+        buf[buf.len() - 1] |= SIGNALING;
+    } else {
+        buf[buf.len() - 1] &= !SIGNALING;
+    }
+}
+
 /**
 Mark the decimal as being finite.
 */
@@ -166,7 +188,12 @@ pub fn encode_combination_finite<D: BinaryBuf>(
     }
 
     // Encode the final 2 non-most-significant bits into the final byte
-    buf[decimal_byte_index] |= exponent[exponent_byte_index] << decimal_byte_shift;
+    //
+    // In the widest decimals the exponent may already be fully written, and there may be no
+    // more exponent bytes to read
+    if exponent_byte_index * 8 < exponent_bits {
+        buf[decimal_byte_index] |= exponent[exponent_byte_index] << decimal_byte_shift;
+    }
 
     // Finally, we encode the 2 most significant bits of the exponent and most significant digit
     // together into a 5 bit combination field.
@@ -524,6 +551,100 @@ pub fn is_sign_negative<D: BinaryBuf>(decimal: &D) -> bool {
     buf[buf.len() - 1] & SIGN_NEGATIVE == SIGN_NEGATIVE
 }
 
+/**
+Whether or not the unused bits of an infinity or NaN are all zero.
+
+The encoder always leaves these bits zero, but decimals from other sources may not. An infinity
+is identified by the first 5 bits of its combination field, so the rest of the decimal besides
+its sign must be zero. A NaN is identified by the first 5 bits of its combination field, followed
+by the signaling bit, with its payload in the trailing significand, so the rest of its combination
+field must be zero.
+
+Finite decimals don't have any unused bits, so they're always canonical here.
+*/
+pub fn is_canonical_special<D: BinaryBuf>(decimal: &D) -> bool {
+    let buf = decimal.bytes();
+
+    let width = decimal.storage_width_bits();
+    let msb = buf[buf.len() - 1];
+
+    // Only the first 5 bits of the combination field are needed to identify an infinity.
+    // This is looser than `is_infinite`, so an infinity with garbage in the rest of its
+    // combination field is still checked, rather than being treated as finite
+    if msb & NAN == INFINITY {
+        // The sign and 5 combination bits are the only ones used
+        return is_zero_bits(buf, 0..width - 6);
+    }
+
+    if msb & NAN == NAN {
+        // The sign, 5 combination bits, and signaling bit are used, along with the payload
+        return is_zero_bits(buf, decimal.trailing_significand_width_bits()..width - 7);
+    }
+
+    true
+}
+
+/**
+Whether or not the decimal uses the canonical encoding of its value.
+
+Besides the unused bits of infinities and NaNs, checked by [`is_canonical_special`], the declets of
+finite decimals and NaN payloads must also be canonical. Every combination field of a finite decimal
+is canonical.
+*/
+pub fn is_canonical<D: BinaryBuf>(decimal: &D) -> bool {
+    is_canonical_special(decimal) && is_canonical_trailing_significand(decimal)
+}
+
+fn is_zero_bits(buf: &[u8], bits: core::ops::Range<usize>) -> bool {
+    bits.into_iter()
+        .all(|bit| buf[bit / 8] & (1 << (bit % 8)) == 0)
+}
+
+/**
+Whether or not the decimal is a non-zero finite number that's smaller than the smallest normal number.
+
+A decimal is subnormal when its adjusted exponent, which is the position of its most significant
+digit, is less than the minimum exponent.
+*/
+pub fn is_subnormal<D: BinaryBuf>(decimal: &D) -> bool {
+    if !is_finite(decimal) {
+        return false;
+    }
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    let significant_digits = iter::once(msd.get_ascii())
+        .chain(decode_significand_trailing_declets(decimal).flatten())
+        .skip_while(|digit| *digit == b'0')
+        .count();
+
+    significant_digits > 0 && exponent.raise(significant_digits - 1) < D::Exponent::emin(decimal)
+}
+
+/**
+Flip the sign bit of the decimal.
+
+Only the sign bit is changed, so the rest of the decimal, including any NaN payload, is left as-is.
+*/
+pub fn negate<D: BinaryBuf>(decimal: &mut D) {
+    let buf = decimal.bytes_mut();
+
+    buf[buf.len() - 1] ^= SIGN_NEGATIVE;
+}
+
+/**
+Set or clear the sign bit of the decimal.
+*/
+pub fn set_sign_negative<D: BinaryBuf>(decimal: &mut D, is_negative: bool) {
+    let buf = decimal.bytes_mut();
+
+    if is_negative {
+        buf[buf.len() - 1] |= SIGN_NEGATIVE;
+    } else {
+        buf[buf.len() - 1] &= !SIGN_NEGATIVE;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -569,6 +690,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn canonical_special() {
+        for (bytes, expected) in [
+            // Infinities
+            ([0, 0, 0, 0b0111_1000], true),
+            ([0, 0, 0, 0b1111_1000], true),
+            ([1, 0, 0, 0b0111_1000], false),
+            ([0, 0, 0b1000_0000, 0b0111_1000], false),
+            ([0, 0, 0, 0b0111_1001], false),
+            ([0, 0, 0, 0b0111_1010], false),
+            ([0xff, 0xff, 0xff, 0b1111_1011], false),
+            // NaNs
+            ([0, 0, 0, 0b0111_1100], true),
+            ([0, 0, 0, 0b1111_1110], true),
+            ([0xff, 0xff, 0b0000_1111, 0b0111_1100], true),
+            ([0, 0, 0b0001_0000, 0b0111_1100], false),
+            ([0, 0, 0b1000_0000, 0b0111_1110], false),
+            ([0, 0, 0, 0b0111_1101], false),
+            // Finite numbers
+            ([0, 0, 0, 0], true),
+            ([0xff, 0xff, 0xff, 0b0110_0111], true),
+        ] {
+            let mut decimal = DynamicBinaryBuf::<4>::ZERO;
+            decimal.bytes_mut().copy_from_slice(&bytes);
+
+            assert_eq!(expected, is_canonical_special(&decimal), "{:?}", bytes);
+        }
+    }
+
+    #[test]
+    fn canonical() {
+        for (bytes, expected) in [
+            ([0xff, 0, 0, 0], true),
+            ([0xff, 0b0000_0011, 0, 0], false),
+            ([0, 0b1111_1100, 0b0000_1111, 0b0010_0010], false),
+            ([0xff, 0b0000_0011, 0, 0b0111_1100], false),
+            ([1, 0, 0, 0b0111_1000], false),
+            ([0, 0, 0, 0b0111_1000], true),
+        ] {
+            let mut decimal = DynamicBinaryBuf::<4>::ZERO;
+            decimal.bytes_mut().copy_from_slice(&bytes);
+
+            assert_eq!(expected, is_canonical(&decimal), "{:?}", bytes);
+        }
+    }
+
+    #[test]
+    fn canonical_special_encoded() {
+        let mut decimal = DynamicBinaryBuf::<16>::ZERO;
+        encode_combination_infinity(&mut decimal, true);
+        assert!(is_canonical_special(&decimal));
+
+        let mut decimal = DynamicBinaryBuf::<16>::ZERO;
+        encode_combination_nan(&mut decimal, false, true);
+        assert!(is_canonical_special(&decimal));
+
+        // Bits just above the trailing significand are part of the combination field
+        decimal.bytes_mut()[13] |= 0b0100_0000;
+        assert!(!is_canonical_special(&decimal));
+    }
+
     #[test]
     fn encode_decode_combination_decimal32_all() {
         encode_decode_case(DynamicBinaryBuf::<4>::ZERO);
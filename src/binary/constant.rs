@@ -0,0 +1,235 @@
+/*!
+Encode finite decimals in `const` contexts.
+
+The rest of the `binary` module works with any width of decimal through the `BinaryBuf` trait,
+which can't be used in `const` functions. This module is a separate, much simpler, encoder for
+decimals up to 128 bits wide, so fixed-width decimals can be created in `const` items.
+*/
+
+/**
+Encode a finite decimal with the given coefficient and exponent into `N` little-endian bytes.
+
+The width must be 4, 8, or 16 bytes. This function panics if the coefficient has more digits than
+the decimal can store, or the exponent is outside its range. In a `const` item, that panic becomes
+a compile error.
+*/
+pub(crate) const fn encode_finite_const<const N: usize>(
+    is_negative: bool,
+    coefficient: u128,
+    exponent: i32,
+) -> [u8; N] {
+    assert!(
+        N == 4 || N == 8 || N == 16,
+        "only 32, 64, and 128 bit decimals can be encoded in `const` contexts"
+    );
+
+    let width_bits = N as u32 * 8;
+
+    // These are the same parameters as `precision_digits`, `emax`, and `bias`
+    // for the widths this function supports
+    let precision_digits = 9 * width_bits / 32 - 2;
+    let trailing_declets = (precision_digits - 1) / 3;
+    let exponent_continuation_bits = width_bits / 16 + 4;
+    let emax = 3 * (1i32 << (exponent_continuation_bits - 1));
+    let bias = emax + precision_digits as i32 - 2;
+
+    assert!(
+        coefficient < 10u128.pow(precision_digits),
+        "the coefficient has too many digits to fit in the decimal"
+    );
+    assert!(
+        exponent >= -bias && exponent <= 2 * emax - 1 - bias,
+        "the exponent is outside the range of the decimal"
+    );
+
+    let mut bits = 0u128;
+    let mut rest = coefficient;
+
+    // The trailing significand is written one declet at a time, from the least significant
+    let mut declet = 0;
+    while declet < trailing_declets {
+        bits |= (encode_dpd_declet((rest % 1000) as u16) as u128) << (declet * 10);
+
+        rest /= 1000;
+        declet += 1;
+    }
+
+    let msd = rest as u32;
+    let biased_exponent = (exponent + bias) as u32;
+
+    // The combination field holds the most significant digit and the top 2 bits of the exponent
+    let exponent_msb = biased_exponent >> exponent_continuation_bits;
+    let combination = if msd < 8 {
+        (exponent_msb << 3) | msd
+    } else {
+        0b11000 | (exponent_msb << 1) | (msd & 1)
+    };
+
+    let exponent_continuation = biased_exponent & ((1 << exponent_continuation_bits) - 1);
+
+    bits |= (exponent_continuation as u128) << (trailing_declets * 10);
+    bits |= (combination as u128) << (trailing_declets * 10 + exponent_continuation_bits);
+
+    if is_negative {
+        bits |= 1 << (width_bits - 1);
+    }
+
+    let le_bytes = bits.to_le_bytes();
+
+    let mut bytes = [0; N];
+    let mut i = 0;
+    while i < N {
+        bytes[i] = le_bytes[i];
+        i += 1;
+    }
+
+    bytes
+}
+
+/**
+Compress a binary integer from `0` to `999` into a densely packed decimal (DPD) declet.
+
+This follows the same encoding as `encode_bcd_declet_to_dpd`, using the table from the
+[General Decimal Arithmetic specification](https://speleotrove.com/decimal/DPDecimal.html).
+The declet is split into 3 digits, `abcd`, `efgh`, and `ijkm`, and the bits `a`, `e`, and
+`i` determine how they're packed.
+*/
+const fn encode_dpd_declet(declet: u16) -> u16 {
+    let d2 = declet / 100;
+    let d1 = declet / 10 % 10;
+    let d0 = declet % 10;
+
+    let (a, bcd) = (d2 >> 3, d2 & 0b111);
+    let (e, fgh) = (d1 >> 3, d1 & 0b111);
+    let (i, jkm) = (d0 >> 3, d0 & 0b111);
+
+    let d = bcd & 1;
+    let h = fgh & 1;
+    let m = jkm & 1;
+
+    let fg = fgh >> 1;
+    let jk = jkm >> 1;
+
+    // Returns the bits `pqr`, `stu`, `v`, and `wxy`
+    let (pqr, stu, v, wxy) = match (a, e, i) {
+        (0, 0, 0) => (bcd, fgh, 0, jkm),
+        (0, 0, 1) => (bcd, fgh, 1, m),
+        (0, 1, 0) => (bcd, (jk << 1) | h, 1, 0b010 | m),
+        (1, 0, 0) => ((jk << 1) | d, fgh, 1, 0b100 | m),
+        (1, 1, 0) => ((jk << 1) | d, h, 1, 0b110 | m),
+        (1, 0, 1) => ((fg << 1) | d, 0b010 | h, 1, 0b110 | m),
+        (0, 1, 1) => (bcd, 0b100 | h, 1, 0b110 | m),
+        _ => (d, 0b110 | h, 1, 0b110 | m),
+    };
+
+    (pqr << 7) | (stu << 4) | (v << 3) | wxy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::{
+            BinaryBuf,
+            FixedBinaryBuf,
+        },
+        convert::decimal_from_str,
+    };
+
+    fn parse<const N: usize>(s: &str) -> [u8; N] {
+        let d: FixedBinaryBuf<N, i32> = decimal_from_str(s).expect("failed to parse decimal");
+
+        d.bytes().try_into().expect("unexpected width")
+    }
+
+    #[test]
+    fn encode_const_every_declet() {
+        for declet in 0..1000u128 {
+            assert_eq!(
+                parse::<8>(&declet.to_string()),
+                encode_finite_const::<8>(false, declet, 0),
+                "{}",
+                declet
+            );
+        }
+    }
+
+    #[test]
+    fn encode_const_matches_parsed() {
+        for (is_negative, coefficient, exponent) in [
+            (false, 0, 0),
+            (true, 0, 0),
+            (false, 1234567, -3),
+            (true, 9999999, 90),
+            (false, 8000000, -101),
+            (false, 1, -101),
+        ] {
+            let s = format!(
+                "{}{}e{}",
+                if is_negative { "-" } else { "" },
+                coefficient,
+                exponent
+            );
+
+            assert_eq!(
+                parse::<4>(&s),
+                encode_finite_const::<4>(is_negative, coefficient, exponent),
+                "{}",
+                s
+            );
+        }
+
+        for (is_negative, coefficient, exponent) in [
+            (false, 9999999999999999, 369),
+            (true, 8123456789012345, -398),
+            (false, 525, -4),
+        ] {
+            let s = format!(
+                "{}{}e{}",
+                if is_negative { "-" } else { "" },
+                coefficient,
+                exponent
+            );
+
+            assert_eq!(
+                parse::<8>(&s),
+                encode_finite_const::<8>(is_negative, coefficient, exponent),
+                "{}",
+                s
+            );
+        }
+
+        for (is_negative, coefficient, exponent) in [
+            (false, 9999999999999999999999999999999999, 6111),
+            (true, 1234567890123456789012345678901234, -6176),
+            (false, 8, 0),
+        ] {
+            let s = format!(
+                "{}{}e{}",
+                if is_negative { "-" } else { "" },
+                coefficient,
+                exponent
+            );
+
+            assert_eq!(
+                parse::<16>(&s),
+                encode_finite_const::<16>(is_negative, coefficient, exponent),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn encode_const_too_many_digits() {
+        encode_finite_const::<4>(false, 12345678, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn encode_const_exponent_out_of_range() {
+        encode_finite_const::<4>(false, 1, 91);
+    }
+}
@@ -16,7 +16,7 @@ use core::{
 /**
 The high-level operators needed to work with binary exponents.
 */
-pub trait BinaryExponent: Integer {
+pub trait BinaryExponent: Integer + Ord + Clone {
     /**
     Account for digits on the integral side of the decimal point by raising the exponent.
     */
@@ -27,6 +27,16 @@ pub trait BinaryExponent: Integer {
     */
     fn lower(&self, by: usize) -> Self;
 
+    /**
+    Raise the exponent, returning `None` if the result would overflow.
+    */
+    fn checked_raise(&self, by: usize) -> Option<Self>;
+
+    /**
+    Lower the exponent, returning `None` if the result would overflow.
+    */
+    fn checked_lower(&self, by: usize) -> Option<Self>;
+
     /**
     Add the bias to the exponent.
 
@@ -97,6 +107,14 @@ macro_rules! impl_binary_exponent {
                     *self - (by as $i)
                 }
 
+                fn checked_raise(&self, by: usize) -> Option<Self> {
+                    self.checked_add(<$i>::try_from(by).ok()?)
+                }
+
+                fn checked_lower(&self, by: usize) -> Option<Self> {
+                    self.checked_sub(<$i>::try_from(by).ok()?)
+                }
+
                 #[must_use]
                 fn bias<D: BinaryBuf>(&self, decimal: &D) -> Self {
                     add_bias(decimal, *self)
@@ -120,7 +138,9 @@ macro_rules! impl_binary_exponent {
 
             impl BinaryExponentMath for $i {
                 fn abs(self) -> Self {
-                    <$i>::abs(self)
+                    // The minimum value saturates rather than overflowing. This is only
+                    // used to estimate widths, so being off by one doesn't matter
+                    <$i>::saturating_abs(self)
                 }
 
                 fn pow2(e: u32) -> Self {
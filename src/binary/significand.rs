@@ -37,6 +37,35 @@ pub fn encode_significand_trailing_digits<D: BinaryBuf, const N: usize>(
     debug_assert_eq!(0, max_digits % 3, "{}", max_digits);
 
     let decimal = decimal.bytes_mut();
+
+    // Fast path: at most 3 digits across all chunks fit in the first declet.
+    // Small numbers like `1.5` or `42` are common, so this avoids the bookkeeping
+    // needed to merge digits across chunks. Every decimal has at least 6 trailing
+    // digits, so the most significant digit is always zero.
+    if chunks.iter().map(|chunk| chunk.len()).sum::<usize>() <= 3 {
+        debug_assert!(
+            chunks.iter().all(|chunk| !chunk.is_empty()),
+            "all chunks must have at least 1 digit"
+        );
+
+        let mut ascii = [b'0'; 3];
+
+        for (i, digit) in chunks
+            .iter()
+            .flat_map(|chunk| chunk.iter())
+            .rev()
+            .enumerate()
+        {
+            ascii[i] = *digit;
+        }
+
+        let bcd = encode_ascii_declet_to_bcd(ascii);
+
+        encode_bcd_declet_to_dpd(bcd, decimal, &mut 0);
+
+        return MostSignificantDigit::zero();
+    }
+
     let mut digit_index = 0;
     let mut bit_index = 0;
 
@@ -103,20 +132,193 @@ pub fn encode_significand_trailing_digits_repeat<D: BinaryBuf>(
     MostSignificantDigit::from_ascii(digit)
 }
 
+/**
+Encode the trailing digits into the decimal buffer from a stream of ASCII digits.
+
+The digits are given from least to most significant. This method only encodes up to
+`D::trailing_significand_digits` into the buffer. The next digit after those is returned as the
+`MostSignificantDigit`. Any remaining digits are ignored.
+
+The buffer is expected to be zeroed, so if there are fewer digits than the decimal can fit then the
+remaining most significant ones are left zero.
+*/
+pub fn encode_significand_trailing_digits_rev<D: BinaryBuf>(
+    decimal: &mut D,
+    digits: impl Iterator<Item = u8>,
+) -> MostSignificantDigit {
+    let max_digits = decimal.trailing_significand_digits();
+
+    debug_assert_eq!(0, max_digits % 3, "{}", max_digits);
+
+    let decimal = decimal.bytes_mut();
+    let mut digits = digits.fuse();
+    let mut digit_index = 0;
+    let mut bit_index = 0;
+
+    while digit_index < max_digits {
+        let ascii = match (digits.next(), digits.next(), digits.next()) {
+            // If there are no more digits then the rest of the buffer is already zero
+            (None, _, _) => return MostSignificantDigit::zero(),
+            // Declets are encoded from their least significant digit
+            (Some(d0), d1, d2) => [d0, d1.unwrap_or(b'0'), d2.unwrap_or(b'0')],
+        };
+
+        let bcd = encode_ascii_declet_to_bcd(ascii);
+
+        encode_bcd_declet_to_dpd(bcd, decimal, &mut bit_index);
+
+        digit_index += 3;
+    }
+
+    match digits.next() {
+        Some(digit) => MostSignificantDigit::from_ascii(digit),
+        None => MostSignificantDigit::zero(),
+    }
+}
+
+/**
+Encode the trailing digits into the decimal buffer from a stream of binary integer declets.
+
+Each declet is a binary integer from `0` to `999` that holds 3 digits. The declets are given from
+least to most significant. This method only encodes up to `D::trailing_significand_digits` into
+the buffer. The next declet after those is returned as the `MostSignificantDigit`, so it must be
+less than `10`. Any remaining declets are ignored.
+
+The buffer is expected to be zeroed, so if there are fewer declets than the decimal can fit then the
+remaining most significant ones are left zero.
+*/
+pub(crate) fn encode_significand_trailing_int_declets_rev<D: BinaryBuf>(
+    decimal: &mut D,
+    declets: impl Iterator<Item = u16>,
+) -> MostSignificantDigit {
+    let max_digits = decimal.trailing_significand_digits();
+
+    debug_assert_eq!(0, max_digits % 3, "{}", max_digits);
+
+    let decimal = decimal.bytes_mut();
+    let mut declets = declets.fuse();
+    let mut digit_index = 0;
+    let mut bit_index = 0;
+
+    while digit_index < max_digits {
+        // If there are no more declets then the rest of the buffer is already zero
+        let Some(declet) = declets.next() else {
+            return MostSignificantDigit::zero();
+        };
+
+        let bcd = encode_int_declet_to_bcd(declet);
+
+        encode_bcd_declet_to_dpd(bcd, decimal, &mut bit_index);
+
+        digit_index += 3;
+    }
+
+    match declets.next() {
+        Some(declet) => {
+            debug_assert!(declet < 10, "{} is not a single digit", declet);
+
+            MostSignificantDigit::from_bcd(declet as u8)
+        }
+        None => MostSignificantDigit::zero(),
+    }
+}
+
+/**
+Encode pre-compressed densely packed decimal (DPD) declets into the decimal buffer.
+
+The declets are given most significant first, and each one must fit in 10 bits. If there are fewer
+declets than the decimal can fit then the remaining most significant ones are left zero.
+*/
+#[cfg(feature = "raw-encoding")]
+pub(crate) fn encode_significand_trailing_dpd_declets<D: BinaryBuf>(
+    decimal: &mut D,
+    declets: &[u16],
+) {
+    debug_assert!(declets.len() * 3 <= decimal.trailing_significand_digits());
+
+    let decimal = decimal.bytes_mut();
+    let mut bit_index = 0;
+
+    // Declets are written from the least significant end of the buffer
+    for dpd in declets.iter().rev() {
+        debug_assert!(*dpd < 1 << 10, "{} is not a 10 bit declet", dpd);
+
+        write_dpd_declet(*dpd, decimal, &mut bit_index);
+    }
+}
+
+/**
+The number of bits dedicated to the trailing significand digits that can actually be read.
+
+If the decimal claims to have more trailing significand bits than it has bytes then it's
+malformed, and none of its declets are read.
+*/
+fn trailing_significand_width_bits_in_bounds<D: BinaryBuf>(decimal: &D) -> usize {
+    let bit_index = decimal.trailing_significand_width_bits();
+
+    if bit_index > decimal.bytes().len() * 8 {
+        0
+    } else {
+        bit_index
+    }
+}
+
+/**
+Whether or not every declet in the trailing significand is canonical.
+
+Densely packed decimal has 24 declets that decode to the same digits as another declet. They're
+the declets where all three digits are `8` or `9`, which leave 2 of their bits unused. The encoder
+always leaves those bits zero, but decimals from other sources may not.
+*/
+pub fn is_canonical_trailing_significand<D: BinaryBuf>(decimal: &D) -> bool {
+    let mut bit_index = trailing_significand_width_bits_in_bounds(decimal);
+
+    let decimal = decimal.bytes();
+
+    while bit_index > 0 {
+        let Some(dpd) = read_dpd_declet(decimal, &mut bit_index) else {
+            return false;
+        };
+
+        if !is_canonical_dpd_declet(dpd) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/**
+Whether or not a densely packed decimal (DPD) declet is the same when it's decoded and re-encoded.
+*/
+fn is_canonical_dpd_declet(dpd: u16) -> bool {
+    let mut declet = [0, 0];
+    write_dpd_declet(dpd, &mut declet, &mut 0);
+
+    let bcd = decode_dpd_declet_to_bcd(&declet, &mut 10).expect("declet is in bounds");
+
+    let mut canonical = [0, 0];
+    encode_bcd_declet_to_dpd(bcd, &mut canonical, &mut 0);
+
+    declet == canonical
+}
+
 /**
 Decode and stream the trailing digits encoded into the decimal.
 */
 pub fn decode_significand_trailing_declets<D: BinaryBuf>(
     decimal: &D,
 ) -> impl Iterator<Item = [u8; 3]> + '_ {
-    let mut bit_index = decimal.trailing_significand_width_bits();
+    let mut bit_index = trailing_significand_width_bits_in_bounds(decimal);
 
     let decimal = decimal.bytes();
 
     iter::from_fn(move || {
         // If there's another declet to read then yield it
+        //
+        // If the declet is outside the buffer then it's truncated, so there are no more to read
         if bit_index > 0 {
-            let bcd = decode_dpd_declet_to_bcd(decimal, &mut bit_index);
+            let bcd = decode_dpd_declet_to_bcd(decimal, &mut bit_index)?;
 
             Some(decode_bcd_declet_to_ascii(bcd))
         }
@@ -127,6 +329,143 @@ pub fn decode_significand_trailing_declets<D: BinaryBuf>(
     })
 }
 
+/**
+Decode and stream the trailing digits encoded into the decimal in reverse.
+
+Declets are yielded from least to most significant, but the digits within each declet are
+still ordered from most to least significant.
+*/
+pub fn decode_significand_trailing_declets_rev<D: BinaryBuf>(
+    decimal: &D,
+) -> impl Iterator<Item = [u8; 3]> + '_ {
+    let max_bit_index = trailing_significand_width_bits_in_bounds(decimal);
+    let mut bit_index = 0;
+
+    let decimal = decimal.bytes();
+
+    iter::from_fn(move || {
+        // If there's another declet to read then yield it
+        if bit_index < max_bit_index {
+            // Declets are decoded by reading the 10 bits that come before the index
+            let mut declet_bit_index = bit_index + 10;
+            let bcd = decode_dpd_declet_to_bcd(decimal, &mut declet_bit_index)?;
+
+            bit_index += 10;
+
+            Some(decode_bcd_declet_to_ascii(bcd))
+        }
+        // If there are no digits and no declets then we're finished
+        else {
+            None
+        }
+    })
+}
+
+/**
+The digits in the coefficient of a finite decimal, from most to least significant.
+
+The digits are yielded as ASCII, like `b'1'`, so they can be passed straight back into methods
+like `from_digits`. Leading zeroes aren't yielded, except for a zero coefficient, which is a single
+`b'0'`. The digits are decoded lazily from the encoded decimal as they're iterated.
+*/
+#[derive(Debug, Clone)]
+pub struct CoefficientDigits<'a> {
+    buf: &'a [u8],
+    bit_index: usize,
+    msd: Option<u8>,
+    declet: [u8; 3],
+    declet_index: usize,
+    remaining: usize,
+}
+
+impl<'a> CoefficientDigits<'a> {
+    pub(crate) fn new<D: BinaryBuf>(decimal: &'a D, msd: MostSignificantDigit) -> Self {
+        let bit_index = trailing_significand_width_bits_in_bounds(decimal);
+
+        let mut digits = CoefficientDigits {
+            buf: decimal.bytes(),
+            bit_index,
+            msd: Some(msd.get_ascii()),
+            declet: [b'0'; 3],
+            declet_index: 3,
+            remaining: 1 + (bit_index / 10) * 3,
+        };
+
+        // Skip over any leading zeroes, keeping at least one digit
+        while digits.remaining > 1 && digits.clone().next() == Some(b'0') {
+            digits.next();
+        }
+
+        digits
+    }
+}
+
+impl<'a> Iterator for CoefficientDigits<'a> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+
+        if let Some(msd) = self.msd.take() {
+            return Some(msd);
+        }
+
+        if self.declet_index == 3 {
+            self.declet = decode_bcd_declet_to_ascii(decode_dpd_declet_to_bcd(
+                self.buf,
+                &mut self.bit_index,
+            )?);
+            self.declet_index = 0;
+        }
+
+        let digit = self.declet[self.declet_index];
+        self.declet_index += 1;
+
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for CoefficientDigits<'a> {}
+
+/**
+Stream the pre-compressed densely packed decimal (DPD) declets encoded into the decimal.
+
+The declets are yielded most significant first, and each one is in the lower 10 bits of the value.
+*/
+#[cfg(feature = "raw-encoding")]
+pub(crate) fn decode_significand_trailing_dpd_declets<D: BinaryBuf>(
+    decimal: &D,
+) -> impl Iterator<Item = u16> + '_ {
+    let mut bit_index = trailing_significand_width_bits_in_bounds(decimal);
+
+    let decimal = decimal.bytes();
+
+    iter::from_fn(move || {
+        if bit_index > 0 {
+            read_dpd_declet(decimal, &mut bit_index)
+        } else {
+            None
+        }
+    })
+}
+
+/**
+Decode and stream the trailing digits encoded into the decimal from least to most significant.
+*/
+pub fn decode_significand_trailing_digits_rev<D: BinaryBuf>(
+    decimal: &D,
+) -> impl Iterator<Item = u8> + '_ {
+    decode_significand_trailing_declets_rev(decimal).flat_map(|[d0, d1, d2]| [d2, d1, d0])
+}
+
 /**
 Get the next 3 digits from the back of the buffer to encode.
 
@@ -299,6 +638,19 @@ fn encode_ascii_declet_to_bcd(ascii: [u8; 3]) -> u16 {
     d2 | d1 | d0
 }
 
+/**
+Encode a binary integer from `0` to `999` into binary coded decimal (BCD).
+*/
+fn encode_int_declet_to_bcd(declet: u16) -> u16 {
+    debug_assert!(declet < 1000, "{} is not a declet", declet);
+
+    let d0 = declet % 10;
+    let d1 = (declet / 10 % 10) << 4;
+    let d2 = (declet / 100) << 8;
+
+    d2 | d1 | d0
+}
+
 /**
 Decode binary coded decimal (BCD) into ASCII digits.
 */
@@ -538,71 +890,18 @@ fn encode_bcd_declet_to_dpd(bcd: u16, decimal: &mut [u8], decimal_bit_index: &mu
         _ => unreachable!(),
     };
 
-    // We need to write 10 bits into our byte buffer.
-    //
-    // These 10 bits will always cross a byte boundary, so we shift it
-    // into the next 2 bytes of the buffer.
-    //
-    // Let's say we have:
-    //
-    // dpd    : dddddddddd
-    // dpd_buf: aaaaaaaabbbbbbbbcccccccc
-    //
-    // We want to splat the 10 bits of `dpd` into the next 2 8-bit bytes
-    // of `dpd_buf`. We start from an offset of 0, and then write 8
-    // bits of `dpd` into byte `a`:
-    //
-    // dpd    : --------dd
-    // dpd_buf: ddddddddbbbbbbbbcccccccc
-    //
-    // We've got two bytes left to write, so to get them into the right
-    // place we shift them over, and then write them into byte `b`:
-    //
-    // dpd    : ----------
-    // dpd_buf: ddddddddddbbbbbbcccccccc
-    //
-    // The next time we come around, we need to start from the offset we
-    // left off in `b`:
-    //
-    // dpd    :         --dddddddddd
-    // dpd_buf: ddddddddddbbbbbbcccccccc
-    //
-    // And write into the trailing byte `c`, just like last time:
-    //
-    // dpd    :         --------dddd
-    // dpd_buf: ddddddddddddddddcccccccc
-    //
-    // dpd    :         ------------
-    // dpd_buf: ddddddddddddddddddddcccc
-
-    let decimal_byte_shift = (*decimal_bit_index % 8) as u32;
-    let decimal_byte_index = *decimal_bit_index / 8;
-
-    decimal[decimal_byte_index] |= (dpd << decimal_byte_shift) as u8;
-    decimal[decimal_byte_index + 1] |= (dpd >> (8 - decimal_byte_shift)) as u8;
-
-    *decimal_bit_index += 10;
+    write_dpd_declet(dpd, decimal, decimal_bit_index);
 }
 
 /**
 Decompress densely packed decimal (DPD) into binary coded decimal (BCD).
 
 There are some details on what BCD and DPD are in the encoding function.
+If the declet doesn't fit within the buffer then `None` is returned.
 */
-fn decode_dpd_declet_to_bcd(decimal: &[u8], decimal_bit_index: &mut usize) -> u16 {
+fn decode_dpd_declet_to_bcd(decimal: &[u8], decimal_bit_index: &mut usize) -> Option<u16> {
     // Follow the reverse process of encoding.
-    //
-    // There's some details on how the 10 DPD bits are written across 2 bytes in the encoder.
-
-    *decimal_bit_index -= 10;
-
-    let decimal_byte_shift = (*decimal_bit_index % 8) as u32;
-    let decimal_byte_index = *decimal_bit_index / 8;
-
-    let dpd0 = (decimal[decimal_byte_index] as u16) >> decimal_byte_shift;
-    let dpd1 = (decimal[decimal_byte_index + 1] as u16) << (8 - decimal_byte_shift);
-
-    let dpd = dpd0 | dpd1;
+    let dpd = read_dpd_declet(decimal, decimal_bit_index)?;
 
     // The last decoding group
     const B0: u16 = 0b0000_0000_0000_0001u16;
@@ -643,7 +942,7 @@ fn decode_dpd_declet_to_bcd(decimal: &[u8], decimal_bit_index: &mut usize) -> u1
     const BH: u16 = B1;
     const BI: u16 = B0;
 
-    match dpd {
+    let bcd = match dpd {
         // Three small digits
         // bcd: 0abc0def0ghi
         // dpd:   abcdef0ghi
@@ -813,7 +1112,92 @@ fn decode_dpd_declet_to_bcd(decimal: &[u8], decimal_bit_index: &mut usize) -> u1
             bit0 | bit1 | bit2 | bit3 | bit4 | bit5 | bit6 | bit7 | bit8 | bit9 | bit10 | bit11
         }
         _ => unreachable!(),
+    };
+
+    Some(bcd)
+}
+
+/**
+Write the 10 bits of a densely packed decimal (DPD) declet into the buffer at the given bit index.
+
+The bit index is advanced past the written declet.
+*/
+fn write_dpd_declet(dpd: u16, decimal: &mut [u8], decimal_bit_index: &mut usize) {
+    // We need to write 10 bits into our byte buffer.
+    //
+    // These 10 bits will always cross a byte boundary, so we shift it
+    // into the next 2 bytes of the buffer.
+    //
+    // Let's say we have:
+    //
+    // dpd    : dddddddddd
+    // dpd_buf: aaaaaaaabbbbbbbbcccccccc
+    //
+    // We want to splat the 10 bits of `dpd` into the next 2 8-bit bytes
+    // of `dpd_buf`. We start from an offset of 0, and then write 8
+    // bits of `dpd` into byte `a`:
+    //
+    // dpd    : --------dd
+    // dpd_buf: ddddddddbbbbbbbbcccccccc
+    //
+    // We've got two bytes left to write, so to get them into the right
+    // place we shift them over, and then write them into byte `b`:
+    //
+    // dpd    : ----------
+    // dpd_buf: ddddddddddbbbbbbcccccccc
+    //
+    // The next time we come around, we need to start from the offset we
+    // left off in `b`:
+    //
+    // dpd    :         --dddddddddd
+    // dpd_buf: ddddddddddbbbbbbcccccccc
+    //
+    // And write into the trailing byte `c`, just like last time:
+    //
+    // dpd    :         --------dddd
+    // dpd_buf: ddddddddddddddddcccccccc
+    //
+    // dpd    :         ------------
+    // dpd_buf: ddddddddddddddddddddcccc
+
+    let decimal_byte_shift = (*decimal_bit_index % 8) as u32;
+    let decimal_byte_index = *decimal_bit_index / 8;
+
+    decimal[decimal_byte_index] |= (dpd << decimal_byte_shift) as u8;
+    decimal[decimal_byte_index + 1] |= (dpd >> (8 - decimal_byte_shift)) as u8;
+
+    *decimal_bit_index += 10;
+}
+
+/**
+Read the 10 bits of a densely packed decimal (DPD) declet that end at the given bit index.
+
+The bit index is moved back to the start of the read declet. If the declet doesn't fit within
+the buffer then `None` is returned and the bit index is left unchanged. This can only happen if
+the buffer is narrower than the width its declets were calculated from.
+*/
+fn read_dpd_declet(decimal: &[u8], decimal_bit_index: &mut usize) -> Option<u16> {
+    // There's some details on how the 10 DPD bits are written across 2 bytes in `write_dpd_declet`.
+
+    const DPD: u16 = 0b0000_0011_1111_1111u16;
+
+    if *decimal_bit_index > decimal.len() * 8 {
+        return None;
     }
+
+    let start_bit_index = decimal_bit_index.checked_sub(10)?;
+
+    let decimal_byte_shift = (start_bit_index % 8) as u32;
+    let decimal_byte_index = start_bit_index / 8;
+
+    // The 10 bits always cross a byte boundary, so there are 2 bytes to read.
+    // The end of the declet is within the buffer, so both of them are too
+    let dpd0 = (decimal[decimal_byte_index] as u16) >> decimal_byte_shift;
+    let dpd1 = (decimal[decimal_byte_index + 1] as u16) << (8 - decimal_byte_shift);
+
+    *decimal_bit_index = start_bit_index;
+
+    Some((dpd0 | dpd1) & DPD)
 }
 
 // These methods follow the formulas given in the IEEE754-2019 standard.
@@ -829,7 +1213,7 @@ fn decode_dpd_declet_to_bcd(decimal: &[u8], decimal_bit_index: &mut usize) -> u1
 /**
 Calculate the number of digits a decimal with a given bit-width can hold.
 */
-pub(crate) fn precision_digits(storage_width_bits: usize) -> usize {
+pub(crate) const fn precision_digits(storage_width_bits: usize) -> usize {
     // p = 9k / 32 - 2
     9 * storage_width_bits / 32 - 2
 }
@@ -837,10 +1221,56 @@ pub(crate) fn precision_digits(storage_width_bits: usize) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::binary::FixedBinaryBuf;
+    use crate::{
+        binary::{
+            DynamicBinaryBuf,
+            FixedBinaryBuf,
+        },
+        OverflowError,
+    };
 
     use std::str;
 
+    /**
+    A buffer that claims to have more trailing significand bits than it has bytes for.
+    */
+    struct TruncatedBinaryBuf([u8; 4]);
+
+    impl BinaryBuf for TruncatedBinaryBuf {
+        type Exponent = i32;
+
+        fn try_exponent_from_ascii<I: Iterator<Item = u8>>(
+            _: bool,
+            _: I,
+        ) -> Result<i32, OverflowError> {
+            unreachable!("the truncated buffer is only used to decode significands")
+        }
+
+        fn try_with_at_least_storage_width_bytes(_: usize) -> Result<Self, OverflowError> {
+            unreachable!("the truncated buffer is only used to decode significands")
+        }
+
+        fn try_with_at_least_precision(
+            _: usize,
+            _: Option<&Self::Exponent>,
+        ) -> Result<Self, OverflowError> {
+            unreachable!("the truncated buffer is only used to decode significands")
+        }
+
+        // The width of a decimal128
+        fn trailing_significand_width_bits(&self) -> usize {
+            110
+        }
+
+        fn bytes_mut(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+
+        fn bytes(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
     #[test]
     fn precision_32() {
         assert_eq!(7, precision_digits(32));
@@ -871,6 +1301,47 @@ mod tests {
         assert_eq!(70, precision_digits(256));
     }
 
+    #[test]
+    fn decode_truncated_declets() {
+        let truncated = TruncatedBinaryBuf([0xff; 4]);
+
+        assert_eq!(0, decode_significand_trailing_declets(&truncated).count());
+        assert_eq!(
+            0,
+            super::decode_significand_trailing_declets_rev(&truncated).count()
+        );
+        assert_eq!(
+            0,
+            decode_significand_trailing_digits_rev(&truncated).count()
+        );
+
+        // Buffers that aren't a multiple of 32 bits have declets that don't line up with their width
+        for width in 1..=3 {
+            let narrow = DynamicBinaryBuf::<20>::try_with_at_least_storage_width_bytes(width)
+                .expect("failed to create buffer");
+
+            let _ = decode_significand_trailing_declets(&narrow).count();
+            let _ = super::decode_significand_trailing_declets_rev(&narrow).count();
+        }
+    }
+
+    #[test]
+    fn decode_dpd_declet_out_of_bounds() {
+        let dpd = [0xff, 0xff];
+
+        let mut i = 20;
+        assert_eq!(None, decode_dpd_declet_to_bcd(&dpd, &mut i));
+        assert_eq!(20, i);
+
+        let mut i = 5;
+        assert_eq!(None, decode_dpd_declet_to_bcd(&dpd, &mut i));
+        assert_eq!(5, i);
+
+        let mut i = 10;
+        assert!(decode_dpd_declet_to_bcd(&dpd, &mut i).is_some());
+        assert_eq!(0, i);
+    }
+
     #[test]
     fn encode_decode_dpd_declet_all() {
         for b0 in b'0'..=b'9' {
@@ -888,8 +1359,9 @@ mod tests {
                         &mut i,
                     );
 
-                    let decoded_ascii =
-                        decode_bcd_declet_to_ascii(decode_dpd_declet_to_bcd(&dpd, &mut i));
+                    let decoded_ascii = decode_bcd_declet_to_ascii(
+                        decode_dpd_declet_to_bcd(&dpd, &mut i).expect("declet is in bounds"),
+                    );
 
                     assert_eq!([b0, b1, b2], decoded_ascii, "{}", digits);
                 }
@@ -897,6 +1369,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn canonical_dpd_declets() {
+        let non_canonical = (0..1 << 10)
+            .filter(|dpd| !is_canonical_dpd_declet(*dpd))
+            .collect::<Vec<_>>();
+
+        assert_eq!(24, non_canonical.len());
+
+        // The unused bits are the 2 most significant, and only exist when every digit is large
+        for dpd in non_canonical {
+            assert_ne!(0, dpd & 0b11_0000_0000, "{:#012b}", dpd);
+            assert_eq!(0b00_0110_1110, dpd & 0b00_0110_1110, "{:#012b}", dpd);
+            assert!(is_canonical_dpd_declet(dpd & 0b00_1111_1111));
+        }
+    }
+
     #[test]
     fn encode_decode_dpd_declets_across_bytes() {
         let digits = "277386910789029981476348954311894750984836542397645";
@@ -917,7 +1405,7 @@ mod tests {
 
         let mut decoded_digits = Vec::new();
         while i > 0 {
-            let bcd = decode_dpd_declet_to_bcd(&dpd, &mut i);
+            let bcd = decode_dpd_declet_to_bcd(&dpd, &mut i).expect("declet is in bounds");
             let ascii = decode_bcd_declet_to_ascii(bcd);
 
             decoded_digits.extend(ascii);
@@ -946,6 +1434,83 @@ mod tests {
         assert_eq!(digits, decoded);
     }
 
+    #[test]
+    fn encode_significand_trailing_digits_single_declet() {
+        for digits in ["0", "1", "9", "12", "90", "123", "999", "100"] {
+            let mut decimal = FixedBinaryBuf::<4, i32>::ZERO;
+
+            let msd = encode_significand_trailing_digits(&mut decimal, [digits.as_bytes()]);
+
+            let decoded = decode_significand_trailing_declets(&decimal)
+                .flatten()
+                .map(|b| b as char)
+                .collect::<String>();
+
+            assert_eq!(0, msd.get_bcd(), "{}", digits);
+            assert_eq!(format!("{:0>6}", digits), decoded);
+
+            // Splitting the digits into multiple chunks, like the integer and fractional
+            // parts of `1.5`, should produce exactly the same result
+            if digits.len() > 1 {
+                let (head, tail) = digits.as_bytes().split_at(1);
+
+                let mut split = FixedBinaryBuf::<4, i32>::ZERO;
+
+                let msd = encode_significand_trailing_digits(&mut split, [head, tail]);
+
+                assert_eq!(0, msd.get_bcd(), "{}", digits);
+                assert_eq!(decimal.bytes(), split.bytes(), "{}", digits);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_significand_trailing_declets_rev() {
+        let digits = "129054729387659";
+
+        let mut decimal = FixedBinaryBuf::<8, i32>::ZERO;
+
+        encode_significand_trailing_digits(&mut decimal, [digits.as_bytes()]);
+
+        let decoded = super::decode_significand_trailing_declets_rev(&decimal)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .flatten()
+            .map(|b| b as char)
+            .collect::<String>();
+
+        assert_eq!(digits, decoded);
+    }
+
+    #[test]
+    fn encode_decode_significand_trailing_digits_rev() {
+        for (digits, expected_trailing, expected_msd) in [
+            ("", "000000000000000", 0),
+            ("1", "000000000000001", 0),
+            ("1234", "000000000001234", 0),
+            ("129054729387659", "129054729387659", 0),
+            ("8129054729387659", "129054729387659", 8),
+            ("78129054729387659", "129054729387659", 8),
+        ] {
+            let mut decimal = FixedBinaryBuf::<8, i32>::ZERO;
+
+            let msd = encode_significand_trailing_digits_rev(&mut decimal, digits.bytes().rev());
+
+            let decoded = decode_significand_trailing_digits_rev(&decimal)
+                .map(|b| b as char)
+                .collect::<String>();
+
+            assert_eq!(
+                expected_trailing,
+                decoded.chars().rev().collect::<String>(),
+                "{}",
+                digits
+            );
+            assert_eq!(expected_msd, msd.get_bcd(), "{}", digits);
+        }
+    }
+
     #[test]
     fn encode_decode_significand_trailing_digits_repeat() {
         for digit in b'0'..=b'9' {
@@ -2,6 +2,59 @@
 User-facing types that wrap a decimal number encoded in a buffer.
 */
 
+macro_rules! default {
+    ($d:ident) => {
+        /**
+        The default decimal is zero.
+
+        This is the same decimal as parsing `0`, with a positive sign and an exponent of `0`.
+        */
+        impl Default for $d {
+            fn default() -> $d {
+                $d::zero()
+            }
+        }
+    };
+}
+
+macro_rules! precision {
+    ($d:ident) => {
+        impl $d {
+            /**
+            The number of significant digits the decimal can store.
+
+            For fixed-width decimals this is the same as `DIGITS`. For a dynamically sized decimal
+            it depends on the width it was encoded with.
+            */
+            pub fn precision(&self) -> u32 {
+                $crate::binary::BinaryBuf::precision_digits(&self.0) as u32
+            }
+
+            /**
+            The largest adjusted exponent of a finite decimal, called `emax` in IEEE 754.
+
+            The adjusted exponent is the exponent of the decimal when it's written with a single
+            digit before the decimal point, like `1.5e384`. So for a decimal64, the largest finite
+            value is `9.999999999999999e384`. This is different from `MAX_10_EXP`, which is the
+            exponent applied to the decimal's whole coefficient.
+            */
+            pub fn max_exponent(&self) -> i32 {
+                $crate::binary::emax($crate::binary::BinaryBuf::storage_width_bits(&self.0))
+            }
+
+            /**
+            The smallest adjusted exponent of a normal decimal, called `emin` in IEEE 754.
+
+            This is always `1 - max_exponent()`. Decimals with a smaller adjusted exponent, like
+            `1e-390` in a decimal64, are subnormal, and have fewer significant digits available.
+            */
+            pub fn min_exponent(&self) -> i32 {
+                $crate::binary::emin($crate::binary::BinaryBuf::storage_width_bits(&self.0))
+            }
+        }
+    };
+}
+
 macro_rules! classify {
     ($d:ident) => {
         impl $d {
@@ -12,6 +65,16 @@ macro_rules! classify {
                 $crate::binary::is_sign_negative(&self.0)
             }
 
+            /**
+            Whether or not the sign bit is unset.
+
+            This is the opposite of [`is_sign_negative`](#method.is_sign_negative), so it's `true`
+            for `0` and `nan`, and `false` for `-0` and `-nan`.
+            */
+            pub fn is_sign_positive(&self) -> bool {
+                !$crate::binary::is_sign_negative(&self.0)
+            }
+
             /**
             Whether or not the decimal is a finite number.
             */
@@ -19,6 +82,15 @@ macro_rules! classify {
                 $crate::binary::is_finite(&self.0)
             }
 
+            /**
+            Whether or not the decimal is an infinity or NaN.
+
+            This is the opposite of [`is_finite`](#method.is_finite).
+            */
+            pub fn is_special(&self) -> bool {
+                !$crate::binary::is_finite(&self.0)
+            }
+
             /**
             Whether or not the decimal is an infinity.
             */
@@ -26,6 +98,29 @@ macro_rules! classify {
                 $crate::binary::is_infinite(&self.0)
             }
 
+            /**
+            Whether or not the decimal is a zero.
+
+            Zeroes are considered equal regardless of their sign or exponent, so `0`, `-0`, `0.00`,
+            and `0e5` are all zero. This only inspects the encoded digits, so it's much cheaper than
+            formatting the decimal.
+            */
+            pub fn is_zero(&self) -> bool {
+                $crate::ops::decimal_is_zero(&self.0)
+            }
+
+            /**
+            Whether the decimal is a finite whole number.
+
+            A decimal is whole if it has no fractional part, even if its exponent is negative, so
+            `1.0`, `120e2`, and `0.000` are all whole, but `1.5` and `1e-1` aren't. Infinities and NaNs
+            aren't whole. This only inspects the encoded digits, so it's much cheaper than formatting
+            the decimal.
+            */
+            pub fn is_integer(&self) -> bool {
+                $crate::ops::decimal_is_integer(&self.0)
+            }
+
             /**
             Whether the decimal is not a NaN.
             */
@@ -41,26 +136,1267 @@ macro_rules! classify {
             }
 
             /**
-            Whether the decimal is a sNaN.
+            Whether the decimal is a sNaN.
+            */
+            pub fn is_signaling_nan(&self) -> bool {
+                $crate::binary::is_signaling_nan(&self.0)
+            }
+
+            /**
+            Whether the unused bits of an infinity or NaN are all zero.
+
+            Infinities only use their sign and the first few bits of their combination field, and
+            NaNs also use their signaling bit and payload. Decimals created by this library always
+            leave the rest of their bits zero, but decimals read from bytes produced elsewhere might
+            not. This method returns `false` for an infinity or NaN with any of those bits set.
+            Finite decimals always return `true`.
+            */
+            pub fn has_canonical_special(&self) -> bool {
+                $crate::binary::is_canonical_special(&self.0)
+            }
+
+            /**
+            Whether the decimal is the canonical encoding of its value.
+
+            Besides the unused bits checked by [`has_canonical_special`](#method.has_canonical_special),
+            densely packed decimal has a few bit patterns for groups of digits that decode to the
+            same digits as another pattern. Decimals created by this library always use the
+            canonical patterns, but decimals read from bytes produced elsewhere might not. Two
+            canonical decimals with the same sign, exponent, and digits always have the same bytes.
+            */
+            pub fn is_canonical(&self) -> bool {
+                $crate::binary::is_canonical(&self.0)
+            }
+
+            /**
+            Re-encode the decimal using the canonical encoding of its value.
+
+            This replaces any non-canonical bit patterns, and clears any unused bits in infinities
+            and NaNs, without changing the decimal's sign, exponent, digits, or NaN payload. This is
+            useful for sanitizing decimals read from bytes produced elsewhere before comparing or
+            hashing their bytes. If the decimal is already [canonical](#method.is_canonical) then
+            the result has the same bytes.
+            */
+            pub fn to_canonical(&self) -> $d {
+                $d($crate::binary::canonicalize(&self.0))
+            }
+
+            /**
+            Classify the decimal as a zero, subnormal, normal, infinity, or NaN.
+
+            This is a single call that can be matched on instead of checking several methods
+            like [`is_finite`](#method.is_finite) and [`is_nan`](#method.is_nan) in turn.
+            The sign of the decimal is ignored, so `-0` is classified as a zero.
+            */
+            pub fn classify(&self) -> $crate::DecimalClass {
+                use $crate::{
+                    binary::{
+                        is_infinite,
+                        is_nan,
+                        is_signaling_nan,
+                        is_subnormal,
+                    },
+                    ops::decimal_is_zero,
+                    DecimalClass,
+                };
+
+                if is_nan(&self.0) {
+                    if is_signaling_nan(&self.0) {
+                        DecimalClass::SignalingNan
+                    } else {
+                        DecimalClass::QuietNan
+                    }
+                } else if is_infinite(&self.0) {
+                    DecimalClass::Infinite
+                } else if decimal_is_zero(&self.0) {
+                    DecimalClass::Zero
+                } else if is_subnormal(&self.0) {
+                    DecimalClass::Subnormal
+                } else {
+                    DecimalClass::Normal
+                }
+            }
+        }
+    };
+}
+
+macro_rules! canonical {
+    ($d:ident => [u8; $n:literal]) => {
+        impl $d {
+            /**
+            Try create a decimal from its representation as a byte array in little endian,
+            rejecting it if it's not the canonical encoding of its value.
+
+            This is like [`from_le_bytes`](#method.from_le_bytes), but fails if
+            [`is_canonical`](#method.is_canonical) would return `false`. Decimals exchanged between
+            systems should use canonical encodings so that equal values always have equal bytes.
+            The error can be detected with
+            [`Error::is_non_canonical`](crate::Error::is_non_canonical).
+            */
+            pub fn try_from_le_bytes_canonical(bytes: [u8; $n]) -> Result<$d, $crate::Error> {
+                let decimal = $d::from_le_bytes(bytes);
+
+                if !decimal.is_canonical() {
+                    Err($crate::ConvertError::non_canonical(stringify!($d)))?;
+                }
+
+                Ok(decimal)
+            }
+        }
+    };
+    ($d:ident => &[u8]) => {
+        impl $d {
+            /**
+            Try create a decimal from the given buffer, rejecting it if it's not the canonical
+            encoding of its value.
+
+            This is like [`try_from_le_bytes`](#method.try_from_le_bytes), but also fails if
+            [`is_canonical`](#method.is_canonical) would return `false`. Decimals exchanged between
+            systems should use canonical encodings so that equal values always have equal bytes.
+            The error can be detected with
+            [`Error::is_non_canonical`](crate::Error::is_non_canonical).
+            */
+            pub fn try_from_le_bytes_canonical(bytes: &[u8]) -> Result<$d, $crate::Error> {
+                let decimal = $d::try_from_le_bytes(bytes)?;
+
+                if !decimal.is_canonical() {
+                    Err($crate::ConvertError::non_canonical(stringify!($d)))?;
+                }
+
+                Ok(decimal)
+            }
+        }
+    };
+}
+
+macro_rules! cmp {
+    ($d:ident) => {
+        /**
+        Compare decimals by their numeric value.
+
+        Different encodings of the same value are equal, so `1.0`, `1.00`, and `1e0` are all
+        equal, as are `0`, `-0`, and `0e5`. Infinities are equal to infinities with the same sign.
+        NaNs aren't equal to anything, including themselves.
+
+        To compare decimals by their exact encoding, compare their bytes instead. To use decimals
+        as keys that distinguish different encodings of the same value, see [`TotalOrd`](crate::TotalOrd).
+        */
+        impl PartialEq for $d {
+            fn eq(&self, other: &Self) -> bool {
+                $crate::ops::decimal_cmp(&self.0, &other.0) == Some(core::cmp::Ordering::Equal)
+            }
+        }
+
+        /**
+        Order decimals by their numeric value.
+
+        Different encodings of the same value are equal, so `1000` and `1e3` are neither less
+        than nor greater than each other. Negative infinity is less than every finite number, and
+        positive infinity is greater than every finite number. NaNs aren't ordered, so comparing
+        a NaN with anything returns `None`.
+
+        To sort decimals that might include NaNs, see [`TotalOrd`](crate::TotalOrd).
+        */
+        impl PartialOrd for $d {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                $crate::ops::decimal_cmp(&self.0, &other.0)
+            }
+        }
+
+        /**
+        Hash decimals consistently with their numeric value.
+
+        Decimals that are equal have equal hashes, so `1.0` and `1.00` hash the same, as do `0`
+        and `-0`. NaNs aren't equal to anything, so decimals don't implement `Eq`, but their hashes
+        can be used to group or deduplicate decimals by value.
+        */
+        impl core::hash::Hash for $d {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                $crate::ops::decimal_hash(&self.0, state)
+            }
+        }
+
+        impl $d {
+            /**
+            Compare decimals using the IEEE 754 `totalOrder` predicate.
+
+            Unlike `partial_cmp`, every decimal is ordered, including NaNs, so this method can be
+            used with `sort_by` to sort decimals deterministically. From least to greatest,
+            the order is:
+
+            - negative quiet NaNs, then negative signaling NaNs, ordered by descending payload.
+            - negative infinity.
+            - negative finite numbers, with equal values ordered by descending exponent, so `-1.0`
+              comes before `-1.00`.
+            - `-0`, then `0`.
+            - positive finite numbers, with equal values ordered by ascending exponent, so `1.00`
+              comes before `1.0`.
+            - positive infinity.
+            - positive signaling NaNs, then positive quiet NaNs, ordered by ascending payload.
+
+            Decimals are only equal if they have the same sign, exponent, and coefficient.
+            To use this ordering for keys in collections like `BTreeMap`, see [`TotalOrd`](crate::TotalOrd).
+            */
+            pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+                $crate::ops::decimal_total_cmp(&self.0, &other.0)
+            }
+
+            /**
+            The smaller of this decimal and `other`.
+
+            This is IEEE 754's `minimumNumber`, so if only one of the decimals is a NaN then the
+            other is returned. If both are NaNs then this decimal is returned as a quiet NaN.
+
+            Decimals are compared using [`total_cmp`](#method.total_cmp), so `-0` is smaller than
+            `0`, and when the decimals are equal but in different cohorts the result is still
+            deterministic. The minimum of `1.5` and `1.50` is `1.50`, and the minimum of `-1.5` and
+            `-1.50` is `-1.5`.
+            */
+            pub fn min_num(&self, other: &Self) -> $d {
+                $d($crate::ops::decimal_min(&self.0, &other.0))
+            }
+
+            /**
+            The larger of this decimal and `other`.
+
+            This is IEEE 754's `maximumNumber`, so if only one of the decimals is a NaN then the
+            other is returned. If both are NaNs then this decimal is returned as a quiet NaN.
+
+            Decimals are compared using [`total_cmp`](#method.total_cmp), so `0` is larger than
+            `-0`, and the maximum of `1.5` and `1.50` is `1.5`.
+            */
+            pub fn max_num(&self, other: &Self) -> $d {
+                $d($crate::ops::decimal_max(&self.0, &other.0))
+            }
+
+            /**
+            Bound this decimal to the range `[min, max]`.
+
+            If the decimal is less than `min` then `min` is returned, and if it's greater than
+            `max` then `max` is returned. Otherwise the decimal is returned unchanged. Decimals are
+            compared by their numeric value, so a decimal that's equal to one of the bounds keeps
+            its own cohort, and `1.50` clamped to `[1.0, 2.00]` is still `1.50`.
+
+            If this decimal is a NaN then it's returned unchanged. In debug builds, this method
+            panics if `min` is greater than `max`, or if either of them is a NaN.
+            */
+            pub fn clamp(&self, min: &Self, max: &Self) -> $d {
+                $d($crate::ops::decimal_clamp(&self.0, &min.0, &max.0))
+            }
+
+            /**
+            Whether or not the decimal falls between `lo` and `hi`.
+
+            The decimals are compared by their numeric value, so `1.0` is between `1` and `2`
+            when `inclusive` is `true`. If `inclusive` is `false` then the decimal must be strictly
+            greater than `lo` and strictly less than `hi`.
+
+            If any of the decimals are NaN then this method returns `false`.
+            If `lo` is greater than `hi` then no decimal falls between them, so this method also
+            returns `false`.
+            */
+            pub fn is_between(&self, lo: &Self, hi: &Self, inclusive: bool) -> bool {
+                use core::cmp::Ordering;
+
+                use $crate::ops::decimal_cmp;
+
+                match decimal_cmp(&lo.0, &hi.0) {
+                    Some(Ordering::Less | Ordering::Equal) => (),
+                    _ => return false,
+                }
+
+                match (decimal_cmp(&lo.0, &self.0), decimal_cmp(&self.0, &hi.0)) {
+                    (Some(Ordering::Less), Some(Ordering::Less)) => true,
+                    (
+                        Some(Ordering::Less | Ordering::Equal),
+                        Some(Ordering::Less | Ordering::Equal),
+                    ) => inclusive,
+                    _ => false,
+                }
+            }
+
+            /**
+            Whether the absolute difference between this decimal and `other` is no more than `tolerance`.
+
+            This is useful for comparing computed decimals against expected values, where comparing
+            the exact encoding would be too strict.
+
+            The difference is calculated exactly, like an exact subtraction, so it's never rounded
+            before being compared with the tolerance. That means the result can be decided even when
+            the difference itself wouldn't be representable in this decimal's precision.
+
+            Decimals that are numerically equal are always approximately equal, including infinities
+            with the same sign. If `tolerance` is positive infinity then any non-NaN decimals are
+            approximately equal. NaNs are never approximately equal to anything, and a NaN or negative
+            tolerance is never satisfied.
+            */
+            pub fn approx_eq(&self, other: &Self, tolerance: &Self) -> bool {
+                $crate::ops::decimal_approx_eq(&self.0, &other.0, &tolerance.0)
+            }
+        }
+    };
+}
+
+macro_rules! search {
+    ($d:ident) => {
+        impl $d {
+            /**
+            Binary search a buffer of sorted little-endian decimals for `needle`.
+
+            The buffer is interpreted as an array of decimals that are each `width_bytes` wide, like
+            a memory-mapped column. Only the decimals probed by the search are decoded, so searching
+            doesn't need to deserialize the whole array first.
+
+            The decimals must be sorted by the IEEE 754 `totalOrder` predicate. That orders
+            decimals by their numeric value, with equal values like `1.0` and `1.00` ordered
+            by their exponent. Negative NaNs sort before everything else, and positive NaNs
+            sort after everything else. If the buffer isn't sorted then the result is unspecified.
+
+            The result follows the same convention as `slice::binary_search`. If `needle` is found
+            then `Ok` is returned with its index. Otherwise `Err` is returned with the index where
+            it could be inserted while keeping the buffer sorted.
+
+            # Panics
+
+            This method panics if `width_bytes` isn't a width this decimal can be decoded from, or
+            if the length of `sorted` isn't a multiple of `width_bytes`.
+            */
+            pub fn search_sorted_le(
+                sorted: &[u8],
+                width_bytes: usize,
+                needle: &Self,
+            ) -> Result<usize, usize> {
+                $crate::ops::decimal_search_sorted_le(sorted, width_bytes, &needle.0)
+            }
+        }
+    };
+}
+
+macro_rules! ord_key {
+    ($d:ident) => {
+        #[cfg(feature = "alloc")]
+        impl $d {
+            /**
+            Encode this decimal into a key whose byte order matches its total order.
+
+            Comparing two keys with `memcmp`, or as byte slices, gives the same result as comparing
+            their decimals by the IEEE 754 `totalOrder` predicate. That orders decimals by their
+            numeric value, with equal values like `1.0` and `1.00` ordered by their exponent.
+            Negative NaNs sort before everything else, and positive NaNs sort after everything
+            else. This makes keys suitable for storing decimals in sorted key-value stores.
+
+            Unlike `as_le_bytes`, keys aren't a fixed width, and can't be converted back into a
+            decimal. The key is made up of:
+
+            1. A sign byte, `0x00` for negative and `0x01` for positive.
+            2. A class byte, `0x00` for zero, `0x01` for other finite numbers, `0x02` for infinity,
+               `0x03` for signaling NaNs, and `0x04` for quiet NaNs.
+            3. For zeroes, the exponent. For other finite numbers, the adjusted exponent, which is
+               the position of the most significant digit, followed by the significant digits with
+               trailing zeroes removed, followed by the exponent. For NaNs, the number of digits
+               in the payload, followed by its digits.
+
+            Exponents are encoded as 4 big-endian bytes with the sign bit flipped. Digits are packed
+            two to a byte, with each digit `d` encoded as `d + 1`, and end with a zero nibble.
+            For negative decimals, every byte after the sign byte is inverted.
+
+            Keys are only comparable with other keys produced by the same version of this library.
+            */
+            pub fn to_ord_key(&self) -> alloc::vec::Vec<u8> {
+                let mut key = alloc::vec::Vec::new();
+                $crate::ops::decimal_ord_key(&self.0, |b| key.push(b));
+
+                key
+            }
+        }
+    };
+}
+
+macro_rules! total_ord {
+    ($d:ident) => {
+        impl PartialEq for $crate::TotalOrd<$d> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == core::cmp::Ordering::Equal
+            }
+        }
+
+        impl Eq for $crate::TotalOrd<$d> {}
+
+        impl PartialOrd for $crate::TotalOrd<$d> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $crate::TotalOrd<$d> {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                $crate::ops::decimal_total_cmp(&(self.0).0, &(other.0).0)
+            }
+        }
+    };
+}
+
+macro_rules! nan {
+    ($d:ident) => {
+        impl $d {
+            /**
+            Transform the payload of a NaN.
+
+            If the decimal is a NaN then its payload is decoded, passed to `f`, and the result
+            is encoded as its new payload. The NaN keeps its sign, and remains quiet or signaling.
+            If the decimal isn't a NaN then it's returned unchanged, and `f` isn't called.
+
+            The width of the decimal doesn't change, so it limits how large a payload can be.
+            For a decimal32, the largest payload is `999999`. If the new payload has more digits
+            than the decimal can fit then it's clamped to the largest payload that does fit.
+            If the existing payload is too large to fit in a `u128` then the NaN is returned unchanged.
+            */
+            pub fn map_nan_payload(&self, f: impl FnOnce(u128) -> u128) -> $d {
+                let mut d = $d(self.0.clone());
+
+                $crate::ops::decimal_map_nan_payload(&mut d.0, f);
+
+                d
+            }
+
+            /**
+            Convert a signaling NaN into a quiet NaN.
+
+            The NaN keeps its sign and payload, so `-snan(5)` becomes `-nan(5)`. If the decimal is
+            already a quiet NaN, or isn't a NaN at all, then it's returned unchanged.
+            */
+            pub fn to_quiet(&self) -> $d {
+                let mut d = $d(self.0.clone());
+
+                $crate::binary::set_nan_signaling(&mut d.0, false);
+
+                d
+            }
+
+            /**
+            Convert a quiet NaN into a signaling NaN.
+
+            The NaN keeps its sign and payload, so `-nan(5)` becomes `-snan(5)`. If the decimal is
+            already a signaling NaN, or isn't a NaN at all, then it's returned unchanged.
+            */
+            pub fn to_signaling(&self) -> $d {
+                let mut d = $d(self.0.clone());
+
+                $crate::binary::set_nan_signaling(&mut d.0, true);
+
+                d
+            }
+        }
+    };
+}
+
+macro_rules! cohort {
+    ($d:ident) => {
+        impl $d {
+            /**
+            The number of trailing zeroes in the coefficient of the decimal.
+
+            The coefficient is the integer significand, without the exponent, so `1.200` has 2
+            trailing zeroes, and `120` has 1. A decimal with trailing zeroes can be encoded with
+            fewer digits and a larger exponent without changing its value.
+
+            If the decimal is zero, infinite, or NaN then this method returns `0`.
+            */
+            pub fn coefficient_trailing_zeros(&self) -> u32 {
+                $crate::ops::decimal_coefficient_trailing_zeros(&self.0)
+            }
+
+            /**
+            Decompose the decimal into its sign, coefficient, and exponent.
+
+            The value of the decimal is its coefficient, which is an integer, multiplied by `10`
+            raised to its exponent. So `-123.456` decomposes into `true` for the sign, the digits
+            `123456`, and an exponent of `-3`. The digits are yielded as ASCII from most to least
+            significant, without leading zeroes. A zero coefficient is the single digit `0`.
+            These parts can be passed back into [`from_digits`](#method.from_digits) to recreate
+            an equal decimal.
+
+            If the decimal is infinite or NaN, or its exponent doesn't fit in an `i32`, then this
+            method returns `None`.
+            */
+            pub fn coefficient_and_exponent(
+                &self,
+            ) -> Option<(bool, $crate::CoefficientDigits<'_>, i32)> {
+                $crate::ops::decimal_coefficient_and_exponent(&self.0)
+            }
+
+            /**
+            The exponent of the most significant digit of the decimal.
+
+            This is the exponent the decimal would be written with in scientific notation with a
+            single digit before the decimal point, so `123.456` has an adjusted exponent of `2`,
+            as in `1.23456e2`, and `0.00123` has `-3`. Unlike the exponent of the coefficient, it
+            doesn't depend on trailing zeroes, so `1.2` and `1.200` have the same adjusted exponent.
+            The adjusted exponent of a zero is its exponent.
+
+            If the decimal is infinite or NaN, or its adjusted exponent doesn't fit in an `i32`,
+            then this method returns `None`.
+            */
+            pub fn adjusted_exponent(&self) -> Option<i32> {
+                $crate::ops::decimal_adjusted_exponent(&self.0)
+            }
+
+            /**
+            Try create a decimal from its sign, coefficient, and exponent.
+
+            The value of the decimal is its coefficient multiplied by `10` raised to its exponent,
+            so `true`, `123456`, and `-3` is the decimal `-123.456`. This is the inverse of
+            [`coefficient_and_exponent`](#method.coefficient_and_exponent) for coefficients that
+            fit in a `u128`. The coefficient's digits are encoded directly, so trailing zeroes are
+            kept and there's no need to format it as text first.
+
+            This method will fail if the coefficient has more digits than the decimal can store,
+            or the exponent is outside of its range.
+            */
+            pub fn from_coefficient_exponent(
+                is_negative: bool,
+                coefficient: u128,
+                exponent: i32,
+            ) -> Result<$d, $crate::Error> {
+                Ok($d($crate::convert::decimal_from_coefficient_exponent(
+                    is_negative,
+                    coefficient,
+                    exponent,
+                )?))
+            }
+
+            /**
+            Whether this decimal has the same quantum as another.
+
+            This is the IEEE 754 `sameQuantum` predicate. Finite decimals have the same quantum
+            when their exponents are equal, regardless of their values, so `1.23` and `4.56` do,
+            but `1.2` and `1.20` don't. This is a cheap way to check that a set of values share
+            the same scale. Infinities have the same quantum as each other, and so do NaNs.
+            */
+            pub fn same_quantum(&self, other: &$d) -> bool {
+                $crate::ops::decimal_same_quantum(&self.0, &other.0)
+            }
+
+            /**
+            The smallest and largest exponents this decimal's value could be encoded with.
+
+            The value of a decimal can be encoded with a smaller exponent by padding its coefficient
+            with trailing zeroes, as long as they fit in its precision. It can be encoded with a
+            larger exponent by removing trailing zeroes from its coefficient. Both are limited by
+            the range of exponents the decimal can encode. For example, `1.200` in a decimal64
+            could be encoded with an exponent anywhere from `-15`, as `1.200000000000000`, to `-1`,
+            as `1.2`. Zero can be encoded with any exponent.
+
+            The exponents are the same as the ones that scientific notation is written with when
+            the coefficient is an integer, so `1.2` has an exponent of `-1`, as in `12e-1`.
+
+            If the decimal is infinite or NaN, or the exponents don't fit in an `i32`, then this
+            method returns `None`.
+            */
+            pub fn cohort_exponent_range(&self) -> Option<(i32, i32)> {
+                $crate::ops::decimal_cohort_exponent_range(&self.0)
+            }
+
+            /**
+            Encode the value of this decimal with the given exponent, without rounding.
+
+            Lowering the exponent pads the coefficient with trailing zeroes, and raising it removes
+            them, so `1.2` rescaled to an exponent of `-3` is `1.200`. The result has the same
+            precision as this decimal, so the exponent must be within its
+            [`cohort_exponent_range`](#method.cohort_exponent_range).
+
+            If the decimal is infinite or NaN, or its value can't be encoded with the exponent
+            without rounding, then this method will return an error.
+            */
+            pub fn rescale(&self, exponent: i32) -> Result<$d, $crate::Error> {
+                Ok($d($crate::ops::decimal_rescale(&self.0, exponent)?))
+            }
+
+            /**
+            Encode the value of this decimal with the given exponent, like a fixed number of decimal places.
+
+            This is useful for storing values at a known scale, like money in cents with an
+            exponent of `-2`. `1.5` quantized to `-2` is `1.50`. Unlike [`rescale`](#method.rescale),
+            this method distinguishes the reasons a value can't be encoded with the exponent. If it
+            has non-zero digits below the exponent, like `1.234` quantized to `-2`, then the error
+            is [inexact](crate::ConvertError::is_inexact). If padding it with trailing zeroes would
+            need more digits than the decimal can store, or the exponent is outside its range, then
+            the error is an [overflow](crate::ConvertError::is_overflow).
+
+            If the decimal is NaN then the result is a quiet NaN, and if it's infinite then the result
+            is NaN.
+            */
+            pub fn quantize(&self, exponent: i32) -> Result<$d, $crate::ConvertError> {
+                Ok($d($crate::ops::decimal_quantize(&self.0, exponent)?))
+            }
+
+            /**
+            Encode the value of this decimal with the same exponent as another, without rounding.
+
+            This is useful for storing a computed value with the same scale as an existing one,
+            like matching the number of decimal places in a price. `1.2` quantized like `0.001`
+            is `1.200`. If the value can't be encoded with the reference's exponent then this
+            method will return an error, in the same way as [`quantize`](#method.quantize).
+
+            If either decimal is NaN then the result is a quiet NaN. If both are infinite then the
+            result is this decimal, and if only one is infinite then the result is NaN.
+            */
+            pub fn quantize_like(&self, reference: &$d) -> Result<$d, $crate::ConvertError> {
+                Ok($d($crate::ops::decimal_quantize_like(
+                    &self.0,
+                    &reference.0,
+                )?))
+            }
+
+            /**
+            Remove trailing zeroes from the coefficient of the decimal without changing its value.
+
+            This is the IEEE 754 `reduce` operation, which picks the member of the decimal's
+            cohort with the largest exponent. Trailing zeroes of integers are removed too, so
+            `1.2300` reduces to `1.23`, and `100` reduces to `1e2`. The exponent can't be raised
+            beyond its maximum, so very large decimals may keep some trailing zeroes.
+
+            Zeroes reduce to `0`, with an exponent of `0` and the same sign. Infinities and NaNs
+            are returned unchanged.
+            */
+            pub fn reduce(&self) -> $d {
+                $d($crate::ops::decimal_reduce(&self.0))
+            }
+
+            /**
+            Rescale every decimal in a slice to the same exponent, without rounding.
+
+            This is useful for giving a column of values a common scale, like a set of prices
+            that should all have two decimal places. Each decimal is replaced in place with the
+            result of [`rescale`](#method.rescale).
+
+            If any decimal can't be rescaled then this method stops and returns an error, whose
+            [`Error::index`](crate::Error::index) is the position of that decimal in the slice.
+            Decimals before it will have already been rescaled, and decimals after it are left
+            unchanged.
+            */
+            pub fn rescale_all(values: &mut [$d], exponent: i32) -> Result<(), $crate::Error> {
+                for (index, value) in values.iter_mut().enumerate() {
+                    *value = value.rescale(exponent).map_err(|err| err.at_index(index))?;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+macro_rules! pow10 {
+    ($d:ident) => {
+        impl $d {
+            /**
+            Split the decimal into a quotient and remainder by `10^n`.
+
+            The quotient is the integer part of the decimal divided by `10^n`, and the remainder is
+            the rest, so the decimal is equal to `quotient * 10^n + remainder`. For example, splitting
+            `12345.678` by `10^2` gives `123` and `45.678`. Splitting by `10^0` gives the integer and
+            fractional parts of the decimal. Both parts have the same sign as the decimal, and the
+            remainder has the same exponent.
+
+            This method only moves digits between the two parts, so it never needs to round.
+            If the decimal is infinite or NaN, or the quotient is too large to encode, then this
+            method will return `None`.
+            */
+            pub fn divmod_pow10(&self, n: i32) -> Option<($d, $d)> {
+                let (quotient, remainder) = $crate::ops::decimal_divmod_pow10(&self.0, n)?;
+
+                Some(($d(quotient), $d(remainder)))
+            }
+
+            /**
+            The unit in the last place of the decimal.
+
+            This is `1` scaled by the decimal's exponent, so it depends on the decimal's cohort
+            as well as its value. `1.23` has a unit in the last place of `0.01`, while `1.230` has
+            `0.001`, and `1.2e5` has `1e4`. The result is always positive.
+
+            Zeroes don't have a last place, so their unit in the last place is the smallest
+            positive decimal that can be encoded. Infinities have a unit in the last place of
+            positive infinity, and NaNs have a quiet NaN without a payload.
+            */
+            pub fn ulp(&self) -> $d {
+                $d($crate::ops::decimal_ulp(&self.0))
+            }
+        }
+    };
+}
+
+macro_rules! next {
+    ($d:ident) => {
+        impl $d {
+            /**
+            The next representable decimal toward positive infinity.
+
+            This is IEEE 754's `nextUp`. The result is the smallest decimal that's greater than
+            this one, using the smallest exponent it can, so `1` steps up to `1.000001` in a
+            `Bitstring32`. Zeroes step up to the smallest positive subnormal, the largest finite
+            decimal steps up to positive infinity, and negative infinity steps up to the
+            largest negative finite decimal. NaNs return a quiet NaN.
+
+            Decimals with a dynamic width, like `Bitstring` and `BigBitstring`, step within the
+            width they're currently encoded in, and the result is never widened. A `Bitstring`
+            that holds `1` in 32 bits steps up to `1.000001`, and the largest finite decimal in
+            32 bits steps up to positive infinity, even though a wider encoding could represent
+            decimals between them.
+            */
+            pub fn next_up(&self) -> $d {
+                $d($crate::ops::decimal_next_up(&self.0))
+            }
+
+            /**
+            The next representable decimal toward negative infinity.
+
+            This is IEEE 754's `nextDown`. The result is the largest decimal that's less than
+            this one, using the smallest exponent it can, so `1` steps down to `0.9999999` in a
+            `Bitstring32`. Zeroes step down to the smallest negative subnormal, the largest
+            negative finite decimal steps down to negative infinity, and positive infinity steps
+            down to the largest finite decimal. NaNs return a quiet NaN.
+
+            Like [`next_up`](#method.next_up), decimals with a dynamic width step within the width
+            they're currently encoded in, and the result is never widened.
+            */
+            pub fn next_down(&self) -> $d {
+                $d($crate::ops::decimal_next_down(&self.0))
+            }
+        }
+    };
+}
+
+macro_rules! arith {
+    ($d:ident) => {
+        impl $d {
+            /**
+            Add `other` to this decimal, returning `None` if the exact sum can't be encoded.
+
+            The sum is never rounded. Its exponent is the smaller of the two exponents, unless
+            trailing zeroes need to be removed for it to fit, so `1.50 + 1` is `2.50`. If the sum
+            has more significant digits than the precision of the wider decimal, or falls outside
+            the range of exponents it can encode, then this method will return `None`. A sum of
+            zero is `0`, unless both decimals are negative, in which case it's `-0`.
+
+            If either decimal is a NaN then the first NaN is returned as a quiet NaN. The sum of
+            two infinities with different signs is a quiet NaN.
+            */
+            pub fn checked_add(&self, other: &Self) -> Option<$d> {
+                Some($d($crate::ops::decimal_checked_add(&self.0, &other.0)?))
+            }
+
+            /**
+            Subtract `other` from this decimal, returning `None` if the exact difference can't
+            be encoded.
+
+            This is the same as adding the negation of `other` with
+            [`checked_add`](#method.checked_add), so `1 - 1` is `0`, and `-0 - 0` is `-0`.
+            */
+            pub fn checked_sub(&self, other: &Self) -> Option<$d> {
+                Some($d($crate::ops::decimal_checked_sub(&self.0, &other.0)?))
+            }
+
+            /**
+            Multiply this decimal by `other`, returning `None` if the exact product can't be
+            encoded.
+
+            The product is never rounded. Its exponent is the sum of the two exponents, unless
+            trailing zeroes need to be removed or added for it to fit, so `1.5 * 1.0` is `1.50`.
+            If the product has more significant digits than the precision of the wider decimal,
+            or falls outside the range of exponents it can encode, then this method will return
+            `None`. The product is negative if exactly one of the decimals is, including zeroes.
+
+            If either decimal is a NaN then the first NaN is returned as a quiet NaN. The product
+            of an infinity and a zero is a quiet NaN.
+            */
+            pub fn checked_mul(&self, other: &Self) -> Option<$d> {
+                Some($d($crate::ops::decimal_checked_mul(&self.0, &other.0)?))
+            }
+        }
+    };
+}
+
+macro_rules! dynamic {
+    ($d:ident) => {
+        impl $d {
+            /**
+            Convert this decimal into a dynamically sized `Bitstring` with the same width.
+
+            The bytes of the decimal are copied as-is, so the conversion is exact and doesn't
+            change its encoding. It's also cheaper than converting through text.
+            */
+            pub fn to_dynamic(&self) -> $crate::Bitstring {
+                $crate::Bitstring::try_from_le_bytes(self.as_le_bytes())
+                    .expect("fixed-width decimals always fit in a `Bitstring`")
+            }
+        }
+
+        impl From<$d> for $crate::Bitstring {
+            fn from(d: $d) -> $crate::Bitstring {
+                d.to_dynamic()
+            }
+        }
+
+        // The bytes of the dynamic decimal are copied as-is, so it
+        // can only be converted if it has exactly the same width
+        impl TryFrom<$crate::Bitstring> for $d {
+            type Error = $crate::Error;
+
+            fn try_from(d: $crate::Bitstring) -> Result<$d, $crate::Error> {
+                let bytes = d.as_le_bytes();
+
+                match bytes.try_into() {
+                    Ok(bytes) => Ok($d::from_le_bytes(bytes)),
+                    Err(_) => Err($crate::OverflowError::exact_size_mismatch(
+                        bytes.len(),
+                        $d::ZERO.as_le_bytes().len(),
+                        "the decimal must have the same width to convert",
+                    )
+                    .into()),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! try_d2d {
+    ($from:ident => $to:ident) => {
+        /**
+        Try convert a wider decimal into a narrower one.
+
+        This uses the same rules as `try_fit`, so the value of the decimal never changes.
+        Trailing zeroes may be removed from its significand to make it fit, but if it still needs
+        more precision or exponent range than the narrower decimal supports, or it's a NaN with a
+        payload that doesn't fit, then the conversion fails instead of rounding.
+        */
+        impl TryFrom<$crate::$from> for $to {
+            type Error = $crate::Error;
+
+            fn try_from(d: $crate::$from) -> Result<$to, $crate::Error> {
+                d.try_fit()
+            }
+        }
+    };
+}
+
+macro_rules! fit {
+    ($d:ident) => {
+        impl $crate::bitstring::private::Sealed for $d {
+            fn try_fit_from<D: $crate::binary::BinaryBuf>(
+                decimal: &D,
+            ) -> Result<Self, $crate::Error> {
+                Ok($d($crate::convert::decimal_try_fit(decimal)?))
+            }
+
+            fn cmp_value_from<D: $crate::bitstring::private::Sealed>(
+                &self,
+                other: &D,
+            ) -> Option<core::cmp::Ordering> {
+                other.cmp_value_buf(&self.0)
+            }
+
+            fn cmp_value_buf<D: $crate::binary::BinaryBuf>(
+                &self,
+                other: &D,
+            ) -> Option<core::cmp::Ordering> {
+                $crate::ops::decimal_cmp(&self.0, other)
+            }
+
+            #[cfg(feature = "serde")]
+            fn serialize_as_number<S: ::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    $crate::serde::serialize_decimal_as_number(&self.0, serializer, self)
+                } else {
+                    ::serde::Serialize::serialize(self, serializer)
+                }
+            }
+        }
+
+        impl $crate::Decimal for $d {}
+
+        impl $d {
+            /**
+            Try convert the decimal into a decimal of another type, which may be narrower.
+
+            If the decimal has more digits or a larger exponent than `D` can encode, then trailing
+            zeroes are removed from its significand to try make it fit. That means `1.2300`
+            will fit into a [`Bitstring32`](crate::Bitstring32), even if it's stored with more
+            digits in a wider decimal, like `1.230000000`. The value of the decimal never changes,
+            so this method will only fail if the decimal still needs more precision or exponent
+            range than `D` supports after removing all its trailing zeroes.
+
+            The cohort of the decimal may change, so the result may have fewer trailing zeroes
+            than the original. If the decimal already fits into `D` then its cohort is kept.
+            Infinities and NaNs are always kept, as long as a NaN's payload fits.
+            */
+            pub fn try_fit<D: $crate::Decimal>(&self) -> Result<D, $crate::Error> {
+                D::try_fit_from(&self.0)
+            }
+        }
+    };
+}
+
+macro_rules! scaleb {
+    ($d:ident) => {
+        impl $d {
+            /**
+            Multiply the decimal by `10^n`, saturating at the limits of its exponent range.
+
+            Scaling a decimal by a power of ten only changes its exponent. If the new exponent is
+            outside the range the decimal can encode then trailing zeroes are removed from its
+            significand, or added to it, to try make it fit exactly. If it still doesn't fit then:
+
+            - if `n` is positive, the result is the decimal's [`max`](#method.max) value, or
+              [`min`](#method.min) if it's negative. The result is always finite, so scaling
+              never saturates to an infinity.
+            - if `n` is negative, the result is `0`, or `-0` if it's negative. The result is never
+              a non-zero decimal that's been rounded, even if one exists.
+
+            Zeroes don't have any digits to lose, so scaling one never saturates. Instead, its
+            exponent is clamped to the nearest one the decimal can encode, changing its cohort. In a
+            [`Bitstring32`](crate::Bitstring32), scaling `0` by `1000` gives `0e90`, and scaling it
+            by `-1000` gives `0e-101`. The sign of a zero is kept.
+
+            Infinities and NaNs are returned unchanged.
+            */
+            pub fn saturating_scaleb(&self, n: i32) -> $d {
+                match $crate::ops::decimal_scaleb(&self.0, n) {
+                    Ok(d) => $d(d),
+                    Err(_) if n > 0 => {
+                        if self.is_sign_negative() {
+                            $d::min()
+                        } else {
+                            $d::max()
+                        }
+                    }
+                    Err(_) => {
+                        if self.is_sign_negative() {
+                            -$d::zero()
+                        } else {
+                            $d::zero()
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! neg {
+    ($d:ident) => {
+        /**
+        Negate the decimal by flipping its sign bit.
+
+        This doesn't consider the class of the decimal, so the sign of every decimal is flipped,
+        including infinities and NaNs. A NaN keeps its payload, and remains quiet or signaling.
+        Negating `0` produces `-0`, which is encoded differently even though it has the same value.
+        */
+        impl core::ops::Neg for $d {
+            type Output = $d;
+
+            fn neg(mut self) -> $d {
+                $crate::binary::negate(&mut self.0);
+
+                self
+            }
+        }
+
+        /**
+        Negate the decimal by flipping its sign bit.
+
+        This returns a new decimal, leaving the original untouched.
+        See the by-value implementation for details on how NaNs and zeroes are negated.
+        */
+        impl<'a> core::ops::Neg for &'a $d {
+            type Output = $d;
+
+            fn neg(self) -> $d {
+                -$d(self.0.clone())
+            }
+        }
+
+        impl $d {
+            /**
+            Whether the sign bit of the decimal is set.
+
+            This is the same as [`is_sign_negative`](#method.is_sign_negative), but is named for
+            working with the decimal's encoding directly. The sign bit is the most significant
+            bit of the decimal, so reading it doesn't decode anything else.
+            */
+            pub fn sign_bit(&self) -> bool {
+                $crate::binary::is_sign_negative(&self.0)
+            }
+
+            /**
+            Get a copy of the decimal with its sign bit set to `is_negative`.
+
+            Only the sign bit is changed, so like negation, this works the same way for every
+            decimal, including infinities and NaNs. Setting the sign bit of `0` produces `-0`.
+            */
+            pub fn with_sign_bit(&self, is_negative: bool) -> $d {
+                let mut decimal = self.0.clone();
+                $crate::binary::set_sign_negative(&mut decimal, is_negative);
+
+                $d(decimal)
+            }
+
+            /**
+            Get the absolute value of the decimal.
+
+            This is the IEEE 754 `abs` operation, which clears the sign bit and leaves everything
+            else untouched. That includes infinities and NaNs, so the absolute value of `-snan(7)`
+            is `snan(7)`. The absolute value of `-0` is `0`.
+            */
+            pub fn abs(&self) -> $d {
+                self.with_sign_bit(false)
+            }
+
+            /**
+            Get a copy of the decimal with the sign of `sign`.
+
+            This is the IEEE 754 `copySign` operation, which copies the sign bit of `sign` and leaves
+            everything else untouched. That includes infinities and NaNs, so the sign of a NaN can
+            be copied onto a number, and the sign of a number can be copied onto a NaN.
+            */
+            pub fn copysign(&self, sign: &$d) -> $d {
+                self.with_sign_bit(sign.sign_bit())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "raw-encoding")]
+macro_rules! raw {
+    ($d:ident) => {
+        impl $d {
+            /**
+            Iterate over the raw densely-packed-decimal declets in the trailing significand.
+
+            Each declet is 3 decimal digits compressed into 10 bits. The declets are yielded from
+            most to least significant, which is the same order `raw::encode_finite` accepts them in.
+            The 10 bits of each declet are in the lower bits of the returned value, so the most
+            significant bit of the declet is bit 9, and the upper 6 bits are always `0`.
+
+            The most significant digit of the decimal isn't part of the trailing significand,
+            so it isn't yielded. All declets are yielded, including any leading zeroes, so a
+            decimal64 always yields 5 declets. The declets of infinities and NaNs are yielded
+            as they're encoded.
+            */
+            pub fn iter_declets(&self) -> impl Iterator<Item = u16> + '_ {
+                $crate::binary::decode_significand_trailing_dpd_declets(&self.0)
+            }
+        }
+    };
+}
+
+macro_rules! d2s {
+    ($d:ident) => {
+        impl core::fmt::Debug for $d {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::convert::decimal_to_fmt(&self.0, f)
+            }
+        }
+
+        /**
+        Format the decimal as text.
+
+        If a precision is given, like `{:.2}`, then the decimal is written in fixed notation with
+        exactly that many fractional digits. Missing digits are padded with zeroes, and extra digits
+        are truncated rather than rounded, so `1.99` is written as `1.9` with `{:.1}`.
+        Infinities and NaNs ignore the precision.
+        */
+        impl core::fmt::Display for $d {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                match f.precision() {
+                    Some(precision) => {
+                        $crate::convert::decimal_to_fmt_with_precision(&self.0, precision, f)
+                    }
+                    None => $crate::convert::decimal_to_fmt(&self.0, f),
+                }
+            }
+        }
+
+        impl core::fmt::LowerExp for $d {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::convert::decimal_to_fmt_with_options(
+                    &self.0,
+                    &$crate::FormatOptions::new().scientific_notation(true),
+                    f,
+                )
+            }
+        }
+
+        impl core::fmt::UpperExp for $d {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::convert::decimal_to_fmt_with_options(
+                    &self.0,
+                    &$crate::FormatOptions::new()
+                        .scientific_notation(true)
+                        .uppercase_exponent(true),
+                    f,
+                )
+            }
+        }
+
+        impl $d {
+            /**
+            Write the decimal as text into the given writer.
+
+            This writes the same text as the decimal's `Display` implementation, but directly into
+            `out` rather than through a `Formatter`. That makes it convenient for writing multiple
+            decimals into a single buffer without allocating.
+            */
+            pub fn write_to<W: core::fmt::Write>(&self, out: &mut W) -> core::fmt::Result {
+                $crate::convert::decimal_to_fmt(&self.0, out)
+            }
+
+            /**
+            Write the decimal as text into the given byte buffer, returning the written text.
+
+            This writes the same text as the decimal's `Display` implementation, but doesn't need
+            an allocator or a `fmt::Write` implementation. If the buffer is too small to fit the text then
+            an error is returned instead, which reports the number of bytes needed through its
+            `required_width_bytes` method.
+            */
+            pub fn write_str_into<'b>(
+                &self,
+                buf: &'b mut [u8],
+            ) -> Result<&'b str, $crate::OverflowError> {
+                $crate::convert::decimal_to_str_buf(&self.0, buf)
+            }
+
+            /**
+            Write the decimal as text in engineering notation into the given writer.
+
+            Engineering notation is like scientific notation, except the exponent is always a multiple
+            of three, so `1.23e4` is written as `12.3e3`, and `0.0000123` is written as `12.3e-6`. The
+            exponent is always written, even when it's zero. If there aren't enough digits to fill the
+            integer part then it's padded with zeroes, so `1e5` is written as `100e3`. The value of
+            the decimal is always preserved, so the text can be parsed back into an equal decimal.
+
+            Infinities and NaNs are written the same as they are by `Display`.
+            */
+            pub fn write_engineering_to<W: core::fmt::Write>(
+                &self,
+                out: &mut W,
+            ) -> core::fmt::Result {
+                $crate::convert::decimal_to_engineering_fmt(&self.0, out)
+            }
+
+            /**
+            Format the decimal with the given options.
+
+            The returned value implements `Display`. With the default options, it produces the same
+            text as the decimal's own `Display` implementation. The options only affect how the decimal
+            is written, not which digits are written, so the text can always be parsed back into the
+            same decimal.
+            */
+            pub fn display_with(
+                &self,
+                options: $crate::FormatOptions,
+            ) -> impl core::fmt::Display + '_ {
+                $crate::convert::DisplayWith(&self.0, options)
+            }
+
+            /**
+            Format just the digits of the decimal's coefficient.
+
+            The returned value implements `Display`. It writes the coefficient without its sign,
+            decimal point, or exponent, so `12.34` is written as `1234`, and `-1.5e3` is written
+            as `15`. This is useful for assembling custom text representations of decimals.
+
+            If `keep_leading_zeroes` is `false` then leading zeroes are skipped, so `0.05` is written
+            as `5`, and zero is written as `0`. If it's `true` then every digit the decimal can store
+            is written, so a decimal64 always writes 16 digits. Trailing zeroes are always written,
+            since they're significant: `1.50` is written as `150`.
+
+            Infinities and NaNs don't have a coefficient in the usual sense, so the digits of their
+            trailing significand are written instead. For a NaN, that's its payload, so `nan(123)`
+            is written as `123`.
+            */
+            pub fn coefficient_display(
+                &self,
+                keep_leading_zeroes: bool,
+            ) -> impl core::fmt::Display + '_ {
+                $crate::convert::CoefficientDisplay(&self.0, keep_leading_zeroes)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl $d {
+            /**
+            Format the decimal using the General Decimal Arithmetic `to-scientific-string` rules.
+
+            This is the format used by the `.decTest` conformance vectors, so it's useful for testing
+            this crate against them. It's different from the `Display` implementation:
+            exponential notation is used whenever the exponent is positive, or the number would
+            need more than 6 leading zeroes after the decimal point, and exponents are always
+            written with a sign, as in `1.23E+5`. Infinities are written as `Infinity`, and NaNs
+            as `NaN` or `sNaN`, with any payload directly following, as in `NaN123`.
+            */
+            pub fn to_gda_string(&self) -> alloc::string::String {
+                let mut s = alloc::string::String::new();
+                $crate::convert::decimal_to_gda_fmt(&self.0, &mut s)
+                    .expect("writing to a string never fails");
+
+                s
+            }
+
+            /**
+            Format the decimal in engineering notation.
+
+            See `write_engineering_to` for details on the format.
+            */
+            pub fn to_engineering_string(&self) -> alloc::string::String {
+                let mut s = alloc::string::String::new();
+                $crate::convert::decimal_to_engineering_fmt(&self.0, &mut s)
+                    .expect("writing to a string never fails");
+
+                s
+            }
+        }
+    };
+}
+
+macro_rules! d2v {
+    ($d:ident) => {
+        #[cfg(feature = "alloc")]
+        impl $d {
+            /**
+            Get an owned copy of the underlying bitstring buffer.
+
+            The bytes are always in little-endian byte-order, regardless of the endianness
+            of the platform.
             */
-            pub fn is_signaling_nan(&self) -> bool {
-                $crate::binary::is_signaling_nan(&self.0)
+            pub fn to_le_bytes_vec(&self) -> alloc::vec::Vec<u8> {
+                self.as_le_bytes().to_vec()
             }
         }
     };
 }
 
-macro_rules! d2s {
+macro_rules! d2b {
     ($d:ident) => {
-        impl core::fmt::Debug for $d {
+        // Bytes are written in little-endian byte-order, so the sign and combination field
+        // are in the last group of bits. The alternate flag `{:#b}` separates each byte with
+        // an underscore, as in `10100011_00000000_01010000_00100010` for the decimal32 `123`.
+        impl core::fmt::Binary for $d {
             fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-                $crate::convert::decimal_to_fmt(&self.0, f)
-            }
-        }
+                use core::fmt::Write;
 
-        impl core::fmt::Display for $d {
-            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-                $crate::convert::decimal_to_fmt(&self.0, f)
+                for (i, b) in self.as_le_bytes().iter().enumerate() {
+                    if i > 0 && f.alternate() {
+                        f.write_char('_')?;
+                    }
+
+                    write!(f, "{:08b}", b)?;
+                }
+
+                Ok(())
             }
         }
     };
@@ -78,14 +1414,124 @@ macro_rules! try_s2d {
                 Ok($d($crate::convert::decimal_from_str(s)?))
             }
 
+            /**
+            Try parse a decimal from a string with the given options.
+
+            With the default options, this method accepts the same text as `try_parse_str`.
+            */
+            pub fn try_parse_str_with(
+                s: &str,
+                options: $crate::ParseOptions,
+            ) -> Result<$d, $crate::Error> {
+                Ok($d($crate::convert::decimal_from_str_with_options(
+                    s,
+                    &options,
+                    <$b>::default(),
+                )?))
+            }
+
+            /**
+            Try parse a decimal from a string, also returning details about how it was encoded.
+
+            If the exponent of the decimal is too large to encode as written, but the decimal
+            can still be encoded exactly by padding its significand with zeroes, then its exponent
+            will be clamped. The returned `ParseStatus` will report whether that happened.
+            Other methods that parse decimals from text, like `try_parse_str`, fail instead.
+            */
+            pub fn parse_with_status(s: &str) -> Result<($d, $crate::ParseStatus), $crate::Error> {
+                let (decimal, status) = $crate::convert::decimal_from_str_with_status(s)?;
+
+                Ok(($d(decimal), status))
+            }
+
+            /**
+            Try parse a decimal from a string, and encode it with the given exponent.
+
+            This is useful for parsing values for a column with a fixed scale, like a
+            `NUMERIC(10, 4)` in SQL, which always has 4 decimal places. The value is encoded with
+            `exponent`, so parsing `1.5` with an exponent of `-4` gives `1.5000`. If the value is
+            written with fewer decimal places than that then it's padded with trailing zeroes.
+
+            If the value is written with more decimal places than that, like `1.23456`, then it
+            would need rounding, and this method will fail. It will also fail if the value can't
+            be parsed, or it's infinite or NaN.
+            */
+            pub fn parse_at_scale(s: &str, exponent: i32) -> Result<$d, $crate::Error> {
+                Ok($d($crate::ops::decimal_rescale_fit(
+                    &$crate::convert::decimal_from_str(s)?,
+                    exponent,
+                )?))
+            }
+
             /**
             Try parse a decimal from some formattable value.
 
-            This method can avoid needing to buffer an entire number upfront.
+            This method can avoid needing to buffer an entire number upfront. If the number has
+            more trailing zeroes in its fractional part than can fit then they're dropped, rather
+            than failing. This means `1.2300000000` parses into a `Bitstring32` as `1.230000`.
             */
             pub fn try_parse(n: impl core::fmt::Display) -> Result<$d, $crate::Error> {
                 Ok($d($crate::convert::decimal_from_fmt(n, <$b>::default())?))
             }
+
+            /**
+            Try create a decimal from one parsed by a [`DecimalParser`]($crate::text::DecimalParser).
+
+            This is useful for parsing a decimal incrementally, like from chunks of a network
+            stream, and then encoding it once it's been fully parsed. This method will fail if the
+            decimal has too many digits, or its exponent is too large, to fit.
+            */
+            pub fn try_from_parsed<B: $crate::text::TextBuf>(
+                parsed: $crate::text::ParsedDecimal<B>,
+            ) -> Result<$d, $crate::Error> {
+                Ok($d($crate::convert::decimal_from_parsed(parsed)?))
+            }
+
+            /**
+            Try create a decimal from a stream of ASCII digits and an exponent.
+
+            The digits are given from most to least significant, like `b"12345"`, and are
+            treated as an integer that's multiplied by `10` raised to the `exponent`. So the digits
+            `b"12345"` with an exponent of `-2` are the decimal `123.45`. Leading zeroes are ignored.
+
+            The digits are consumed lazily, so they don't need to be collected into a string first.
+            If the digits contain anything besides `0`-`9`, there are no digits at all, or there are
+            too many digits to fit in the decimal, then this method will fail. If there are far
+            too many digits then this method will fail before consuming all of them.
+
+            Decimals that grow to fit their digits, like `BigBitstring`, never run out of space,
+            so they'll keep consuming digits for as long as the iterator yields them. Leading
+            zeroes aren't buffered either, so an endless stream of them is consumed forever by any
+            decimal. Iterators from untrusted sources should be bounded first, like with
+            [`Iterator::take`].
+
+            The parts returned by [`coefficient_and_exponent`](#method.coefficient_and_exponent)
+            can be passed back into this method to recreate an equal decimal:
+
+            ```
+            use decstr::Bitstring64;
+
+            let d = Bitstring64::try_parse_str("-123.450")?;
+
+            let (is_negative, digits, exponent) = d.coefficient_and_exponent().unwrap();
+            let roundtrip = Bitstring64::from_digits(is_negative, digits, exponent)?;
+
+            assert_eq!("-123.450", roundtrip.to_string());
+            # Ok::<(), decstr::Error>(())
+            ```
+            */
+            pub fn from_digits<I: Iterator<Item = u8>>(
+                is_negative: bool,
+                digits: I,
+                exponent: i32,
+            ) -> Result<$d, $crate::Error> {
+                Ok($d($crate::convert::decimal_from_digits(
+                    is_negative,
+                    digits,
+                    exponent,
+                    <$b>::default(),
+                )?))
+            }
         }
 
         impl<'a> TryFrom<&'a str> for $d {
@@ -106,6 +1552,132 @@ macro_rules! try_s2d {
     };
 }
 
+macro_rules! try_tagged_s2d {
+    ($d:ident) => {
+        impl $d {
+            /**
+            Try parse a decimal from a string with an optional suffix that pins its width.
+
+            The suffix is a `d` followed by the width of the decimal in bits, like `1.5d64`.
+            The grammar is:
+
+            ```text
+            tagged = decimal [ ( "d" | "D" ) ( "32" | "64" | "128" | "256" ) ]
+            ```
+
+            where `decimal` is any text that `try_parse_str` accepts. The `d` is case-insensitive.
+
+            If there's a suffix then the decimal is encoded with exactly that width, just like
+            the fixed-width decimal of the same size. This method will fail if the decimal doesn't fit
+            in that width, or if the width is larger than this decimal supports. A suffix with any
+            other width, like `1.5d16`, or with no width at all, like `1.5d`, is a parse error.
+            If there's no suffix then the decimal will be encoded in the smallest width that fits,
+            just like `try_parse_str`.
+            */
+            pub fn parse_tagged(s: &str) -> Result<$d, $crate::Error> {
+                Ok($d($crate::convert::decimal_from_tagged_str(s)?))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "bytemuck")]
+macro_rules! bytemuck {
+    ($d:ident) => {
+        // SAFETY: The decimal is `#[repr(transparent)]` over a byte array, and every bit pattern
+        // of that array is a valid decimal, including all zeroes
+        unsafe impl ::bytemuck::Zeroable for $d {}
+        unsafe impl ::bytemuck::Pod for $d {}
+    };
+}
+
+#[cfg(feature = "proptest")]
+macro_rules! proptest {
+    ($d:ident => [u8; $n:literal]) => {
+        proptest!($d => ::proptest::strategy::Just($n));
+    };
+    ($d:ident => dynamic($max_width_bytes:literal)) => {
+        proptest!($d => (1..=$max_width_bytes / 4usize).prop_map(|n| n * 4));
+    };
+    ($d:ident => $width_bytes:expr) => {
+        impl ::proptest::arbitrary::Arbitrary for $d {
+            type Parameters = ();
+            type Strategy = ::proptest::strategy::BoxedStrategy<$d>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                use ::proptest::strategy::Strategy;
+
+                ($width_bytes)
+                    .prop_flat_map($crate::proptest::arbitrary_decimal)
+                    .prop_map($d)
+                    .boxed()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+macro_rules! serde {
+    ($d:ident => [u8; $n:literal]) => {
+        serde!($d => |bytes: &[u8]| {
+            <[u8; $n]>::try_from(bytes).ok().map($d::from_le_bytes)
+        } => concat!("exactly ", stringify!($n), " bytes"));
+    };
+    ($d:ident => dynamic($expected_len:expr)) => {
+        serde!($d => |bytes: &[u8]| $d::try_from_le_bytes(bytes).ok() => $expected_len);
+    };
+    ($d:ident => $from_le_bytes:expr => $expected_len:expr) => {
+        // Decimals are serialized as text in human-readable formats, which preserves any
+        // trailing zeroes, and as their little-endian bytes in binary formats
+        impl ::serde::Serialize for $d {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.collect_str(self)
+                } else {
+                    serializer.serialize_bytes(self.as_le_bytes())
+                }
+            }
+        }
+
+        // Decimals can be deserialized from either text or numbers in human-readable formats
+        impl<'de> ::serde::Deserialize<'de> for $d {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for Visitor {
+                    type Value = $d;
+
+                    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                        f.write_str("a decimal number")
+                    }
+
+                    fn visit_str<E: ::serde::de::Error>(self, v: &str) -> Result<$d, E> {
+                        $d::try_parse_str(v).map_err(E::custom)
+                    }
+
+                    fn visit_i64<E: ::serde::de::Error>(self, v: i64) -> Result<$d, E> {
+                        $d::try_from(v).map_err(E::custom)
+                    }
+
+                    fn visit_u64<E: ::serde::de::Error>(self, v: u64) -> Result<$d, E> {
+                        $d::try_from(v).map_err(E::custom)
+                    }
+
+                    fn visit_bytes<E: ::serde::de::Error>(self, v: &[u8]) -> Result<$d, E> {
+                        ($from_le_bytes)(v).ok_or_else(|| E::invalid_length(v.len(), &$expected_len))
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(Visitor)
+                } else {
+                    deserializer.deserialize_bytes(Visitor)
+                }
+            }
+        }
+    };
+}
+
 macro_rules! i2d {
     ($i:ident => $convert:ident => $d:ident) => {
         impl $d {
@@ -178,6 +1750,62 @@ macro_rules! try_i2d {
     };
 }
 
+macro_rules! b2d {
+    ($convert:ident => $d:ident) => {
+        impl $d {
+            /**
+            Convert a boolean into a decimal.
+
+            `true` is converted into `1`, and `false` is converted into `0`.
+            */
+            pub fn $convert(b: bool) -> $d {
+                $d($crate::convert::decimal_from_int(b as u8).expect("infallible conversion"))
+            }
+        }
+
+        impl From<bool> for $d {
+            fn from(b: bool) -> $d {
+                $d::$convert(b)
+            }
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn $convert() {
+            assert_eq!(
+                $d::from_u8(1).as_le_bytes(),
+                $d::$convert(true).as_le_bytes()
+            );
+            assert_eq!(
+                $d::from_u8(0).as_le_bytes(),
+                $d::$convert(false).as_le_bytes()
+            );
+        }
+    };
+}
+
+macro_rules! scaled_i2d {
+    ($i:ident => $convert:ident => $d:ident) => {
+        impl $d {
+            /**
+            Try convert an integer into a decimal, scaled down by `scale` powers of ten.
+
+            The result is `value × 10^-scale`, so `12345` with a scale of `2` is `123.45`. This is
+            useful for converting amounts stored as integer minor units, like cents, into decimals.
+            The integer's digits are encoded directly with an exponent of `-scale`, so trailing
+            zeroes are kept: `1200` with a scale of `2` is `12.00`.
+
+            This method will fail if the integer has more digits than the decimal can store, or
+            the scale is outside of its exponent range. A zero is encoded with the nearest exponent
+            in range instead.
+            */
+            pub fn $convert(value: $i, scale: u32) -> Result<$d, $crate::Error> {
+                Ok($d($crate::convert::decimal_from_scaled_int(value, scale)?))
+            }
+        }
+    };
+}
+
 macro_rules! try_d2i {
     ($d:ident => $convert:ident => $i:ident) => {
         impl $d {
@@ -227,11 +1855,83 @@ macro_rules! try_d2i {
     };
 }
 
+macro_rules! try_d2nz {
+    ($d:ident => $convert:ident => $nz:ident => $to_int:ident) => {
+        impl $d {
+            /**
+            Try convert a decimal into a non-zero integer.
+
+            This is the same as converting the decimal into an integer, but also returns `None`
+            if the decimal is zero, including `-0` and zeroes with a fractional part like `0.00`.
+            */
+            pub fn $convert(&self) -> Option<core::num::$nz> {
+                self.$to_int().and_then(core::num::$nz::new)
+            }
+        }
+    };
+}
+
+macro_rules! try_d2i_exact {
+    ($d:ident => $convert:ident => $i:ident) => {
+        impl $d {
+            /**
+            Try convert a decimal into an integer, reporting why it failed.
+
+            Unlike the `Option`-returning conversion, the error distinguishes between a decimal that's
+            too large to fit in the integer, where [`ConvertError::is_overflow`](crate::ConvertError::is_overflow)
+            is `true`, and a decimal that has a fractional part, where
+            [`ConvertError::is_non_integer`](crate::ConvertError::is_non_integer) is `true`.
+            The decimal is never truncated or rounded. Infinities and NaNs are reported as overflowing.
+            */
+            pub fn $convert(&self) -> Result<$i, $crate::ConvertError> {
+                $crate::convert::decimal_to_int(&self.0)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "float")]
+macro_rules! f2d_precision {
+    ($f:ident => $convert:ident => $d:ident) => {
+        impl $d {
+            /**
+            Convert a binary floating point into a decimal with a fixed number of significant digits.
+
+            The exact value of the float is rounded to `sig_digits` significant digits, like the
+            `%.*e` format in `printf`. Trailing zeroes are kept, so `0.5` with 3 significant digits
+            is `5.00e-1`. This is useful for matching the decimal export of other systems exactly,
+            such as `%.17g`, which always round-trips a `f64`.
+
+            This is different from the `From` conversion, which picks the shortest decimal that
+            round-trips back into the same float, so `0.1` is converted into `0.1` rather than
+            `0.10000000000000001`. Prefer the `From` conversion unless you need a specific number
+            of digits.
+
+            If `sig_digits` is zero then this method will fail with a
+            [convert](crate::Error::is_convert) error. If it's larger than the precision of this
+            decimal then it will fail with an [overflow](crate::Error::is_overflow) error.
+            Infinities and NaNs are converted the same way as the `From` conversion.
+            */
+            pub fn $convert(f: $f, sig_digits: u8) -> Result<$d, $crate::Error> {
+                Ok($d($crate::convert::decimal_from_f64_precision(
+                    f, sig_digits,
+                )?))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "float")]
 macro_rules! f2d {
     ($f:ident => $convert:ident => $d:ident) => {
         impl $d {
             /**
             Convert a binary floating point into a decimal.
+
+            The decimal has the shortest digits that convert back into exactly the same float,
+            so `0.1` is converted into the decimal `0.1`, even though the float's exact value is
+            slightly larger. Integral floats are converted with an exponent of `0`, just like
+            integers, so `1.0` is converted into the decimal `1` and `-0.0` into `-0`.
             */
             pub fn $convert(f: $f) -> $d {
                 $d($crate::convert::decimal_from_binary_float(f).expect("infallible conversion"))
@@ -259,6 +1959,7 @@ macro_rules! f2d {
     };
 }
 
+#[cfg(feature = "float")]
 macro_rules! d2f {
     ($d:ident => $convert:ident => $f:ident) => {
         impl $d {
@@ -296,6 +1997,7 @@ macro_rules! d2f {
     };
 }
 
+#[cfg(feature = "float")]
 macro_rules! try_f2d {
     ($f:ident => $convert:ident => $d:ident) => {
         impl $d {
@@ -347,6 +2049,7 @@ macro_rules! try_f2d {
     };
 }
 
+#[cfg(feature = "float")]
 macro_rules! try_d2f {
     ($d:ident => $convert:ident => $f:ident) => {
         impl $d {
@@ -394,10 +2097,241 @@ macro_rules! try_d2f {
     };
 }
 
+#[cfg(feature = "float")]
+macro_rules! d2f_round {
+    ($d:ident => $convert:ident => $f:ident) => {
+        impl $d {
+            /**
+            Try convert a decimal into a binary floating point, rounding it with the given mode.
+
+            The conversion is done exactly, without going through the standard library, so the
+            result is the same on every platform. If the rounded number is infinite then this
+            method returns `None`. Rounding modes that round towards zero return the largest
+            finite number instead, so `1e400` rounds to `f64::MAX` with [`RoundingMode::TowardZero`].
+            Numbers that are too small round to zero or the smallest subnormal number, keeping
+            their sign. Infinities and NaNs are converted the same way as any other mode.
+
+            [`RoundingMode::TowardZero`]: crate::RoundingMode::TowardZero
+            */
+            pub fn $convert(&self, mode: $crate::RoundingMode) -> Option<$f> {
+                $crate::convert::decimal_to_binary_float_round(&self.0, mode).ok()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "num-traits")]
+macro_rules! num {
+    ($d:ident) => {
+        // Conversions from primitive numbers
+        // Conversions that can't be encoded exactly return `None` instead of rounding
+        impl num_traits::FromPrimitive for $d {
+            fn from_i64(n: i64) -> Option<$d> {
+                $d::try_from(n).ok()
+            }
+
+            fn from_i128(n: i128) -> Option<$d> {
+                $d::try_from(n).ok()
+            }
+
+            fn from_u64(n: u64) -> Option<$d> {
+                $d::try_from(n).ok()
+            }
+
+            fn from_u128(n: u128) -> Option<$d> {
+                $d::try_from(n).ok()
+            }
+
+            #[cfg(feature = "float")]
+            fn from_f32(n: f32) -> Option<$d> {
+                Some($d($crate::convert::decimal_from_binary_float(n).ok()?))
+            }
+
+            #[cfg(feature = "float")]
+            fn from_f64(n: f64) -> Option<$d> {
+                Some($d($crate::convert::decimal_from_binary_float(n).ok()?))
+            }
+
+            // Without the `float` feature, binary floating points can't be converted exactly,
+            // so they're not converted at all instead of being truncated into integers
+            #[cfg(not(feature = "float"))]
+            fn from_f32(_: f32) -> Option<$d> {
+                None
+            }
+
+            #[cfg(not(feature = "float"))]
+            fn from_f64(_: f64) -> Option<$d> {
+                None
+            }
+        }
+
+        // Conversions into primitive numbers
+        // Fractional decimals can't be converted into integers, so they return `None` instead
+        // of truncating
+        impl num_traits::ToPrimitive for $d {
+            fn to_i64(&self) -> Option<i64> {
+                $crate::convert::decimal_to_int(&self.0).ok()
+            }
+
+            fn to_i128(&self) -> Option<i128> {
+                $crate::convert::decimal_to_int(&self.0).ok()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                $crate::convert::decimal_to_int(&self.0).ok()
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                $crate::convert::decimal_to_int(&self.0).ok()
+            }
+
+            #[cfg(feature = "float")]
+            fn to_f32(&self) -> Option<f32> {
+                $crate::convert::decimal_to_binary_float(&self.0).ok()
+            }
+
+            #[cfg(feature = "float")]
+            fn to_f64(&self) -> Option<f64> {
+                $crate::convert::decimal_to_binary_float(&self.0).ok()
+            }
+
+            #[cfg(not(feature = "float"))]
+            fn to_f32(&self) -> Option<f32> {
+                None
+            }
+
+            #[cfg(not(feature = "float"))]
+            fn to_f64(&self) -> Option<f64> {
+                None
+            }
+        }
+
+        /**
+        Add two decimals exactly.
+
+        This is the same as [`checked_add`](#method.checked_add), but panics if the exact sum
+        can't be encoded. It's only implemented with the `num-traits` feature, because `Zero`
+        and `CheckedAdd` need it. See [the crate docs](crate#panicking-operators) for details.
+
+        # Panics
+
+        This operator panics if the exact sum can't be encoded in the width of the wider
+        decimal, like `1e90 + 1e-101` in a `Bitstring32`.
+        */
+        impl core::ops::Add for $d {
+            type Output = $d;
+
+            fn add(self, other: $d) -> $d {
+                self.checked_add(&other)
+                    .expect("the sum can't be encoded exactly")
+            }
+        }
+
+        /**
+        Multiply two decimals exactly.
+
+        This is the same as [`checked_mul`](#method.checked_mul), but panics if the exact product
+        can't be encoded. It's only implemented with the `num-traits` feature, because `One`
+        and `CheckedMul` need it. See [the crate docs](crate#panicking-operators) for details.
+
+        # Panics
+
+        This operator panics if the exact product can't be encoded in the width of the wider
+        decimal, like `9999999 * 9999999` in a `Bitstring32`.
+        */
+        impl core::ops::Mul for $d {
+            type Output = $d;
+
+            fn mul(self, other: $d) -> $d {
+                self.checked_mul(&other)
+                    .expect("the product can't be encoded exactly")
+            }
+        }
+
+        // Zeroes in any cohort, like `0` and `-0.00`, are zero
+        impl num_traits::Zero for $d {
+            fn zero() -> $d {
+                $d::zero()
+            }
+
+            fn is_zero(&self) -> bool {
+                $d::is_zero(self)
+            }
+        }
+
+        impl num_traits::One for $d {
+            fn one() -> $d {
+                $d::from(1u8)
+            }
+        }
+
+        // Checked arithmetic returns `None` instead of rounding
+        impl num_traits::CheckedAdd for $d {
+            fn checked_add(&self, other: &$d) -> Option<$d> {
+                $d::checked_add(self, other)
+            }
+        }
+
+        impl num_traits::CheckedMul for $d {
+            fn checked_mul(&self, other: &$d) -> Option<$d> {
+                $d::checked_mul(self, other)
+            }
+        }
+    };
+}
+
+/**
+A decimal number type from this library.
+
+This trait is sealed, so it can't be implemented outside of this library. It's used to write
+methods that are generic over the decimal types they produce, like `try_fit`.
+*/
+pub trait Decimal: private::Sealed {
+    /**
+    Compare this decimal with another by their numeric value, even if they're different widths.
+
+    This is like the [`PartialOrd`] implementation for decimals of the same type, but works across
+    types without needing to widen either of them first. Different encodings of the same value,
+    like a [`Bitstring32`](crate::Bitstring32) `1.5` and a [`Bitstring64`](crate::Bitstring64)
+    `1.50`, compare as equal, as do `0` and `-0`.
+
+    If either decimal is a NaN then they're unordered and `None` is returned.
+    */
+    fn cmp_value<D: Decimal>(&self, other: &D) -> Option<core::cmp::Ordering> {
+        // The other decimal hands its buffer back to this one to compare against
+        other.cmp_value_from(self)
+    }
+}
+
+pub(crate) mod private {
+    pub trait Sealed: Sized {
+        fn try_fit_from<D: crate::binary::BinaryBuf>(decimal: &D) -> Result<Self, crate::Error>;
+
+        // Compare `other` against this decimal
+        fn cmp_value_from<D: Sealed>(&self, other: &D) -> Option<core::cmp::Ordering>;
+
+        // Compare this decimal against `other`
+        fn cmp_value_buf<D: crate::binary::BinaryBuf>(
+            &self,
+            other: &D,
+        ) -> Option<core::cmp::Ordering>;
+
+        #[cfg(feature = "serde")]
+        fn serialize_as_number<S: ::serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>;
+    }
+}
+
+mod bytes;
+mod class;
 mod dynamic;
 mod fixed128;
+mod fixed256;
 mod fixed32;
 mod fixed64;
+mod total_ord;
 
 #[cfg(feature = "arbitrary-precision")]
 mod arbitrary;
@@ -406,8 +2340,12 @@ mod arbitrary;
 pub use self::arbitrary::*;
 
 pub use self::{
+    bytes::*,
+    class::*,
     dynamic::*,
     fixed128::*,
+    fixed256::*,
     fixed32::*,
     fixed64::*,
+    total_ord::*,
 };
@@ -1,3 +1,8 @@
+use core::{
+    cell::OnceCell,
+    fmt,
+};
+
 use crate::{
     binary::{
         ArbitrarySizedBinaryBuf,
@@ -12,6 +17,11 @@ use crate::{
 An arbitrary precision decimal number.
 
 This type is only available when the `arbitrary-precision` feature is enabled.
+
+A `BigBitstring` is always stored on the heap, even if its value is small. Values that fit in
+256 bits, with up to 70 digits and an adjusted exponent up to `1572864`, can be stored inline in a
+[`Bitstring`](crate::Bitstring) instead, which never allocates. See
+[`Bitstring::try_parse_str_inline`](crate::Bitstring::try_parse_str_inline) for details.
 */
 pub struct BigBitstring(ArbitrarySizedBinaryBuf);
 
@@ -38,6 +48,27 @@ impl BigBitstring {
         Ok(Self(buf))
     }
 
+    /**
+    Try parse a decimal from a string, rejecting it if it has too many digits.
+
+    Since a `BigBitstring` can grow to fit any number, parsing untrusted input could
+    allocate a huge buffer. This method checks the number of digits in the significand,
+    and in the exponent, against the given limits before allocating anything, and
+    returns an error if either of them is exceeded. That error can be detected with
+    [`Error::is_limit_exceeded`]. The payload of a NaN counts as its significand.
+    */
+    pub fn try_parse_str_limited(
+        s: &str,
+        max_digits: usize,
+        max_exp_digits: usize,
+    ) -> Result<Self, Error> {
+        Ok(Self(crate::convert::decimal_from_str_limited(
+            s,
+            max_digits,
+            max_exp_digits,
+        )?))
+    }
+
     /**
     Get a reference to the underlying bitstring buffer.
 
@@ -56,6 +87,23 @@ impl BigBitstring {
         Self::from(0u8)
     }
 
+    /**
+    Get a value that formats this decimal once, and reuses that text whenever it's displayed again.
+
+    Formatting a decimal decodes all of its digits, which can be costly when its coefficient
+    has thousands of them. If the same decimal will be displayed many times, like when logging
+    it in a loop, then the returned value will only do that work the first time it's displayed.
+    The text is allocated lazily, so nothing is formatted if the value is never displayed.
+
+    For one-off formatting, the decimal's `Display` implementation avoids allocating the text.
+    */
+    pub fn formatted(&self) -> impl fmt::Display + '_ {
+        CachedDisplay {
+            decimal: self,
+            formatted: OnceCell::new(),
+        }
+    }
+
     #[cfg(test)]
     fn max() -> Option<Self> {
         None
@@ -67,16 +115,58 @@ impl BigBitstring {
     }
 }
 
+struct CachedDisplay<'a> {
+    decimal: &'a BigBitstring,
+    formatted: OnceCell<String>,
+}
+
+impl<'a> fmt::Display for CachedDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.formatted.get_or_init(|| self.decimal.to_string()))
+    }
+}
+
+default!(BigBitstring);
 classify!(BigBitstring);
+canonical!(BigBitstring => &[u8]);
+cmp!(BigBitstring);
+total_ord!(BigBitstring);
+search!(BigBitstring);
+cohort!(BigBitstring);
+neg!(BigBitstring);
+pow10!(BigBitstring);
+next!(BigBitstring);
+arith!(BigBitstring);
+nan!(BigBitstring);
+fit!(BigBitstring);
+
+#[cfg(feature = "raw-encoding")]
+raw!(BigBitstring);
 
 try_s2d!(VecTextBuf => BigBitstring);
+try_tagged_s2d!(BigBitstring);
 d2s!(BigBitstring);
+d2v!(BigBitstring);
 
+#[cfg(feature = "serde")]
+serde!(BigBitstring => dynamic("a non-zero multiple of 4 bytes"));
+
+#[cfg(feature = "proptest")]
+proptest!(BigBitstring => dynamic(32));
+
+#[cfg(feature = "float")]
 f2d!(f32 => from_f32 => BigBitstring);
+#[cfg(feature = "float")]
 f2d!(f64 => from_f64 => BigBitstring);
 
+#[cfg(feature = "float")]
 try_d2f!(BigBitstring => to_f32 => f32);
+#[cfg(feature = "float")]
+d2f_round!(BigBitstring => to_f32_round => f32);
+#[cfg(feature = "float")]
 try_d2f!(BigBitstring => to_f64 => f64);
+#[cfg(feature = "float")]
+d2f_round!(BigBitstring => to_f64_round => f64);
 
 i2d!(i8 => from_i8 => BigBitstring);
 i2d!(i16 => from_i16 => BigBitstring);
@@ -89,6 +179,13 @@ try_d2i!(BigBitstring => to_i16 => i16);
 try_d2i!(BigBitstring => to_i32 => i32);
 try_d2i!(BigBitstring => to_i64 => i64);
 try_d2i!(BigBitstring => to_i128 => i128);
+try_d2i_exact!(BigBitstring => to_i128_exact => i128);
+
+try_d2nz!(BigBitstring => to_nonzero_i8 => NonZeroI8 => to_i8);
+try_d2nz!(BigBitstring => to_nonzero_i16 => NonZeroI16 => to_i16);
+try_d2nz!(BigBitstring => to_nonzero_i32 => NonZeroI32 => to_i32);
+try_d2nz!(BigBitstring => to_nonzero_i64 => NonZeroI64 => to_i64);
+try_d2nz!(BigBitstring => to_nonzero_i128 => NonZeroI128 => to_i128);
 
 i2d!(u8 => from_u8 => BigBitstring);
 i2d!(u16 => from_u16 => BigBitstring);
@@ -96,8 +193,19 @@ i2d!(u32 => from_u32 => BigBitstring);
 i2d!(u64 => from_u64 => BigBitstring);
 i2d!(u128 => from_u128 => BigBitstring);
 
+b2d!(from_bool => BigBitstring);
+
 try_d2i!(BigBitstring => to_u8 => u8);
 try_d2i!(BigBitstring => to_u16 => u16);
 try_d2i!(BigBitstring => to_u32 => u32);
 try_d2i!(BigBitstring => to_u64 => u64);
 try_d2i!(BigBitstring => to_u128 => u128);
+
+try_d2nz!(BigBitstring => to_nonzero_u8 => NonZeroU8 => to_u8);
+try_d2nz!(BigBitstring => to_nonzero_u16 => NonZeroU16 => to_u16);
+try_d2nz!(BigBitstring => to_nonzero_u32 => NonZeroU32 => to_u32);
+try_d2nz!(BigBitstring => to_nonzero_u64 => NonZeroU64 => to_u64);
+try_d2nz!(BigBitstring => to_nonzero_u128 => NonZeroU128 => to_u128);
+
+#[cfg(feature = "num-traits")]
+num!(BigBitstring);
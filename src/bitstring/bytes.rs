@@ -0,0 +1,125 @@
+use core::fmt;
+
+use crate::{
+    binary::{
+        FINITE_COMBINATION,
+        INFINITY,
+        INFINITY_COMBINATION,
+        NAN,
+        NAN_COMBINATION,
+        SIGN_NEGATIVE,
+    },
+    bitstring::Bitstring,
+    Error,
+    OverflowError,
+};
+
+#[cfg(feature = "arbitrary-precision")]
+use crate::bitstring::BigBitstring;
+
+/**
+Format a decimal directly from its encoded bytes, without picking a concrete type for it first.
+
+The bytes are assumed to be in little-endian byte-order already.
+This method will fail if the buffer length is not a multiple of 4 bytes, it's too big to fit in a
+`Bitstring` (or a `BigBitstring` when the `arbitrary-precision` feature is enabled), or if writing to
+the output fails.
+*/
+pub fn fmt_le_bytes(bytes: &[u8], mut out: impl fmt::Write) -> Result<(), Error> {
+    let decimal = Bitstring::try_from_le_bytes(bytes);
+
+    // If the decimal is too big for a `Bitstring` then try an arbitrary precision one
+    // If the width of the bytes is invalid then this will fail in the same way
+    #[cfg(feature = "arbitrary-precision")]
+    if decimal.is_err() {
+        write!(out, "{}", BigBitstring::try_from_le_bytes(bytes)?)?;
+
+        return Ok(());
+    }
+
+    write!(out, "{}", decimal?)?;
+
+    Ok(())
+}
+
+/**
+The class of a decimal that can be determined from its most significant byte alone.
+
+Finite decimals aren't split into zeroes, subnormals, and normals, because that depends on the
+rest of their encoding. NaNs aren't split by their sign, because it doesn't affect their value.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberClass {
+    /**
+    Negative infinity.
+    */
+    NegativeInfinity,
+    /**
+    A finite number with its sign bit set, including `-0`.
+    */
+    NegativeFinite,
+    /**
+    A finite number with its sign bit unset, including `0`.
+    */
+    PositiveFinite,
+    /**
+    Positive infinity.
+    */
+    PositiveInfinity,
+    /**
+    A quiet NaN.
+    */
+    QuietNan,
+    /**
+    A signaling NaN.
+    */
+    SignalingNan,
+}
+
+/**
+Classify a decimal directly from its encoded bytes, without decoding it.
+
+The bytes are assumed to be in little-endian byte-order already. Only the most significant byte,
+which is the last one in the buffer, is inspected, so this is much cheaper than creating a decimal
+and checking it with methods like [`Bitstring::is_nan`]. That makes it useful for skipping over
+infinities and NaNs when scanning through lots of encoded decimals.
+
+This method will fail if the buffer length is not a multiple of 4 bytes, or it's too big to fit in
+a `Bitstring` (or a `BigBitstring` when the `arbitrary-precision` feature is enabled).
+*/
+pub fn class_of_le_bytes(bytes: &[u8]) -> Result<NumberClass, Error> {
+    let msb = match bytes.last() {
+        Some(msb) if bytes.len().is_multiple_of(4) => *msb,
+        _ => Err(OverflowError::exact_size_mismatch(
+            bytes.len(),
+            bytes.len() + 4 - (bytes.len() % 4),
+            "decimals must be a multiple of 32 bits (4 bytes)",
+        ))?,
+    };
+
+    // Arbitrary precision decimals can be any multiple of 32 bits wide
+    #[cfg(not(feature = "arbitrary-precision"))]
+    if bytes.len() > 20 {
+        Err(OverflowError::would_overflow(20, bytes.len()))?;
+    }
+
+    let is_negative = msb & SIGN_NEGATIVE == SIGN_NEGATIVE;
+
+    Ok(if msb & FINITE_COMBINATION != FINITE_COMBINATION {
+        if is_negative {
+            NumberClass::NegativeFinite
+        } else {
+            NumberClass::PositiveFinite
+        }
+    } else if msb & INFINITY_COMBINATION == INFINITY {
+        if is_negative {
+            NumberClass::NegativeInfinity
+        } else {
+            NumberClass::PositiveInfinity
+        }
+    } else if msb & NAN_COMBINATION == NAN {
+        NumberClass::QuietNan
+    } else {
+        NumberClass::SignalingNan
+    })
+}
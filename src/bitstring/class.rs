@@ -0,0 +1,35 @@
+/**
+The class of a decimal, like `core::num::FpCategory` for binary floating points.
+
+The sign of a decimal doesn't affect its class, so `-0` is a `Zero` and `-inf` is `Infinite`.
+Use methods like `is_sign_negative` to check the sign separately.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalClass {
+    /**
+    A zero, with any sign or exponent.
+    */
+    Zero,
+    /**
+    A non-zero finite number whose most significant digit is below the minimum exponent.
+
+    Subnormal decimals have fewer digits of precision available than normal ones.
+    */
+    Subnormal,
+    /**
+    A non-zero finite number that isn't subnormal.
+    */
+    Normal,
+    /**
+    A positive or negative infinity.
+    */
+    Infinite,
+    /**
+    A quiet NaN.
+    */
+    QuietNan,
+    /**
+    A signaling NaN.
+    */
+    SignalingNan,
+}
@@ -13,15 +13,32 @@ use crate::binary::encode_max;
 
 /**
 A dynamically sized decimal number with enough precision to fit any Rust primitive number.
+
+A `Bitstring` picks the smallest width that fits its value, in multiples of 32 bits, up to
+256 bits (32 bytes). It's always stored inline, so creating one, including parsing it from text,
+never allocates. The widest `Bitstring` is a decimal256, which can store 70 digits with an
+adjusted exponent up to `1572864`. A number with fewer digits can't reach that adjusted exponent
+unless it's padded with trailing zeroes, so `1e1572795` fits but `1e1572796` doesn't, unless it's
+parsed with `parse_with_status`. Its smallest subnormal is `1e-1572932`. Values that need more
+digits or a larger exponent than that can't be parsed into a `Bitstring`, and need to use a
+`BigBitstring` instead, which is available with the `arbitrary-precision` feature.
 */
 #[derive(Clone, Copy)]
-pub struct Bitstring(DynamicBinaryBuf<20>);
+pub struct Bitstring(DynamicBinaryBuf<32>);
 
 impl Bitstring {
     /**
     Try create a decimal from the given buffer.
 
-    The buffer is assumed to be in little-endian byte-order already.
+    The buffer is assumed to be in little-endian byte-order already. Any width that's a multiple
+    of 4 bytes, up to the 32 bytes a `Bitstring` can store, is accepted, and the decimal keeps
+    that width. That means a 4 byte buffer is read as a decimal32, an 8 byte buffer as a decimal64,
+    a 16 byte buffer as a decimal128, and so on. The decimal can be written back out with the same
+    width using [`Bitstring::as_le_bytes`].
+
+    This is different from the fixed-width decimals, like [`Bitstring64::from_le_bytes`](crate::Bitstring64::from_le_bytes),
+    which only accept an array of exactly their width.
+
     This method will fail if the buffer length is not a multiple of 4 bytes, or it's too
     big to fit in a `Bitstring`.
     */
@@ -41,15 +58,66 @@ impl Bitstring {
         Ok(Self(buf))
     }
 
+    /**
+    Create a decimal from its representation as a byte array in little endian, in a `const` context.
+
+    This is like [`Bitstring::try_from_le_bytes`], except the width of the decimal is checked when
+    it's created, so it can be used to define constants of any width a `Bitstring` supports:
+
+    ```
+    use decstr::{
+        Bitstring,
+        Bitstring64,
+    };
+
+    const RATE: Bitstring =
+        Bitstring::from_le_bytes(Bitstring64::from_coefficient_exponent_const(1234, -2).to_le_bytes());
+
+    assert_eq!("12.34", RATE.to_string());
+    assert_eq!(8, RATE.as_le_bytes().len());
+    ```
+
+    This method panics if the array isn't a multiple of 4 bytes, or is wider than 32 bytes,
+    which in a `const` item is a compile error.
+    */
+    pub const fn from_le_bytes<const N: usize>(bytes: [u8; N]) -> Self {
+        Self(DynamicBinaryBuf::from_le_bytes(bytes))
+    }
+
+    /**
+    Try parse a decimal from a string, storing it inline without allocating.
+
+    The decimal is auto-sized to the smallest width that fits it, up to a decimal256, just like
+    [`Bitstring::try_parse_str`]. This method is for services that parse many small decimals and
+    need to guarantee they never allocate. Values that need more than 256 bits, which is more than
+    70 digits or an adjusted exponent above `1572864`, fail to parse. That's the crossover point
+    where a `BigBitstring` is needed instead:
+
+    ```
+    use decstr::Bitstring;
+
+    let small = Bitstring::try_parse_str_inline("123.45").expect("failed to parse decimal");
+    assert_eq!(4, small.as_le_bytes().len());
+
+    let wide = Bitstring::try_parse_str_inline(&"9".repeat(70)).expect("failed to parse decimal");
+    assert_eq!(32, wide.as_le_bytes().len());
+
+    assert!(Bitstring::try_parse_str_inline(&"9".repeat(71)).is_err());
+    ```
+    */
+    pub fn try_parse_str_inline(s: &str) -> Result<Self, Error> {
+        Self::try_parse_str(s)
+    }
+
     /**
     Get a reference to the underlying bitstring buffer.
 
     This buffer is always stored in little-endian byte-order, regardless of the endianness
     of the platform.
     */
-    pub fn as_le_bytes(&self) -> &[u8] {
+    pub const fn as_le_bytes(&self) -> &[u8] {
         // Even on big-endian platforms we always encode numbers in little-endian order
-        self.0.bytes()
+        self.0.as_le_bytes()
     }
 
     /**
@@ -78,16 +146,49 @@ impl Bitstring {
     }
 }
 
+default!(Bitstring);
 classify!(Bitstring);
+canonical!(Bitstring => &[u8]);
+precision!(Bitstring);
+cmp!(Bitstring);
+total_ord!(Bitstring);
+search!(Bitstring);
+ord_key!(Bitstring);
+cohort!(Bitstring);
+neg!(Bitstring);
+pow10!(Bitstring);
+next!(Bitstring);
+arith!(Bitstring);
+nan!(Bitstring);
+fit!(Bitstring);
+
+#[cfg(feature = "raw-encoding")]
+raw!(Bitstring);
 
 try_s2d!(ArrayTextBuf::<128> => Bitstring);
+try_tagged_s2d!(Bitstring);
 d2s!(Bitstring);
+d2v!(Bitstring);
 
+#[cfg(feature = "serde")]
+serde!(Bitstring => dynamic("a multiple of 4 bytes, up to 32 bytes"));
+
+#[cfg(feature = "proptest")]
+proptest!(Bitstring => dynamic(32));
+
+#[cfg(feature = "float")]
 f2d!(f32 => from_f32 => Bitstring);
+#[cfg(feature = "float")]
 f2d!(f64 => from_f64 => Bitstring);
 
+#[cfg(feature = "float")]
 try_d2f!(Bitstring => to_f32 => f32);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring => to_f32_round => f32);
+#[cfg(feature = "float")]
 try_d2f!(Bitstring => to_f64 => f64);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring => to_f64_round => f64);
 
 i2d!(i8 => from_i8 => Bitstring);
 i2d!(i16 => from_i16 => Bitstring);
@@ -95,11 +196,20 @@ i2d!(i32 => from_i32 => Bitstring);
 i2d!(i64 => from_i64 => Bitstring);
 i2d!(i128 => from_i128 => Bitstring);
 
+scaled_i2d!(i64 => from_scaled_i64 => Bitstring);
+
 try_d2i!(Bitstring => to_i8 => i8);
 try_d2i!(Bitstring => to_i16 => i16);
 try_d2i!(Bitstring => to_i32 => i32);
 try_d2i!(Bitstring => to_i64 => i64);
 try_d2i!(Bitstring => to_i128 => i128);
+try_d2i_exact!(Bitstring => to_i128_exact => i128);
+
+try_d2nz!(Bitstring => to_nonzero_i8 => NonZeroI8 => to_i8);
+try_d2nz!(Bitstring => to_nonzero_i16 => NonZeroI16 => to_i16);
+try_d2nz!(Bitstring => to_nonzero_i32 => NonZeroI32 => to_i32);
+try_d2nz!(Bitstring => to_nonzero_i64 => NonZeroI64 => to_i64);
+try_d2nz!(Bitstring => to_nonzero_i128 => NonZeroI128 => to_i128);
 
 i2d!(u8 => from_u8 => Bitstring);
 i2d!(u16 => from_u16 => Bitstring);
@@ -107,8 +217,19 @@ i2d!(u32 => from_u32 => Bitstring);
 i2d!(u64 => from_u64 => Bitstring);
 i2d!(u128 => from_u128 => Bitstring);
 
+b2d!(from_bool => Bitstring);
+
 try_d2i!(Bitstring => to_u8 => u8);
 try_d2i!(Bitstring => to_u16 => u16);
 try_d2i!(Bitstring => to_u32 => u32);
 try_d2i!(Bitstring => to_u64 => u64);
 try_d2i!(Bitstring => to_u128 => u128);
+
+try_d2nz!(Bitstring => to_nonzero_u8 => NonZeroU8 => to_u8);
+try_d2nz!(Bitstring => to_nonzero_u16 => NonZeroU16 => to_u16);
+try_d2nz!(Bitstring => to_nonzero_u32 => NonZeroU32 => to_u32);
+try_d2nz!(Bitstring => to_nonzero_u64 => NonZeroU64 => to_u64);
+try_d2nz!(Bitstring => to_nonzero_u128 => NonZeroU128 => to_u128);
+
+#[cfg(feature = "num-traits")]
+num!(Bitstring);
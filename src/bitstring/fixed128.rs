@@ -1,7 +1,9 @@
 use crate::{
     binary::{
+        encode_finite_const,
         encode_max,
         encode_min,
+        encode_subnormal,
         FixedBinaryBuf,
     },
     text::ArrayTextBuf,
@@ -11,6 +13,7 @@ use crate::{
 A [128bit decimal number](https://en.wikipedia.org/wiki/Decimal128_floating-point_format).
 */
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Bitstring128(FixedBinaryBuf<16, i32>);
 
 /**
@@ -196,12 +199,40 @@ impl Bitstring128 {
     Create a decimal from its representation as a byte array in little endian.
 
     This matches the internal byte representation of the decimal, regardless of the platform.
+    The array must be exactly 16 bytes. To read a decimal whose width is only known at runtime,
+    use [`Bitstring::try_from_le_bytes`](crate::Bitstring::try_from_le_bytes) instead.
     */
     #[inline]
     pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
         Self(FixedBinaryBuf::from_le_bytes(bytes))
     }
 
+    /**
+    Create a decimal from a coefficient and exponent in a `const` context.
+
+    The value of the decimal is `coefficient * 10^exponent`, so a coefficient of `314159` with an
+    exponent of `-5` is `3.14159`. This is the intended way to define your own decimal constants:
+
+    ```
+    use decstr::Bitstring128;
+
+    const RATE: Bitstring128 = Bitstring128::from_coefficient_exponent_const(314159, -5);
+
+    assert_eq!("3.14159", RATE.to_string());
+    ```
+
+    The coefficient can have up to 34 digits, and the exponent must be between `-6176` and
+    `6111`. This method panics if either is out of range, which in a `const` item is a compile
+    error. To create a decimal from parts that are only known at runtime, parse it instead.
+    */
+    pub const fn from_coefficient_exponent_const(coefficient: i128, exponent: i32) -> Self {
+        Self::from_le_bytes(encode_finite_const(
+            coefficient < 0,
+            coefficient.unsigned_abs(),
+            exponent,
+        ))
+    }
+
     /**
     Create a decimal from its representation as a byte array in big endian.
     */
@@ -224,6 +255,16 @@ impl Bitstring128 {
         self.0.as_le_bytes()
     }
 
+    /**
+    Return the memory representation of this decimal as a byte array in little-endian byte order.
+
+    This is the same as `as_le_bytes`, but returns the array by-value.
+    */
+    #[inline]
+    pub const fn to_le_bytes(&self) -> [u8; 16] {
+        *self.0.as_le_bytes()
+    }
+
     /**
     Return the memory representation of this decimal as a byte array in big-endian
     (network) byte order.
@@ -276,18 +317,80 @@ impl Bitstring128 {
 
         Self(buf)
     }
+
+    /**
+    Create a positive subnormal decimal whose significand is `1` preceded by `leading_zeros` zeroes.
+
+    The decimal uses the smallest exponent, so its last digit is worth `1e-6176`, and each
+    leading zero makes it 10 times smaller. With `33` leading zeroes it's the same as
+    [`Bitstring128::min_positive`], and with `1` it's the largest power of ten that's still subnormal.
+    This is useful for building decimals to test underflow with.
+
+    # Panics
+
+    This method panics if `leading_zeros` isn't between `1` and `33`.
+    */
+    pub fn subnormal(leading_zeros: u32) -> Self {
+        let mut buf = FixedBinaryBuf::ZERO;
+
+        encode_subnormal(&mut buf, false, leading_zeros);
+
+        Self(buf)
+    }
 }
 
+default!(Bitstring128);
 classify!(Bitstring128);
+canonical!(Bitstring128 => [u8; 16]);
+precision!(Bitstring128);
+cmp!(Bitstring128);
+total_ord!(Bitstring128);
+search!(Bitstring128);
+ord_key!(Bitstring128);
+cohort!(Bitstring128);
+neg!(Bitstring128);
+pow10!(Bitstring128);
+next!(Bitstring128);
+arith!(Bitstring128);
+scaleb!(Bitstring128);
+nan!(Bitstring128);
+fit!(Bitstring128);
+dynamic!(Bitstring128);
+
+try_d2d!(Bitstring256 => Bitstring128);
+
+#[cfg(feature = "raw-encoding")]
+raw!(Bitstring128);
 
 try_s2d!(ArrayTextBuf::<128> => Bitstring128);
 d2s!(Bitstring128);
+d2v!(Bitstring128);
+d2b!(Bitstring128);
 
+#[cfg(feature = "serde")]
+serde!(Bitstring128 => [u8; 16]);
+
+#[cfg(feature = "proptest")]
+proptest!(Bitstring128 => [u8; 16]);
+
+#[cfg(feature = "bytemuck")]
+bytemuck!(Bitstring128);
+
+#[cfg(feature = "float")]
 f2d!(f32 => from_f32 => Bitstring128);
+#[cfg(feature = "float")]
 f2d!(f64 => from_f64 => Bitstring128);
+#[cfg(feature = "float")]
+f2d_precision!(f64 => from_f64_precision => Bitstring128);
 
+#[cfg(feature = "float")]
 try_d2f!(Bitstring128 => to_f32 => f32);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring128 => to_f32_round => f32);
+#[cfg(feature = "float")]
 try_d2f!(Bitstring128 => to_f64 => f64);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring128 => to_f64_round => f64);
 
 i2d!(i8 => from_i8 => Bitstring128);
 i2d!(i16 => from_i16 => Bitstring128);
@@ -295,11 +398,20 @@ i2d!(i32 => from_i32 => Bitstring128);
 i2d!(i64 => from_i64 => Bitstring128);
 try_i2d!(i128 => from_i128 => Bitstring128);
 
+scaled_i2d!(i64 => from_scaled_i64 => Bitstring128);
+
 try_d2i!(Bitstring128 => to_i8 => i8);
 try_d2i!(Bitstring128 => to_i16 => i16);
 try_d2i!(Bitstring128 => to_i32 => i32);
 try_d2i!(Bitstring128 => to_i64 => i64);
 try_d2i!(Bitstring128 => to_i128 => i128);
+try_d2i_exact!(Bitstring128 => to_i128_exact => i128);
+
+try_d2nz!(Bitstring128 => to_nonzero_i8 => NonZeroI8 => to_i8);
+try_d2nz!(Bitstring128 => to_nonzero_i16 => NonZeroI16 => to_i16);
+try_d2nz!(Bitstring128 => to_nonzero_i32 => NonZeroI32 => to_i32);
+try_d2nz!(Bitstring128 => to_nonzero_i64 => NonZeroI64 => to_i64);
+try_d2nz!(Bitstring128 => to_nonzero_i128 => NonZeroI128 => to_i128);
 
 i2d!(u8 => from_u8 => Bitstring128);
 i2d!(u16 => from_u16 => Bitstring128);
@@ -307,12 +419,23 @@ i2d!(u32 => from_u32 => Bitstring128);
 i2d!(u64 => from_u64 => Bitstring128);
 try_i2d!(u128 => from_u128 => Bitstring128);
 
+b2d!(from_bool => Bitstring128);
+
 try_d2i!(Bitstring128 => to_u8 => u8);
 try_d2i!(Bitstring128 => to_u16 => u16);
 try_d2i!(Bitstring128 => to_u32 => u32);
 try_d2i!(Bitstring128 => to_u64 => u64);
 try_d2i!(Bitstring128 => to_u128 => u128);
 
+try_d2nz!(Bitstring128 => to_nonzero_u8 => NonZeroU8 => to_u8);
+try_d2nz!(Bitstring128 => to_nonzero_u16 => NonZeroU16 => to_u16);
+try_d2nz!(Bitstring128 => to_nonzero_u32 => NonZeroU32 => to_u32);
+try_d2nz!(Bitstring128 => to_nonzero_u64 => NonZeroU64 => to_u64);
+try_d2nz!(Bitstring128 => to_nonzero_u128 => NonZeroU128 => to_u128);
+
+#[cfg(feature = "num-traits")]
+num!(Bitstring128);
+
 #[cfg(test)]
 mod tests {
     use super::*;
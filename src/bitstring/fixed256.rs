@@ -0,0 +1,558 @@
+use crate::{
+    binary::{
+        encode_max,
+        encode_min,
+        encode_subnormal,
+        FixedBinaryBuf,
+    },
+    text::ArrayTextBuf,
+};
+
+/**
+A [256bit decimal number](https://en.wikipedia.org/wiki/Decimal_floating_point#IEEE_754-2008_encoding).
+
+This isn't one of the basic formats in IEEE 754, but follows the same encoding as the smaller ones
+with a precision of 70 digits.
+*/
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Bitstring256(FixedBinaryBuf<32, i64>);
+
+/**
+Basic mathematical constants.
+*/
+impl Bitstring256 {
+    /// 0
+    pub const ZERO: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17,
+        0, 34,
+    ]));
+
+    /// 1
+    pub const ONE: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17,
+        0, 34,
+    ]));
+
+    /// -1
+    pub const NEG_ONE: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17,
+        0, 162,
+    ]));
+
+    /// Archimedes' constant (π)
+    pub const PI: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        28, 31, 166, 43, 179, 252, 178, 80, 72, 125, 223, 165, 179, 143, 131, 130, 230, 181, 218,
+        208, 98, 226, 180, 251, 179, 83, 235, 26, 204, 255, 255, 45,
+    ]));
+
+    /// The full circle constant (τ)
+    ///
+    /// Equal to 2π.
+    pub const TAU: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        50, 87, 172, 204, 27, 175, 6, 29, 17, 244, 222, 227, 166, 29, 250, 5, 100, 107, 190, 90,
+        173, 218, 169, 110, 62, 135, 45, 179, 210, 255, 255, 57,
+    ]));
+
+    /// π/2
+    pub const FRAC_PI_2: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        174, 76, 163, 151, 156, 107, 114, 34, 237, 218, 95, 43, 235, 133, 144, 209, 231, 188, 113,
+        104, 49, 101, 236, 177, 246, 166, 233, 15, 239, 255, 255, 37,
+    ]));
+
+    /// π/3
+    pub const FRAC_PI_3: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        5, 11, 194, 38, 101, 88, 207, 85, 67, 41, 179, 140, 94, 6, 202, 231, 236, 16, 38, 69, 212,
+        24, 191, 175, 62, 209, 238, 115, 196, 255, 255, 37,
+    ]));
+
+    /// π/4
+    pub const FRAC_PI_4: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        220, 218, 129, 140, 237, 67, 85, 105, 127, 83, 201, 233, 148, 68, 232, 125, 254, 208, 36,
+        216, 21, 39, 134, 228, 126, 227, 120, 183, 252, 255, 255, 33,
+    ]));
+
+    /// π/6
+    pub const FRAC_PI_6: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        130, 69, 33, 227, 53, 47, 217, 124, 169, 184, 230, 182, 33, 3, 135, 171, 26, 11, 211, 33,
+        119, 244, 229, 149, 183, 245, 123, 59, 234, 255, 255, 33,
+    ]));
+
+    /// π/8
+    pub const FRAC_PI_8: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        119, 226, 64, 135, 203, 33, 159, 228, 70, 43, 244, 24, 77, 42, 27, 189, 191, 34, 34, 15,
+        13, 83, 67, 186, 215, 11, 124, 173, 219, 255, 255, 33,
+    ]));
+
+    /// 1/π
+    pub const FRAC_1_PI: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        187, 95, 226, 52, 109, 91, 126, 204, 153, 130, 27, 253, 130, 6, 233, 40, 20, 111, 234, 249,
+        183, 198, 173, 249, 42, 14, 39, 134, 217, 255, 255, 33,
+    ]));
+
+    /// 2/π
+    pub const FRAC_2_PI: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        235, 214, 100, 95, 218, 158, 104, 223, 98, 59, 170, 250, 116, 11, 146, 87, 104, 56, 69,
+        173, 117, 12, 183, 232, 121, 242, 103, 108, 243, 255, 255, 33,
+    ]));
+
+    /// 2/sqrt(π)
+    pub const FRAC_2_SQRT_PI: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        74, 133, 56, 100, 60, 78, 79, 254, 249, 213, 88, 5, 226, 116, 60, 197, 134, 210, 24, 54,
+        30, 207, 43, 233, 22, 231, 228, 135, 202, 255, 255, 37,
+    ]));
+
+    /// sqrt(2)
+    pub const SQRT_2: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        178, 123, 114, 123, 222, 246, 204, 225, 170, 125, 125, 198, 157, 46, 30, 94, 39, 68, 186,
+        211, 13, 32, 177, 197, 124, 226, 78, 68, 225, 255, 255, 37,
+    ]));
+
+    /// 1/sqrt(2)
+    pub const FRAC_1_SQRT_2: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        230, 125, 234, 114, 110, 206, 218, 64, 167, 211, 189, 247, 168, 84, 14, 47, 18, 34, 30,
+        147, 0, 146, 122, 172, 58, 139, 27, 114, 248, 255, 255, 33,
+    ]));
+
+    /// Euler's number (e)
+    pub const E: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        118, 144, 126, 114, 251, 236, 211, 251, 235, 215, 59, 28, 117, 253, 158, 98, 75, 23, 231,
+        90, 224, 213, 84, 68, 150, 46, 45, 132, 249, 255, 255, 41,
+    ]));
+
+    /// log<sub>2</sub>(10)
+    pub const LOG2_10: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        188, 118, 184, 157, 245, 84, 72, 172, 40, 9, 187, 245, 192, 102, 61, 154, 61, 153, 98, 102,
+        124, 28, 39, 222, 195, 90, 184, 22, 218, 255, 255, 45,
+    ]));
+
+    /// log<sub>2</sub>(e)
+    pub const LOG2_E: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        137, 244, 98, 96, 146, 181, 240, 250, 168, 52, 220, 22, 109, 226, 45, 30, 5, 176, 48, 171,
+        217, 29, 216, 158, 27, 64, 108, 45, 228, 255, 255, 37,
+    ]));
+
+    /// log<sub>10</sub>(2)
+    pub const LOG10_2: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        136, 156, 18, 230, 137, 144, 5, 139, 136, 152, 15, 60, 131, 190, 9, 59, 146, 238, 33, 238,
+        19, 109, 243, 200, 216, 159, 166, 16, 216, 255, 255, 33,
+    ]));
+
+    /// log<sub>10</sub>(e)
+    pub const LOG10_E: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        108, 150, 179, 250, 148, 148, 152, 107, 118, 67, 5, 236, 167, 149, 10, 5, 115, 238, 11, 42,
+        81, 183, 28, 85, 99, 11, 106, 69, 227, 255, 255, 33,
+    ]));
+
+    /// ln(2)
+    pub const LN_2: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        187, 237, 152, 128, 194, 160, 80, 85, 21, 120, 180, 0, 90, 7, 186, 246, 96, 25, 138, 76,
+        23, 38, 214, 108, 182, 138, 28, 179, 243, 255, 255, 33,
+    ]));
+
+    /// ln(10)
+    pub const LN_10: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        242, 182, 207, 200, 105, 51, 240, 47, 63, 202, 78, 6, 18, 240, 65, 228, 41, 77, 229, 39,
+        23, 40, 93, 132, 167, 58, 44, 43, 216, 255, 255, 41,
+    ]));
+}
+
+impl Bitstring256 {
+    /// The radix or base of the internal representation.
+    pub const RADIX: u32 = 10;
+
+    /**
+    The number of digits in base 10 that can be represented without loss of precision.
+
+    This constant indicates the total count of significant decimal digits in the
+    significand, regardless of the decimal point's position. For instance
+    1234567 and 123.4567, both contain `DIGITS` digits.
+    */
+    pub const DIGITS: u32 = 70;
+
+    /**
+    [Machine epsilon] value.
+
+    This is the difference between `1.0` and the next larger representable number.
+
+    [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
+    */
+    pub const EPSILON: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192,
+        255, 255, 33,
+    ]));
+
+    /// Smallest finite value.
+    pub const MIN: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        255, 252, 243, 207, 63, 255, 252, 243, 207, 63, 255, 252, 243, 207, 63, 255, 252, 243, 207,
+        63, 255, 252, 243, 207, 63, 255, 252, 243, 143, 255, 255, 247,
+    ]));
+
+    /// Smallest positive normal value.
+    pub const MIN_POSITIVE: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ]));
+
+    /// Largest finite value.
+    pub const MAX: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        255, 252, 243, 207, 63, 255, 252, 243, 207, 63, 255, 252, 243, 207, 63, 255, 252, 243, 207,
+        63, 255, 252, 243, 207, 63, 255, 252, 243, 143, 255, 255, 119,
+    ]));
+
+    /// Minimum possible normal power of 10 exponent.
+    pub const MIN_10_EXP: i32 = -1572932;
+
+    /// Maximum possible normal power of 10 exponent.
+    pub const MAX_10_EXP: i32 = 1572795;
+
+    /// Not a Number (NaN), with a zero payload.
+    pub const NAN: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 124,
+    ]));
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 120,
+    ]));
+
+    /// Negative infinity (−∞).
+    pub const NEG_INFINITY: Self = Bitstring256(FixedBinaryBuf::from_le_bytes([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 248,
+    ]));
+}
+
+impl Bitstring256 {
+    /**
+    Create a decimal from its representation as a byte array in little endian.
+
+    This matches the internal byte representation of the decimal, regardless of the platform.
+    The array must be exactly 32 bytes.
+    */
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        Self(FixedBinaryBuf::from_le_bytes(bytes))
+    }
+
+    /**
+    Create a decimal from its representation as a byte array in big endian.
+    */
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        Self(FixedBinaryBuf::from_le_bytes(reverse_bytes(bytes)))
+    }
+
+    /**
+    Return the memory representation of this decimal as a byte array in little-endian byte order.
+
+    This matches the internal byte representation of the decimal, regardless of the platform.
+    */
+    #[inline]
+    pub const fn as_le_bytes(&self) -> &[u8; 32] {
+        // Even on big-endian platforms we always encode numbers in little-endian order
+        self.0.as_le_bytes()
+    }
+
+    /**
+    Return the memory representation of this decimal as a byte array in little-endian byte order.
+
+    This is the same as `as_le_bytes`, but returns the array by-value.
+    */
+    #[inline]
+    pub const fn to_le_bytes(&self) -> [u8; 32] {
+        *self.0.as_le_bytes()
+    }
+
+    /**
+    Return the memory representation of this decimal as a byte array in big-endian
+    (network) byte order.
+    */
+    #[inline]
+    pub const fn to_be_bytes(&self) -> [u8; 32] {
+        reverse_bytes(*self.0.as_le_bytes())
+    }
+
+    /**
+    Create a decimal with the finite value zero.
+    */
+    pub fn zero() -> Self {
+        Self::from(0u8)
+    }
+
+    /**
+    Create a decimal with its maximum finite value.
+    */
+    pub fn max() -> Self {
+        let mut buf = FixedBinaryBuf::ZERO;
+
+        encode_max(&mut buf, false);
+
+        Self(buf)
+    }
+
+    /**
+    Create a decimal with its minimum finite value.
+    */
+    pub fn min() -> Self {
+        let mut buf = FixedBinaryBuf::ZERO;
+
+        encode_max(&mut buf, true);
+
+        Self(buf)
+    }
+
+    /**
+    Create a decimal with its minimum positive non-zero value.
+    */
+    pub fn min_positive() -> Self {
+        let mut buf = FixedBinaryBuf::ZERO;
+
+        encode_min(&mut buf, false);
+
+        Self(buf)
+    }
+
+    /**
+    Create a positive subnormal decimal whose significand is `1` preceded by `leading_zeros` zeroes.
+
+    The decimal uses the smallest exponent, so its last digit is worth `1e-1572932`, and each
+    leading zero makes it 10 times smaller. With `69` leading zeroes it's the same as
+    [`Bitstring256::min_positive`], and with `1` it's the largest power of ten that's still subnormal.
+    This is useful for building decimals to test underflow with.
+
+    # Panics
+
+    This method panics if `leading_zeros` isn't between `1` and `69`.
+    */
+    pub fn subnormal(leading_zeros: u32) -> Self {
+        let mut buf = FixedBinaryBuf::ZERO;
+
+        encode_subnormal(&mut buf, false, leading_zeros);
+
+        Self(buf)
+    }
+}
+
+const fn reverse_bytes(bytes: [u8; 32]) -> [u8; 32] {
+    let mut reversed = [0; 32];
+
+    let mut i = 0;
+    while i < 32 {
+        reversed[i] = bytes[31 - i];
+        i += 1;
+    }
+
+    reversed
+}
+
+default!(Bitstring256);
+classify!(Bitstring256);
+canonical!(Bitstring256 => [u8; 32]);
+precision!(Bitstring256);
+cmp!(Bitstring256);
+total_ord!(Bitstring256);
+search!(Bitstring256);
+ord_key!(Bitstring256);
+cohort!(Bitstring256);
+neg!(Bitstring256);
+pow10!(Bitstring256);
+next!(Bitstring256);
+arith!(Bitstring256);
+scaleb!(Bitstring256);
+nan!(Bitstring256);
+fit!(Bitstring256);
+dynamic!(Bitstring256);
+
+#[cfg(feature = "raw-encoding")]
+raw!(Bitstring256);
+
+try_s2d!(ArrayTextBuf::<256> => Bitstring256);
+d2s!(Bitstring256);
+d2v!(Bitstring256);
+d2b!(Bitstring256);
+
+#[cfg(feature = "serde")]
+serde!(Bitstring256 => [u8; 32]);
+
+#[cfg(feature = "proptest")]
+proptest!(Bitstring256 => [u8; 32]);
+
+#[cfg(feature = "bytemuck")]
+bytemuck!(Bitstring256);
+
+#[cfg(feature = "float")]
+f2d!(f32 => from_f32 => Bitstring256);
+#[cfg(feature = "float")]
+f2d!(f64 => from_f64 => Bitstring256);
+#[cfg(feature = "float")]
+f2d_precision!(f64 => from_f64_precision => Bitstring256);
+
+#[cfg(feature = "float")]
+try_d2f!(Bitstring256 => to_f32 => f32);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring256 => to_f32_round => f32);
+#[cfg(feature = "float")]
+try_d2f!(Bitstring256 => to_f64 => f64);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring256 => to_f64_round => f64);
+
+i2d!(i8 => from_i8 => Bitstring256);
+i2d!(i16 => from_i16 => Bitstring256);
+i2d!(i32 => from_i32 => Bitstring256);
+i2d!(i64 => from_i64 => Bitstring256);
+i2d!(i128 => from_i128 => Bitstring256);
+
+scaled_i2d!(i64 => from_scaled_i64 => Bitstring256);
+
+try_d2i!(Bitstring256 => to_i8 => i8);
+try_d2i!(Bitstring256 => to_i16 => i16);
+try_d2i!(Bitstring256 => to_i32 => i32);
+try_d2i!(Bitstring256 => to_i64 => i64);
+try_d2i!(Bitstring256 => to_i128 => i128);
+try_d2i_exact!(Bitstring256 => to_i128_exact => i128);
+
+try_d2nz!(Bitstring256 => to_nonzero_i8 => NonZeroI8 => to_i8);
+try_d2nz!(Bitstring256 => to_nonzero_i16 => NonZeroI16 => to_i16);
+try_d2nz!(Bitstring256 => to_nonzero_i32 => NonZeroI32 => to_i32);
+try_d2nz!(Bitstring256 => to_nonzero_i64 => NonZeroI64 => to_i64);
+try_d2nz!(Bitstring256 => to_nonzero_i128 => NonZeroI128 => to_i128);
+
+i2d!(u8 => from_u8 => Bitstring256);
+i2d!(u16 => from_u16 => Bitstring256);
+i2d!(u32 => from_u32 => Bitstring256);
+i2d!(u64 => from_u64 => Bitstring256);
+i2d!(u128 => from_u128 => Bitstring256);
+
+b2d!(from_bool => Bitstring256);
+
+try_d2i!(Bitstring256 => to_u8 => u8);
+try_d2i!(Bitstring256 => to_u16 => u16);
+try_d2i!(Bitstring256 => to_u32 => u32);
+try_d2i!(Bitstring256 => to_u64 => u64);
+try_d2i!(Bitstring256 => to_u128 => u128);
+
+try_d2nz!(Bitstring256 => to_nonzero_u8 => NonZeroU8 => to_u8);
+try_d2nz!(Bitstring256 => to_nonzero_u16 => NonZeroU16 => to_u16);
+try_d2nz!(Bitstring256 => to_nonzero_u32 => NonZeroU32 => to_u32);
+try_d2nz!(Bitstring256 => to_nonzero_u64 => NonZeroU64 => to_u64);
+try_d2nz!(Bitstring256 => to_nonzero_u128 => NonZeroU128 => to_u128);
+
+#[cfg(feature = "num-traits")]
+num!(Bitstring256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consts_256() {
+        use core::str::FromStr;
+
+        // helper fn
+        fn is_eq(a: Bitstring256, b: Bitstring256) {
+            assert_eq!(a.as_le_bytes(), b.as_le_bytes());
+        }
+        // helper fn
+        fn is_eq_f(a: Bitstring256, s: &str) {
+            assert_eq!(
+                a.to_string(),
+                s.chars()
+                    .take((Bitstring256::DIGITS + 1) as usize)
+                    .collect::<String>()
+            );
+        }
+
+        is_eq(Bitstring256::ZERO, Bitstring256::from_str("0").unwrap());
+        is_eq(Bitstring256::ONE, Bitstring256::from_str("1").unwrap());
+        is_eq(Bitstring256::NEG_ONE, Bitstring256::from_str("-1").unwrap());
+
+        // 74 char strings calculated to 100 digits of precision
+        const PI: &str =
+            "3.141592653589793238462643383279502884197169399375105820974944592307816406";
+        const TAU: &str =
+            "6.283185307179586476925286766559005768394338798750211641949889184615632812";
+        const FRAC_PI_2: &str =
+            "1.570796326794896619231321691639751442098584699687552910487472296153908203";
+        const FRAC_PI_3: &str =
+            "1.047197551196597746154214461093167628065723133125035273658314864102605468";
+        const FRAC_PI_4: &str =
+            "0.785398163397448309615660845819875721049292349843776455243736148076954101";
+        const FRAC_PI_6: &str =
+            "0.523598775598298873077107230546583814032861566562517636829157432051302734";
+        const FRAC_PI_8: &str =
+            "0.392699081698724154807830422909937860524646174921888227621868074038477050";
+        const FRAC_1_PI: &str =
+            "0.318309886183790671537767526745028724068919291480912897495334688117793595";
+        const FRAC_2_PI: &str =
+            "0.636619772367581343075535053490057448137838582961825794990669376235587190";
+        const FRAC_2_SQRT_PI: &str =
+            "1.128379167095512573896158903121545171688101258657997713688171443421284936";
+        const SQRT_2: &str =
+            "1.414213562373095048801688724209698078569671875376948073176679737990732478";
+        const FRAC_1_SQRT_2: &str =
+            "0.707106781186547524400844362104849039284835937688474036588339868995366239";
+        const E: &str =
+            "2.718281828459045235360287471352662497757247093699959574966967627724076630";
+        const LOG2_10: &str =
+            "3.321928094887362347870319429489390175864831393024580612054756395815934776";
+        const LOG2_E: &str =
+            "1.442695040888963407359924681001892137426645954152985934135449406931109219";
+        const LOG10_2: &str =
+            "0.301029995663981195213738894724493026768189881462108541310427461127108189";
+        const LOG10_E: &str =
+            "0.434294481903251827651128918916605082294397005803666566114453783165864649";
+        const LN_2: &str =
+            "0.693147180559945309417232121458176568075500134360255254120680009493393621";
+        const LN_10: &str =
+            "2.302585092994045684017991454684364207601101488628772976033327900967572609";
+
+        is_eq_f(Bitstring256::PI, PI);
+        is_eq_f(Bitstring256::TAU, TAU);
+        is_eq_f(Bitstring256::FRAC_PI_2, FRAC_PI_2);
+        is_eq_f(Bitstring256::FRAC_PI_3, FRAC_PI_3);
+        is_eq_f(Bitstring256::FRAC_PI_4, FRAC_PI_4);
+        is_eq_f(Bitstring256::FRAC_PI_6, FRAC_PI_6);
+        is_eq_f(Bitstring256::FRAC_PI_8, FRAC_PI_8);
+        is_eq_f(Bitstring256::FRAC_1_PI, FRAC_1_PI);
+        is_eq_f(Bitstring256::FRAC_2_PI, FRAC_2_PI);
+        is_eq_f(Bitstring256::FRAC_2_SQRT_PI, FRAC_2_SQRT_PI);
+        is_eq_f(Bitstring256::SQRT_2, SQRT_2);
+        is_eq_f(Bitstring256::FRAC_1_SQRT_2, FRAC_1_SQRT_2);
+        is_eq_f(Bitstring256::E, E);
+        is_eq_f(Bitstring256::LOG2_10, LOG2_10);
+        is_eq_f(Bitstring256::LOG2_E, LOG2_E);
+        is_eq_f(Bitstring256::LOG10_2, LOG10_2);
+        is_eq_f(Bitstring256::LOG10_E, LOG10_E);
+        is_eq_f(Bitstring256::LN_2, LN_2);
+        is_eq_f(Bitstring256::LN_10, LN_10);
+
+        is_eq(
+            Bitstring256::EPSILON,
+            Bitstring256::from_str("1e-69").unwrap(),
+        );
+        is_eq(Bitstring256::MIN, Bitstring256::min());
+        is_eq(Bitstring256::MIN_POSITIVE, Bitstring256::min_positive());
+        is_eq(Bitstring256::MAX, Bitstring256::max());
+        is_eq(Bitstring256::NAN, Bitstring256::from_str("nan").unwrap());
+        is_eq(
+            Bitstring256::INFINITY,
+            Bitstring256::from_str("inf").unwrap(),
+        );
+        is_eq(
+            Bitstring256::NEG_INFINITY,
+            Bitstring256::from_str("-inf").unwrap(),
+        );
+
+        assert_eq!(
+            Bitstring256::MIN_10_EXP as i64,
+            crate::binary::emin::<i64>(256) - (Bitstring256::DIGITS as i64) + 1
+        );
+        assert_eq!(
+            Bitstring256::MAX_10_EXP as i64,
+            crate::binary::emax::<i64>(256) - (Bitstring256::DIGITS as i64) + 1
+        );
+    }
+}
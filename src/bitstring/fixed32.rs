@@ -1,7 +1,9 @@
 use crate::{
     binary::{
+        encode_finite_const,
         encode_max,
         encode_min,
+        encode_subnormal,
         FixedBinaryBuf,
     },
     text::ArrayTextBuf,
@@ -11,6 +13,7 @@ use crate::{
 A [32bit decimal number](https://en.wikipedia.org/wiki/Decimal32_floating-point_format).
 */
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Bitstring32(FixedBinaryBuf<4, i32>);
 
 /**
@@ -139,12 +142,40 @@ impl Bitstring32 {
     Create a decimal from its representation as a byte array in little endian.
 
     This matches the internal byte representation of the decimal, regardless of the platform.
+    The array must be exactly 4 bytes. To read a decimal whose width is only known at runtime,
+    use [`Bitstring::try_from_le_bytes`](crate::Bitstring::try_from_le_bytes) instead.
     */
     #[inline]
     pub const fn from_le_bytes(bytes: [u8; 4]) -> Self {
         Self(FixedBinaryBuf::from_le_bytes(bytes))
     }
 
+    /**
+    Create a decimal from a coefficient and exponent in a `const` context.
+
+    The value of the decimal is `coefficient * 10^exponent`, so a coefficient of `1234` with an
+    exponent of `-2` is `12.34`. This is the intended way to define your own decimal constants:
+
+    ```
+    use decstr::Bitstring32;
+
+    const RATE: Bitstring32 = Bitstring32::from_coefficient_exponent_const(1234, -2);
+
+    assert_eq!("12.34", RATE.to_string());
+    ```
+
+    The coefficient can have up to 7 digits, and the exponent must be between `-101` and
+    `90`. This method panics if either is out of range, which in a `const` item is a compile
+    error. To create a decimal from parts that are only known at runtime, parse it instead.
+    */
+    pub const fn from_coefficient_exponent_const(coefficient: i32, exponent: i32) -> Self {
+        Self::from_le_bytes(encode_finite_const(
+            coefficient < 0,
+            coefficient.unsigned_abs() as u128,
+            exponent,
+        ))
+    }
+
     /**
     Create a decimal from its representation as a byte array in big endian.
     */
@@ -166,6 +197,16 @@ impl Bitstring32 {
         self.0.as_le_bytes()
     }
 
+    /**
+    Return the memory representation of this decimal as a byte array in little-endian byte order.
+
+    This is the same as `as_le_bytes`, but returns the array by-value.
+    */
+    #[inline]
+    pub const fn to_le_bytes(&self) -> [u8; 4] {
+        *self.0.as_le_bytes()
+    }
+
     /**
     Return the memory representation of this decimal as a byte array in big-endian
     (network) byte order.
@@ -215,18 +256,80 @@ impl Bitstring32 {
 
         Self(buf)
     }
+
+    /**
+    Create a positive subnormal decimal whose significand is `1` preceded by `leading_zeros` zeroes.
+
+    The decimal uses the smallest exponent, so its last digit is worth `1e-101`, and each
+    leading zero makes it 10 times smaller. With `6` leading zeroes it's the same as
+    [`Bitstring32::min_positive`], and with `1` it's the largest power of ten that's still subnormal.
+    This is useful for building decimals to test underflow with.
+
+    # Panics
+
+    This method panics if `leading_zeros` isn't between `1` and `6`.
+    */
+    pub fn subnormal(leading_zeros: u32) -> Self {
+        let mut buf = FixedBinaryBuf::ZERO;
+
+        encode_subnormal(&mut buf, false, leading_zeros);
+
+        Self(buf)
+    }
 }
 
+default!(Bitstring32);
 classify!(Bitstring32);
+canonical!(Bitstring32 => [u8; 4]);
+precision!(Bitstring32);
+cmp!(Bitstring32);
+total_ord!(Bitstring32);
+search!(Bitstring32);
+ord_key!(Bitstring32);
+cohort!(Bitstring32);
+neg!(Bitstring32);
+pow10!(Bitstring32);
+next!(Bitstring32);
+arith!(Bitstring32);
+scaleb!(Bitstring32);
+nan!(Bitstring32);
+fit!(Bitstring32);
+dynamic!(Bitstring32);
+
+try_d2d!(Bitstring64 => Bitstring32);
+try_d2d!(Bitstring128 => Bitstring32);
+try_d2d!(Bitstring256 => Bitstring32);
+
+#[cfg(feature = "raw-encoding")]
+raw!(Bitstring32);
 
 try_s2d!(ArrayTextBuf::<32> => Bitstring32);
 d2s!(Bitstring32);
+d2v!(Bitstring32);
+d2b!(Bitstring32);
 
+#[cfg(feature = "serde")]
+serde!(Bitstring32 => [u8; 4]);
+
+#[cfg(feature = "proptest")]
+proptest!(Bitstring32 => [u8; 4]);
+
+#[cfg(feature = "bytemuck")]
+bytemuck!(Bitstring32);
+
+#[cfg(feature = "float")]
 try_f2d!(f32 => from_f32 => Bitstring32);
+#[cfg(feature = "float")]
 try_f2d!(f64 => from_f64 => Bitstring32);
 
+#[cfg(feature = "float")]
 try_d2f!(Bitstring32 => to_f32 => f32);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring32 => to_f32_round => f32);
+#[cfg(feature = "float")]
 d2f!(Bitstring32 => to_f64 => f64);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring32 => to_f64_round => f64);
 
 i2d!(i8 => from_i8 => Bitstring32);
 i2d!(i16 => from_i16 => Bitstring32);
@@ -234,11 +337,20 @@ try_i2d!(i32 => from_i32 => Bitstring32);
 try_i2d!(i64 => from_i64 => Bitstring32);
 try_i2d!(i128 => from_i128 => Bitstring32);
 
+scaled_i2d!(i64 => from_scaled_i64 => Bitstring32);
+
 try_d2i!(Bitstring32 => to_i8 => i8);
 try_d2i!(Bitstring32 => to_i16 => i16);
 try_d2i!(Bitstring32 => to_i32 => i32);
 try_d2i!(Bitstring32 => to_i64 => i64);
 try_d2i!(Bitstring32 => to_i128 => i128);
+try_d2i_exact!(Bitstring32 => to_i128_exact => i128);
+
+try_d2nz!(Bitstring32 => to_nonzero_i8 => NonZeroI8 => to_i8);
+try_d2nz!(Bitstring32 => to_nonzero_i16 => NonZeroI16 => to_i16);
+try_d2nz!(Bitstring32 => to_nonzero_i32 => NonZeroI32 => to_i32);
+try_d2nz!(Bitstring32 => to_nonzero_i64 => NonZeroI64 => to_i64);
+try_d2nz!(Bitstring32 => to_nonzero_i128 => NonZeroI128 => to_i128);
 
 i2d!(u8 => from_u8 => Bitstring32);
 i2d!(u16 => from_u16 => Bitstring32);
@@ -246,12 +358,23 @@ try_i2d!(u32 => from_u32 => Bitstring32);
 try_i2d!(u64 => from_u64 => Bitstring32);
 try_i2d!(u128 => from_u128 => Bitstring32);
 
+b2d!(from_bool => Bitstring32);
+
 try_d2i!(Bitstring32 => to_u8 => u8);
 try_d2i!(Bitstring32 => to_u16 => u16);
 try_d2i!(Bitstring32 => to_u32 => u32);
 try_d2i!(Bitstring32 => to_u64 => u64);
 try_d2i!(Bitstring32 => to_u128 => u128);
 
+try_d2nz!(Bitstring32 => to_nonzero_u8 => NonZeroU8 => to_u8);
+try_d2nz!(Bitstring32 => to_nonzero_u16 => NonZeroU16 => to_u16);
+try_d2nz!(Bitstring32 => to_nonzero_u32 => NonZeroU32 => to_u32);
+try_d2nz!(Bitstring32 => to_nonzero_u64 => NonZeroU64 => to_u64);
+try_d2nz!(Bitstring32 => to_nonzero_u128 => NonZeroU128 => to_u128);
+
+#[cfg(feature = "num-traits")]
+num!(Bitstring32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,9 @@
 use crate::{
     binary::{
+        encode_finite_const,
         encode_max,
         encode_min,
+        encode_subnormal,
         FixedBinaryBuf,
     },
     text::ArrayTextBuf,
@@ -11,6 +13,7 @@ use crate::{
 A [64bit decimal number](https://en.wikipedia.org/wiki/Decimal64_floating-point_format).
 */
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Bitstring64(FixedBinaryBuf<8, i32>);
 
 /**
@@ -184,12 +187,40 @@ impl Bitstring64 {
     Create a decimal from its representation as a byte array in little endian.
 
     This matches the internal byte representation of the decimal, regardless of the platform.
+    The array must be exactly 8 bytes. To read a decimal whose width is only known at runtime,
+    use [`Bitstring::try_from_le_bytes`](crate::Bitstring::try_from_le_bytes) instead.
     */
     #[inline]
     pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
         Self(FixedBinaryBuf::from_le_bytes(bytes))
     }
 
+    /**
+    Create a decimal from a coefficient and exponent in a `const` context.
+
+    The value of the decimal is `coefficient * 10^exponent`, so a coefficient of `525` with an
+    exponent of `-4` is `0.0525`. This is the intended way to define your own decimal constants:
+
+    ```
+    use decstr::Bitstring64;
+
+    const RATE: Bitstring64 = Bitstring64::from_coefficient_exponent_const(525, -4);
+
+    assert_eq!("0.0525", RATE.to_string());
+    ```
+
+    The coefficient can have up to 16 digits, and the exponent must be between `-398` and
+    `369`. This method panics if either is out of range, which in a `const` item is a compile
+    error. To create a decimal from parts that are only known at runtime, parse it instead.
+    */
+    pub const fn from_coefficient_exponent_const(coefficient: i64, exponent: i32) -> Self {
+        Self::from_le_bytes(encode_finite_const(
+            coefficient < 0,
+            coefficient.unsigned_abs() as u128,
+            exponent,
+        ))
+    }
+
     /**
     Create a decimal from its representation as a byte array in big endian.
     */
@@ -211,6 +242,16 @@ impl Bitstring64 {
         self.0.as_le_bytes()
     }
 
+    /**
+    Return the memory representation of this decimal as a byte array in little-endian byte order.
+
+    This is the same as `as_le_bytes`, but returns the array by-value.
+    */
+    #[inline]
+    pub const fn to_le_bytes(&self) -> [u8; 8] {
+        *self.0.as_le_bytes()
+    }
+
     /**
     Return the memory representation of this decimal as a byte array in big-endian
     (network) byte order.
@@ -260,18 +301,79 @@ impl Bitstring64 {
 
         Self(buf)
     }
+
+    /**
+    Create a positive subnormal decimal whose significand is `1` preceded by `leading_zeros` zeroes.
+
+    The decimal uses the smallest exponent, so its last digit is worth `1e-398`, and each
+    leading zero makes it 10 times smaller. With `15` leading zeroes it's the same as
+    [`Bitstring64::min_positive`], and with `1` it's the largest power of ten that's still subnormal.
+    This is useful for building decimals to test underflow with.
+
+    # Panics
+
+    This method panics if `leading_zeros` isn't between `1` and `15`.
+    */
+    pub fn subnormal(leading_zeros: u32) -> Self {
+        let mut buf = FixedBinaryBuf::ZERO;
+
+        encode_subnormal(&mut buf, false, leading_zeros);
+
+        Self(buf)
+    }
 }
 
+default!(Bitstring64);
 classify!(Bitstring64);
+canonical!(Bitstring64 => [u8; 8]);
+precision!(Bitstring64);
+cmp!(Bitstring64);
+total_ord!(Bitstring64);
+search!(Bitstring64);
+ord_key!(Bitstring64);
+cohort!(Bitstring64);
+neg!(Bitstring64);
+pow10!(Bitstring64);
+next!(Bitstring64);
+arith!(Bitstring64);
+scaleb!(Bitstring64);
+nan!(Bitstring64);
+fit!(Bitstring64);
+dynamic!(Bitstring64);
+
+try_d2d!(Bitstring128 => Bitstring64);
+try_d2d!(Bitstring256 => Bitstring64);
+
+#[cfg(feature = "raw-encoding")]
+raw!(Bitstring64);
 
 try_s2d!(ArrayTextBuf::<64> => Bitstring64);
 d2s!(Bitstring64);
+d2v!(Bitstring64);
+d2b!(Bitstring64);
 
+#[cfg(feature = "serde")]
+serde!(Bitstring64 => [u8; 8]);
+
+#[cfg(feature = "proptest")]
+proptest!(Bitstring64 => [u8; 8]);
+
+#[cfg(feature = "bytemuck")]
+bytemuck!(Bitstring64);
+
+#[cfg(feature = "float")]
 f2d!(f32 => from_f32 => Bitstring64);
+#[cfg(feature = "float")]
 try_f2d!(f64 => from_f64 => Bitstring64);
 
+#[cfg(feature = "float")]
 try_d2f!(Bitstring64 => to_f32 => f32);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring64 => to_f32_round => f32);
+#[cfg(feature = "float")]
 try_d2f!(Bitstring64 => to_f64 => f64);
+#[cfg(feature = "float")]
+d2f_round!(Bitstring64 => to_f64_round => f64);
 
 i2d!(i8 => from_i8 => Bitstring64);
 i2d!(i16 => from_i16 => Bitstring64);
@@ -279,11 +381,20 @@ i2d!(i32 => from_i32 => Bitstring64);
 try_i2d!(i64 => from_i64 => Bitstring64);
 try_i2d!(i128 => from_i128 => Bitstring64);
 
+scaled_i2d!(i64 => from_scaled_i64 => Bitstring64);
+
 try_d2i!(Bitstring64 => to_i8 => i8);
 try_d2i!(Bitstring64 => to_i16 => i16);
 try_d2i!(Bitstring64 => to_i32 => i32);
 try_d2i!(Bitstring64 => to_i64 => i64);
 try_d2i!(Bitstring64 => to_i128 => i128);
+try_d2i_exact!(Bitstring64 => to_i128_exact => i128);
+
+try_d2nz!(Bitstring64 => to_nonzero_i8 => NonZeroI8 => to_i8);
+try_d2nz!(Bitstring64 => to_nonzero_i16 => NonZeroI16 => to_i16);
+try_d2nz!(Bitstring64 => to_nonzero_i32 => NonZeroI32 => to_i32);
+try_d2nz!(Bitstring64 => to_nonzero_i64 => NonZeroI64 => to_i64);
+try_d2nz!(Bitstring64 => to_nonzero_i128 => NonZeroI128 => to_i128);
 
 i2d!(u8 => from_u8 => Bitstring64);
 i2d!(u16 => from_u16 => Bitstring64);
@@ -291,12 +402,23 @@ i2d!(u32 => from_u32 => Bitstring64);
 try_i2d!(u64 => from_u64 => Bitstring64);
 try_i2d!(u128 => from_u128 => Bitstring64);
 
+b2d!(from_bool => Bitstring64);
+
 try_d2i!(Bitstring64 => to_u8 => u8);
 try_d2i!(Bitstring64 => to_u16 => u16);
 try_d2i!(Bitstring64 => to_u32 => u32);
 try_d2i!(Bitstring64 => to_u64 => u64);
 try_d2i!(Bitstring64 => to_u128 => u128);
 
+try_d2nz!(Bitstring64 => to_nonzero_u8 => NonZeroU8 => to_u8);
+try_d2nz!(Bitstring64 => to_nonzero_u16 => NonZeroU16 => to_u16);
+try_d2nz!(Bitstring64 => to_nonzero_u32 => NonZeroU32 => to_u32);
+try_d2nz!(Bitstring64 => to_nonzero_u64 => NonZeroU64 => to_u64);
+try_d2nz!(Bitstring64 => to_nonzero_u128 => NonZeroU128 => to_u128);
+
+#[cfg(feature = "num-traits")]
+num!(Bitstring64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,40 @@
+/**
+A decimal that's ordered by the IEEE 754 `totalOrder` predicate.
+
+Decimals can be compared in a few different ways, depending on what you need:
+
+- By their encoding, using the bytes from methods like `as_le_bytes`. Two decimals are only
+  equal if they're encoded with exactly the same bits.
+- By their numeric value, using [`Decimal::cmp_value`](crate::Decimal::cmp_value). Different
+  encodings of the same value, like `1.0` and `1.00`, are equal, and NaNs aren't ordered at all.
+- By their total order, using `TotalOrd`. Every decimal is ordered, including NaNs and different
+  encodings of the same value. This is the one to use for keys in a `BTreeMap` or `BTreeSet`.
+
+In the total order, decimals are ordered by their numeric value. Equal values are ordered by their
+exponent, so `1.00` comes before `1.0`, and `-0` comes before `0`. Positive NaNs come after every
+other decimal, and negative NaNs before every other decimal. Two decimals are only equal if they're
+encoded with the same sign, exponent, and significand.
+
+```
+use std::collections::BTreeSet;
+
+use decstr::{
+    Bitstring64,
+    TotalOrd,
+};
+
+let set = ["nan", "1.0", "-inf", "1.00", "-2"]
+    .into_iter()
+    .map(|f| TotalOrd(Bitstring64::try_parse_str(f).unwrap()))
+    .collect::<BTreeSet<_>>();
+
+let ordered = set
+    .iter()
+    .map(|d| d.0.to_string())
+    .collect::<Vec<_>>();
+
+assert_eq!(vec!["-inf", "-2", "1.00", "1.0", "nan"], ordered);
+```
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct TotalOrd<D>(pub D);
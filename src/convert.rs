@@ -15,10 +15,16 @@ use crate::{
         is_nan,
         is_quiet_nan,
         is_sign_negative,
+        precision_digits,
         BinaryBuf,
         BinaryExponent,
+        MostSignificantDigit,
     },
     num::Integer,
+    ops::{
+        decimal_coefficient_and_exponent,
+        decimal_is_zero,
+    },
     text::{
         ParsedDecimal,
         ParsedDecimalPoint,
@@ -33,25 +39,109 @@ use crate::{
 };
 use core::{
     fmt,
+    iter,
     str,
 };
 
+#[cfg(feature = "float")]
 mod from_binary_float;
+mod from_decimal;
 mod from_int;
 mod from_str;
+#[cfg(feature = "float")]
+mod to_binary_float;
+mod to_engineering;
+#[cfg(feature = "alloc")]
+mod to_gda;
 
+#[cfg(feature = "float")]
 pub(crate) use self::{
     from_binary_float::*,
+    to_binary_float::*,
+};
+
+pub(crate) use self::{
+    from_decimal::*,
     from_int::*,
     from_str::*,
+    to_engineering::*,
 };
 
+#[cfg(feature = "alloc")]
+pub(crate) use self::to_gda::*;
+
+pub use self::from_str::{
+    ParseOptions,
+    ParseStatus,
+};
+
+/**
+How a decimal parsed from text may be changed to make it fit.
+
+By default, a decimal is encoded exactly as it's written, or not at all.
+*/
+#[derive(Debug, Clone, Copy, Default)]
+struct Fit {
+    /**
+    Clamp an exponent that's out of range by padding the significand with zeroes.
+    */
+    clamp_exponent: bool,
+    /**
+    Drop trailing zeroes in the fractional part of the significand that don't fit.
+    */
+    trim_fractional_zeroes: bool,
+}
+
 /**
 Convert a decimal parsed from text into its binary form.
+
+The decimal is encoded exactly as it's written, so if its exponent is out of range, or it has
+too many digits, this will fail.
 */
 pub(crate) fn decimal_from_parsed<D: BinaryBuf, T: TextBuf>(
     parsed: ParsedDecimal<T>,
 ) -> Result<D, OverflowError> {
+    decimal_from_parsed_fit(parsed, Fit::default()).map(|(decimal, _)| decimal)
+}
+
+/**
+Convert a decimal parsed from text into its binary form, reporting whether it was clamped to fit.
+*/
+pub(crate) fn decimal_from_parsed_with_status<D: BinaryBuf, T: TextBuf>(
+    parsed: ParsedDecimal<T>,
+) -> Result<(D, ParseStatus), OverflowError> {
+    decimal_from_parsed_fit(
+        parsed,
+        Fit {
+            clamp_exponent: true,
+            ..Fit::default()
+        },
+    )
+}
+
+/**
+Convert a decimal streamed into a fixed-size text buffer into its binary form.
+
+The text buffer may hold more digits than the decimal can fit. If some of them are trailing
+zeroes in its fractional part then they're dropped instead of failing.
+*/
+pub(crate) fn decimal_from_streamed<D: BinaryBuf, T: TextBuf>(
+    parsed: ParsedDecimal<T>,
+) -> Result<D, OverflowError> {
+    decimal_from_parsed_fit(
+        parsed,
+        Fit {
+            trim_fractional_zeroes: true,
+            ..Fit::default()
+        },
+    )
+    .map(|(decimal, _)| decimal)
+}
+
+fn decimal_from_parsed_fit<D: BinaryBuf, T: TextBuf>(
+    parsed: ParsedDecimal<T>,
+    fit: Fit,
+) -> Result<(D, ParseStatus), OverflowError> {
     match parsed {
         // ±1.234e±5
         ParsedDecimal::Finite(ParsedFinite {
@@ -88,31 +178,68 @@ pub(crate) fn decimal_from_parsed<D: BinaryBuf, T: TextBuf>(
                     let integer_range = significand_range.start..decimal_point_range.start;
                     let fractional_range = decimal_point_range.end..significand_range.end;
 
-                    let integer_digits = &buf[integer_range];
-                    let fractional_digits = &buf[fractional_range];
-
-                    // Account for the fractional part of the number
-                    // This is where the exponent range that an end-user sees may
-                    // be different than what's actually encoded. For example, the
-                    // exponent range of a decimal64 is -383 to 384, as in
-                    // 10e-383 and 10e384. However, for each fractional digit the
-                    // exponent range is decreased by 1. This doesn't change the
-                    // actual range of what's encoded, it just lets you specify
-                    // the same values in different ways. 1.0e-382 and 1.0e385
-                    // are both equivalent to the values mentioned before.
-                    let unbiased_integer_exponent =
-                        unbiased_exponent.lower(fractional_digits.len());
-
-                    // Get a decimal buffer with enough space to fit all the digits
-                    // and the exponent
-                    let mut buf = D::try_with_at_least_precision(
-                        integer_digits.len() + fractional_digits.len(),
-                        Some(&unbiased_integer_exponent),
-                    )?;
-
-                    let msd = encode_significand_trailing_digits(
+                    // Leading zeroes aren't significant, so they don't count towards the
+                    // digits that need to fit. This means `0.0000050` keeps its exponent
+                    let (integer_digits, mut fractional_digits) =
+                        trim_leading_zeroes(&buf[integer_range], &buf[fractional_range.clone()]);
+
+                    // Any skipped fractional zeroes still count towards the exponent
+                    let fractional_leading_zeroes =
+                        fractional_range.len() - fractional_digits.len();
+
+                    // If the number has too many digits to fit, but some of them are trailing
+                    // zeroes in its fractional part, then they can be dropped without changing
+                    // its value if the caller allows it. Only as many zeroes as needed to make
+                    // the number fit are dropped.
+                    let mut is_trimmed = false;
+
+                    let is_zero = is_all_zeroes(integer_digits) && is_all_zeroes(fractional_digits);
+
+                    let (mut buf, unbiased_integer_exponent, clamped_zeroes, is_clamped) = loop {
+                        // Account for the fractional part of the number
+                        // This is where the exponent range that an end-user sees may
+                        // be different than what's actually encoded. For example, the
+                        // exponent range of a decimal64 is -383 to 384, as in
+                        // 10e-383 and 10e384. However, for each fractional digit the
+                        // exponent range is decreased by 1. This doesn't change the
+                        // actual range of what's encoded, it just lets you specify
+                        // the same values in different ways. 1.0e-382 and 1.0e385
+                        // are both equivalent to the values mentioned before.
+                        let unbiased_integer_exponent = unbiased_exponent
+                            .lower(fractional_leading_zeroes + fractional_digits.len());
+
+                        // Get a decimal buffer with enough space to fit all the digits
+                        // and the exponent
+                        match try_with_at_least_precision_fit::<D>(
+                            integer_digits.len() + fractional_digits.len(),
+                            unbiased_integer_exponent.clone(),
+                            is_zero,
+                            fit,
+                        ) {
+                            Ok((buf, clamped_exponent, clamped_zeroes)) => {
+                                let is_clamped = clamped_exponent != unbiased_integer_exponent;
+
+                                break (buf, clamped_exponent, clamped_zeroes, is_clamped);
+                            }
+                            Err(err) => match fractional_digits.split_last() {
+                                // At least one digit is always kept
+                                Some((b'0', trimmed))
+                                    if fit.trim_fractional_zeroes
+                                        && (!integer_digits.is_empty() || !trimmed.is_empty()) =>
+                                {
+                                    fractional_digits = trimmed;
+                                    is_trimmed = true;
+                                }
+                                _ => return Err(err),
+                            },
+                        }
+                    };
+
+                    let msd = encode_significand_digits(
                         &mut buf,
-                        [integer_digits, fractional_digits],
+                        integer_digits,
+                        fractional_digits,
+                        clamped_zeroes,
                     );
 
                     encode_combination_finite(
@@ -122,31 +249,21 @@ pub(crate) fn decimal_from_parsed<D: BinaryBuf, T: TextBuf>(
                         msd,
                     );
 
-                    Ok(buf)
-                }
-                // ±123e4
-                None => {
-                    let integer_range = significand_range;
-                    let integer_digits = &buf[integer_range];
-
-                    // Get a decimal buffer with enough space to fit all the digits
-                    // and the exponent
-                    let mut buf = D::try_with_at_least_precision(
-                        integer_digits.len(),
-                        Some(&unbiased_exponent),
-                    )?;
-
-                    let msd = encode_significand_trailing_digits(&mut buf, [integer_digits]);
-
-                    encode_combination_finite(
-                        &mut buf,
-                        significand_is_negative,
-                        unbiased_exponent,
-                        msd,
-                    );
+                    let status = if is_trimmed || is_clamped {
+                        ParseStatus::clamped()
+                    } else {
+                        ParseStatus::exact()
+                    };
 
-                    Ok(buf)
+                    Ok((buf, status))
                 }
+                // ±123e4
+                None => decimal_from_integer_digits_fit(
+                    significand_is_negative,
+                    &buf[significand_range],
+                    unbiased_exponent,
+                    fit,
+                ),
             }
         }
         // ±inf
@@ -160,7 +277,7 @@ pub(crate) fn decimal_from_parsed<D: BinaryBuf, T: TextBuf>(
 
             encode_combination_infinity(&mut buf, is_infinity_negative);
 
-            Ok(buf)
+            Ok((buf, ParseStatus::exact()))
         }
         // ±nan(123)
         ParsedDecimal::Nan(ParsedNan {
@@ -172,22 +289,31 @@ pub(crate) fn decimal_from_parsed<D: BinaryBuf, T: TextBuf>(
                 },
             nan_payload,
         }) => {
+            let payload_buf = nan_buf.get_ascii();
+
+            // Leading zeroes in the payload aren't significant, so they're not written when
+            // the NaN is formatted. They're ignored here so they don't affect the width of
+            // the decimal either. A payload that's empty or all zeroes is the same as none
+            let payload = nan_payload
+                .map(|payload| &payload_buf[payload.significand_range])
+                .map(|payload| {
+                    let leading_zeroes = payload.iter().take_while(|b| **b == b'0').count();
+
+                    &payload[leading_zeroes..]
+                })
+                .filter(|payload| !payload.is_empty());
+
             // If the NaN was parsed with a payload then encode it.
             //
             // This process is the same as finite integers.
-            if let Some(ParsedSignificand {
-                significand_range, ..
-            }) = nan_payload
-            {
-                let payload_buf = nan_buf.get_ascii();
+            if let Some(payload) = payload {
+                let mut buf = D::try_with_at_least_precision(payload.len() + 1, None)?;
 
-                let mut buf = D::try_with_at_least_precision(significand_range.len() + 1, None)?;
-
-                encode_significand_trailing_digits(&mut buf, [&payload_buf[significand_range]]);
+                encode_significand_trailing_digits(&mut buf, [payload]);
 
                 encode_combination_nan(&mut buf, is_nan_negative, is_nan_signaling);
 
-                Ok(buf)
+                Ok((buf, ParseStatus::exact()))
             }
             // If the NaN doesn't have a payload then just ask for the minimum size buffer,
             // just like we do for infinities.
@@ -197,17 +323,575 @@ pub(crate) fn decimal_from_parsed<D: BinaryBuf, T: TextBuf>(
 
                 encode_combination_nan(&mut buf, is_nan_negative, is_nan_signaling);
 
-                Ok(buf)
+                Ok((buf, ParseStatus::exact()))
             }
         }
     }
 }
 
+/**
+Convert the ASCII digits of an integer significand with an exponent into its binary form.
+
+This is the same as parsing a decimal like `123e4`, but without needing to run it through the
+parser first. The digits must all be ASCII digits.
+*/
+pub(crate) fn decimal_from_integer_digits<D: BinaryBuf>(
+    is_negative: bool,
+    integer_digits: &[u8],
+    unbiased_exponent: D::Exponent,
+) -> Result<D, OverflowError> {
+    decimal_from_integer_digits_fit(
+        is_negative,
+        integer_digits,
+        unbiased_exponent,
+        Fit::default(),
+    )
+    .map(|(decimal, _)| decimal)
+}
+
+/**
+Convert the ASCII digits of an integer significand with an exponent into its binary form,
+reporting whether it was clamped to fit.
+*/
+pub(crate) fn decimal_from_integer_digits_with_status<D: BinaryBuf>(
+    is_negative: bool,
+    integer_digits: &[u8],
+    unbiased_exponent: D::Exponent,
+) -> Result<(D, ParseStatus), OverflowError> {
+    decimal_from_integer_digits_fit(
+        is_negative,
+        integer_digits,
+        unbiased_exponent,
+        Fit {
+            clamp_exponent: true,
+            ..Fit::default()
+        },
+    )
+}
+
+fn decimal_from_integer_digits_fit<D: BinaryBuf>(
+    is_negative: bool,
+    integer_digits: &[u8],
+    unbiased_exponent: D::Exponent,
+    fit: Fit,
+) -> Result<(D, ParseStatus), OverflowError> {
+    let (integer_digits, _) = trim_leading_zeroes(integer_digits, &[]);
+
+    // Get a decimal buffer with enough space to fit all the digits
+    // and the exponent
+    let (mut buf, clamped_exponent, clamped_zeroes) = try_with_at_least_precision_fit::<D>(
+        integer_digits.len(),
+        unbiased_exponent.clone(),
+        is_all_zeroes(integer_digits),
+        fit,
+    )?;
+
+    let is_clamped = clamped_exponent != unbiased_exponent;
+
+    let msd = encode_significand_digits(&mut buf, integer_digits, &[], clamped_zeroes);
+
+    encode_combination_finite(&mut buf, is_negative, clamped_exponent, msd);
+
+    let status = if is_clamped {
+        ParseStatus::clamped()
+    } else {
+        ParseStatus::exact()
+    };
+
+    Ok((buf, status))
+}
+
+// The width of the widest decimal that might need clamping, which is a decimal256.
+// Wider decimals, like arbitrary precision ones, will grow to fit their exponent
+// rather than clamping it.
+const CLAMPED_MAX_WIDTH_BYTES: usize = 32;
+
+// Zeroes to pad the significand of a decimal with when its exponent is clamped.
+//
+// This needs to be at least as long as the precision of the widest decimal that
+// might need clamping.
+const CLAMPED_ZEROES: &[u8] = &[b'0'; precision_digits(CLAMPED_MAX_WIDTH_BYTES * 8)];
+
+/**
+Encode the integer and fractional digits of a decimal, followed by any zeroes its exponent was clamped by.
+*/
+fn encode_significand_digits<D: BinaryBuf>(
+    decimal: &mut D,
+    integer_digits: &[u8],
+    fractional_digits: &[u8],
+    clamped_zeroes: usize,
+) -> MostSignificantDigit {
+    let clamped_zeroes = &CLAMPED_ZEROES[..clamped_zeroes];
+
+    // Empty chunks can't be encoded, so only pass the ones that have digits
+    match (
+        integer_digits.is_empty(),
+        fractional_digits.is_empty(),
+        clamped_zeroes.is_empty(),
+    ) {
+        (false, true, true) => encode_significand_trailing_digits(decimal, [integer_digits]),
+        (false, false, true) => {
+            encode_significand_trailing_digits(decimal, [integer_digits, fractional_digits])
+        }
+        (false, true, false) => {
+            encode_significand_trailing_digits(decimal, [integer_digits, clamped_zeroes])
+        }
+        (false, false, false) => encode_significand_trailing_digits(
+            decimal,
+            [integer_digits, fractional_digits, clamped_zeroes],
+        ),
+        (true, false, true) => encode_significand_trailing_digits(decimal, [fractional_digits]),
+        (true, false, false) => {
+            encode_significand_trailing_digits(decimal, [fractional_digits, clamped_zeroes])
+        }
+        (true, true, _) => unreachable!("decimals always have at least one digit"),
+    }
+}
+
+/**
+Skip the leading zeroes in the integer and fractional digits of a decimal.
+
+If the integer digits are all zeroes then they're skipped entirely, along with any leading zeroes
+in the fractional digits. If every digit is a zero then only the last one is kept.
+
+Leading zeroes are never significant, so the General Decimal Arithmetic specification's conversion
+from text ignores them when checking whether a number fits its precision. Without trimming them,
+a number like `0.0000050` would need more digits than it has.
+*/
+fn trim_leading_zeroes<'a>(
+    integer_digits: &'a [u8],
+    fractional_digits: &'a [u8],
+) -> (&'a [u8], &'a [u8]) {
+    let integer_zeroes = integer_digits.iter().take_while(|d| **d == b'0').count();
+
+    if integer_zeroes < integer_digits.len() {
+        return (&integer_digits[integer_zeroes..], fractional_digits);
+    }
+
+    let fractional_zeroes = fractional_digits.iter().take_while(|d| **d == b'0').count();
+
+    if fractional_zeroes < fractional_digits.len() {
+        return (&[], &fractional_digits[fractional_zeroes..]);
+    }
+
+    match (fractional_digits.len(), integer_digits.len()) {
+        (0, integer) => (&integer_digits[integer.saturating_sub(1)..], &[]),
+        (fractional, _) => (&[], &fractional_digits[fractional - 1..]),
+    }
+}
+
+fn is_all_zeroes(digits: &[u8]) -> bool {
+    digits.iter().all(|d| *d == b'0')
+}
+
+/**
+Get a buffer that can fit a finite decimal with the given digits and exponent, clamping its
+exponent if that's allowed.
+
+This method returns the buffer along with the exponent to encode and the number of trailing
+zeroes to pad the significand with.
+*/
+fn try_with_at_least_precision_fit<D: BinaryBuf>(
+    integer_digits: usize,
+    unbiased_integer_exponent: D::Exponent,
+    is_zero: bool,
+    fit: Fit,
+) -> Result<(D, D::Exponent, usize), OverflowError> {
+    if fit.clamp_exponent {
+        try_with_at_least_precision_clamped::<D>(integer_digits, unbiased_integer_exponent, is_zero)
+    } else {
+        let buf = D::try_with_at_least_precision(integer_digits, Some(&unbiased_integer_exponent))?;
+
+        Ok((buf, unbiased_integer_exponent, 0))
+    }
+}
+
+/**
+Get a buffer that can fit a finite decimal with the given digits and exponent.
+
+If the exponent is too large to fit, but the decimal would fit by padding its significand
+with trailing zeroes and lowering its exponent to match, then the exponent is clamped.
+For example, `1e91` can't be encoded in a decimal32 as-is, but `10e90` can.
+
+Zeroes can have their exponent clamped in either direction, since they don't have any digits
+to lose. For example, `0e100` is encoded in a decimal32 as `0e90`. This matches the General
+Decimal Arithmetic specification's conversion from text, which clamps the exponent of a zero
+to the nearest one it can encode rather than overflowing or underflowing.
+
+This method returns the buffer along with the exponent to encode and the number of trailing
+zeroes to pad the significand with.
+*/
+fn try_with_at_least_precision_clamped<D: BinaryBuf>(
+    integer_digits: usize,
+    unbiased_integer_exponent: D::Exponent,
+    is_zero: bool,
+) -> Result<(D, D::Exponent, usize), OverflowError> {
+    let err = match D::try_with_at_least_precision(integer_digits, Some(&unbiased_integer_exponent))
+    {
+        Ok(buf) => return Ok((buf, unbiased_integer_exponent, 0)),
+        Err(err) => err,
+    };
+
+    if is_zero {
+        let Ok(buf) = D::try_with_at_least_precision(integer_digits, None) else {
+            return Err(err);
+        };
+
+        let max_exponent = D::Exponent::emax(&buf).lower(buf.precision_digits() - 1);
+        let min_exponent = D::Exponent::emin(&buf).lower(buf.precision_digits() - 1);
+
+        let clamped_exponent = if unbiased_integer_exponent > max_exponent {
+            max_exponent
+        } else if unbiased_integer_exponent < min_exponent {
+            min_exponent
+        } else {
+            return Err(err);
+        };
+
+        return Ok((buf, clamped_exponent, 0));
+    }
+
+    // Only large exponents can be clamped without losing any digits.
+    // Lowering the exponent means padding the significand with zeroes, while
+    // raising it would mean dropping digits
+    if unbiased_integer_exponent.is_negative() {
+        return Err(err);
+    }
+
+    let Ok(narrowest) = D::try_with_at_least_precision(integer_digits, None) else {
+        return Err(err);
+    };
+
+    // Dynamically sized decimals could clamp their exponent in any width that fits their digits,
+    // so try each of them from the narrowest up
+    let narrowest_width_bytes = narrowest.bytes().len();
+    let wider = (narrowest_width_bytes + 4..=CLAMPED_MAX_WIDTH_BYTES)
+        .step_by(4)
+        .filter_map(|width_bytes| D::try_with_exactly_storage_width_bytes(width_bytes).ok());
+
+    iter::once(narrowest)
+        .chain(wider)
+        .find_map(|buf| {
+            // The largest exponent that can be encoded is when all the digits of the significand
+            // are on the integer side of the decimal point
+            let max_exponent = D::Exponent::emax(&buf).lower(buf.precision_digits() - 1);
+
+            let max_clamped_zeroes = buf
+                .precision_digits()
+                .saturating_sub(integer_digits)
+                .min(CLAMPED_ZEROES.len());
+
+            let clamped_zeroes = (1..=max_clamped_zeroes).find(|clamped_zeroes| {
+                unbiased_integer_exponent.lower(*clamped_zeroes) <= max_exponent
+            })?;
+
+            let clamped_exponent = unbiased_integer_exponent.lower(clamped_zeroes);
+
+            Some((buf, clamped_exponent, clamped_zeroes))
+        })
+        .ok_or(err)
+}
+
+/**
+How to round a number that can't be represented exactly.
+
+These are the rounding-direction attributes defined by IEEE 754.
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /**
+    Round to the nearest representable number, choosing the one with an even least significant
+    digit if the number is exactly halfway between two of them.
+
+    This is the default rounding mode of IEEE 754, and the one used by the standard library
+    when parsing floating points from text.
+    */
+    #[default]
+    NearestTiesToEven,
+    /**
+    Round to the nearest representable number, choosing the one with the larger magnitude if the
+    number is exactly halfway between two of them.
+    */
+    NearestTiesToAway,
+    /**
+    Round to the nearest representable number that's no larger in magnitude, truncating it.
+    */
+    TowardZero,
+    /**
+    Round to the nearest representable number that's no smaller, rounding up towards positive infinity.
+    */
+    TowardPositive,
+    /**
+    Round to the nearest representable number that's no larger, rounding down towards negative infinity.
+    */
+    TowardNegative,
+}
+
+/**
+Options for formatting a decimal as text.
+
+The default options produce the same text as a decimal's `Display` implementation, like `1.5e10`.
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FormatOptions {
+    explicit_positive_exponent: bool,
+    uppercase_exponent: bool,
+    scientific_notation: bool,
+}
+
+impl FormatOptions {
+    /**
+    Get the default formatting options.
+    */
+    pub const fn new() -> Self {
+        FormatOptions {
+            explicit_positive_exponent: false,
+            uppercase_exponent: false,
+            scientific_notation: false,
+        }
+    }
+
+    /**
+    Whether to write a `+` sign on positive exponents when a decimal is written in scientific notation.
+
+    By default, positive exponents are written without a sign, as in `1.5e10`. When this option is
+    set they're written as `1.5e+10` instead. Negative exponents are always written with a `-` sign.
+    */
+    pub const fn explicit_positive_exponent(mut self, explicit_positive_exponent: bool) -> Self {
+        self.explicit_positive_exponent = explicit_positive_exponent;
+        self
+    }
+
+    /**
+    Whether to write the exponent marker as `E` rather than `e` when a decimal is written in scientific notation.
+
+    Along with [`explicit_positive_exponent`](#method.explicit_positive_exponent), this produces
+    exponents in the form `1.5E+10`, which is what the General Decimal Arithmetic specification uses.
+    Both forms can be parsed.
+    */
+    pub const fn uppercase_exponent(mut self, uppercase_exponent: bool) -> Self {
+        self.uppercase_exponent = uppercase_exponent;
+        self
+    }
+
+    /**
+    Whether to always write finite decimals in scientific notation.
+
+    By default, decimals are written in whichever of fixed or scientific notation suits them,
+    like `123.456` or `1.5e10`. When this option is set, they're always written with exactly
+    one digit before the decimal point, like `1.23456e2`. Zero is written as `0e0`.
+    Infinities and NaNs are written the same either way.
+    */
+    pub const fn scientific_notation(mut self, scientific_notation: bool) -> Self {
+        self.scientific_notation = scientific_notation;
+        self
+    }
+}
+
+/**
+A decimal that's formatted with a set of options.
+*/
+pub(crate) struct DisplayWith<'a, D>(pub(crate) &'a D, pub(crate) FormatOptions);
+
+impl<'a, D: BinaryBuf> fmt::Display for DisplayWith<'a, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        decimal_to_fmt_with_options(self.0, &self.1, f)
+    }
+}
+
+/**
+The digits of a decimal's coefficient, without its sign, decimal point, or exponent.
+*/
+pub(crate) struct CoefficientDisplay<'a, D>(pub(crate) &'a D, pub(crate) bool);
+
+impl<'a, D: BinaryBuf> fmt::Display for CoefficientDisplay<'a, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        decimal_coefficient_to_fmt(self.0, self.1, f)
+    }
+}
+
+/**
+Write the digits of a decimal's coefficient.
+
+If `keep_leading_zeroes` is `false` then leading zeroes are skipped, but at least one digit is
+always written. Infinities and NaNs don't have a most significant digit, so only the digits of
+their trailing significand are written, which for a NaN is its payload.
+*/
+pub(crate) fn decimal_coefficient_to_fmt<D: BinaryBuf>(
+    decimal: &D,
+    keep_leading_zeroes: bool,
+    mut out: impl fmt::Write,
+) -> Result<(), fmt::Error> {
+    let msd = if is_finite(decimal) {
+        let (_, msd) = decode_combination_finite(decimal);
+
+        Some(msd.get_ascii())
+    } else {
+        None
+    };
+
+    let mut is_leading = !keep_leading_zeroes;
+
+    let digits = msd
+        .into_iter()
+        .chain(decode_significand_trailing_declets(decimal).flatten());
+
+    for digit in digits {
+        if is_leading && digit == b'0' {
+            continue;
+        }
+
+        is_leading = false;
+        out.write_char(digit as char)?;
+    }
+
+    // If every digit was a leading zero then the coefficient is zero
+    if is_leading {
+        out.write_char('0')?;
+    }
+
+    Ok(())
+}
+
 /**
 Convert a decimal in its binary form into text.
 */
 pub(crate) fn decimal_to_fmt<D: BinaryBuf>(
     decimal: &D,
+    out: impl fmt::Write,
+) -> Result<(), fmt::Error> {
+    decimal_to_fmt_with_options(decimal, &FormatOptions::new(), out)
+}
+
+/**
+Convert a decimal in its binary form into text in the given buffer, returning the written text.
+
+If the buffer is too small then an error is returned with the number of bytes needed.
+The contents of the buffer are unspecified in that case.
+*/
+pub(crate) fn decimal_to_str_buf<'b, D: BinaryBuf>(
+    decimal: &D,
+    buf: &'b mut [u8],
+) -> Result<&'b str, OverflowError> {
+    struct SliceWriter<'b> {
+        buf: &'b mut [u8],
+        len: usize,
+    }
+
+    impl<'b> fmt::Write for SliceWriter<'b> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+
+            self.buf
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+
+            Ok(())
+        }
+    }
+
+    struct LenWriter(usize);
+
+    impl fmt::Write for LenWriter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.len();
+
+            Ok(())
+        }
+    }
+
+    let mut writer = SliceWriter { buf, len: 0 };
+
+    if decimal_to_fmt(decimal, &mut writer).is_err() {
+        // Format the decimal again to work out how many bytes it needs
+        let mut required = LenWriter(0);
+        let _ = decimal_to_fmt(decimal, &mut required);
+
+        return Err(OverflowError::text_buffer_too_small(
+            writer.buf.len(),
+            required.0,
+        ));
+    }
+
+    let SliceWriter { buf, len } = writer;
+
+    Ok(str::from_utf8(&buf[..len]).expect("formatted decimals are always ASCII"))
+}
+
+/**
+Convert a decimal in its binary form into text with exactly `precision` fractional digits.
+
+The decimal is always written in fixed notation, like `123.450`. If it has fewer fractional
+digits than `precision` then it's padded with trailing zeroes. If it has more then the extra
+digits are truncated. They're not rounded, so `1.99` with a precision of `1` is written as `1.9`.
+
+Infinities and NaNs ignore the precision and are written the same as they are by `decimal_to_fmt`.
+So are arbitrary precision decimals with exponents that don't fit in an `i32`.
+*/
+pub(crate) fn decimal_to_fmt_with_precision<D: BinaryBuf>(
+    decimal: &D,
+    precision: usize,
+    mut out: impl fmt::Write,
+) -> Result<(), fmt::Error> {
+    let Some((is_negative, digits, exponent)) = decimal_coefficient_and_exponent(decimal) else {
+        return decimal_to_fmt(decimal, out);
+    };
+
+    if is_negative {
+        out.write_char('-')?;
+    }
+
+    // A zero coefficient doesn't contribute any digits
+    let total_digits = if decimal_is_zero(decimal) {
+        0
+    } else {
+        digits.len()
+    };
+    let mut digits = digits.take(total_digits);
+
+    // The number of digits before the decimal point
+    // If this is negative then the digits start that many places after the decimal point
+    let integer_digits = if total_digits > 0 {
+        total_digits as i64 + exponent as i64
+    } else {
+        0
+    };
+
+    if integer_digits > 0 {
+        for _ in 0..integer_digits {
+            out.write_char(digits.next().unwrap_or(b'0') as char)?;
+        }
+    } else {
+        out.write_char('0')?;
+    }
+
+    if precision > 0 {
+        out.write_char('.')?;
+
+        let leading_zeroes = integer_digits.min(0).unsigned_abs();
+
+        for i in 0..precision {
+            let digit = if (i as u64) < leading_zeroes {
+                b'0'
+            } else {
+                digits.next().unwrap_or(b'0')
+            };
+
+            out.write_char(digit as char)?;
+        }
+    }
+
+    Ok(())
+}
+
+/**
+Convert a decimal in its binary form into text, using the given formatting options.
+*/
+pub(crate) fn decimal_to_fmt_with_options<D: BinaryBuf>(
+    decimal: &D,
+    options: &FormatOptions,
     mut out: impl fmt::Write,
 ) -> Result<(), fmt::Error> {
     // Write the sign
@@ -231,7 +915,8 @@ pub(crate) fn decimal_to_fmt<D: BinaryBuf>(
         // extra two zeroes accounted for.
         let mut declets = decode_significand_trailing_declets(decimal);
 
-        match exponent.to_i32() {
+        // If scientific notation is forced then skip straight to it
+        match exponent.to_i32().filter(|_| !options.scientific_notation) {
             // ±123
             Some(0) => {
                 write_all_as_integer(
@@ -330,6 +1015,7 @@ pub(crate) fn decimal_to_fmt<D: BinaryBuf>(
                                 skipped,
                                 declets,
                                 exponent,
+                                options,
                                 &mut written,
                                 &mut out,
                             )?;
@@ -341,6 +1027,7 @@ pub(crate) fn decimal_to_fmt<D: BinaryBuf>(
                             skipped,
                             declets,
                             exponent,
+                            options,
                             &mut written,
                             &mut out,
                         )?;
@@ -353,6 +1040,7 @@ pub(crate) fn decimal_to_fmt<D: BinaryBuf>(
                     skip_leading_zeroes(msd, &mut declets),
                     declets,
                     exponent,
+                    options,
                     &mut written,
                     &mut out,
                 )?;
@@ -549,16 +1237,19 @@ fn write_all_as_scientific(
     leading_zeroes: LeadingZeroes,
     mut declets: impl Iterator<Item = [u8; 3]>,
     exponent: impl BinaryExponent,
+    options: &FormatOptions,
     written: &mut usize,
     mut out: impl fmt::Write,
 ) -> Result<(), fmt::Error> {
     // Write the first declet along with a decimal point
+    let mut written_decimal_point = false;
+
     if let LeadingZeroes {
         partial_declet: Some((declet, idx)),
         ..
     } = leading_zeroes
     {
-        write_decimal_digits(&declet[idx..], 1, written, &mut out)?;
+        written_decimal_point = write_decimal_digits(&declet[idx..], 1, written, &mut out)?;
     } else if let Some(declet) = declets.next() {
         write_content(
             str::from_utf8(&[declet[0], b'.', declet[1], declet[2]]).map_err(|_| fmt::Error)?,
@@ -566,16 +1257,28 @@ fn write_all_as_scientific(
             written,
             &mut out,
         )?;
+
+        written_decimal_point = true;
     }
 
     // Write the remaining digits
+    // If the first declet only had a single digit then the decimal point
+    // is written at the start of the next one
     for declet in declets {
-        write_declet(declet, written, &mut out)?;
+        if written_decimal_point {
+            write_declet(declet, written, &mut out)?;
+        } else {
+            written_decimal_point = write_decimal_digits(&declet, 1, written, &mut out)?;
+        }
     }
 
     // If no digits were written, then write a zero
     if *written == 0 {
-        out.write_str("0e")?;
+        out.write_char('0')?;
+    }
+
+    if options.uppercase_exponent {
+        out.write_char('E')?;
     } else {
         out.write_char('e')?;
     }
@@ -585,6 +1288,10 @@ fn write_all_as_scientific(
     // This means raising it to account for the number of fractional digits written.
     let exponent = exponent.raise(written.saturating_sub(1));
 
+    if options.explicit_positive_exponent && !exponent.is_negative() {
+        out.write_char('+')?;
+    }
+
     // Write the exponent into the buffer
     exponent.to_fmt(&mut out)?;
 
@@ -21,6 +21,7 @@ use crate::{
         Integer,
     },
     text::{
+        ArrayTextBuf,
         FiniteParser,
         ParsedDecimal,
         ParsedInfinity,
@@ -29,6 +30,7 @@ use crate::{
         StrTextBuf,
     },
     ConvertError,
+    Error,
     OverflowError,
 };
 
@@ -88,6 +90,11 @@ pub(crate) fn decimal_from_binary_float<D: BinaryBuf, F: Float + ryu::Float>(
         let mut buf = ryu::Buffer::new();
         let f = buf.format_finite(float);
 
+        // ryū writes integral floats with a trailing `.0`, like `1.0` or `-0.0`.
+        // That zero isn't a significant digit, so it's dropped to give integral floats
+        // the same exponent as integers, making `0f64` the same decimal as `0i32`
+        let f = f.strip_suffix(".0").unwrap_or(f);
+
         // ryū picks a reasonable representation for floating points, picking
         // between scientific and regular formatting automatically
         decimal_from_parsed(ParsedDecimal::Finite(
@@ -135,3 +142,37 @@ pub(crate) fn decimal_from_binary_float<D: BinaryBuf, F: Float + ryu::Float>(
         }))
     }
 }
+
+/**
+Encode a binary floating point as a decimal with exactly `sig_digits` significant digits.
+
+Unlike [`decimal_from_binary_float`], which picks the shortest digits that round-trip, the exact
+value of the float is rounded to the requested number of digits, like `printf`'s `%.*e`. Trailing
+zeroes are kept, so the decimal always has `sig_digits` digits. Infinities and NaNs are encoded
+the same way as [`decimal_from_binary_float`].
+*/
+pub(crate) fn decimal_from_f64_precision<D: BinaryBuf>(
+    float: f64,
+    sig_digits: u8,
+) -> Result<D, Error> {
+    // Make sure the number of digits fits before formatting anything
+    if sig_digits == 0 {
+        return Err(ConvertError::no_significant_digits("decimal").into());
+    }
+
+    D::try_with_at_least_precision(sig_digits as usize, None)?;
+
+    if !float.is_finite() {
+        return Ok(decimal_from_binary_float(float)?);
+    }
+
+    // Rust's float formatting with an explicit precision is exact, so this is
+    // the same as `%.*e` in `printf`. The text is at most 255 digits along with
+    // a sign, decimal point, and exponent, so it always fits in the buffer
+    let parsed = FiniteParser::parse(
+        ArrayTextBuf::<264>::default(),
+        format_args!("{:.*e}", sig_digits as usize - 1, float),
+    )?;
+
+    Ok(decimal_from_parsed(ParsedDecimal::Finite(parsed))?)
+}
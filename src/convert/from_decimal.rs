@@ -0,0 +1,340 @@
+/*!
+Convert decimals between different widths.
+*/
+
+use core::{
+    cmp,
+    iter,
+};
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_digits_rev,
+        encode_combination_finite,
+        encode_combination_infinity,
+        encode_combination_nan,
+        encode_significand_trailing_digits_rev,
+        is_infinite,
+        is_nan,
+        is_sign_negative,
+        is_signaling_nan,
+        BinaryBuf,
+        BinaryExponent,
+    },
+    num::Integer,
+    OverflowError,
+};
+
+/**
+Convert a decimal into one of a potentially different width, removing trailing zeroes if needed.
+
+If the decimal fits in the target as-is then its exponent is kept. Otherwise, the exponent is
+adjusted by removing trailing zeroes from the significand, or by padding it with them, to find the
+nearest exponent the target can encode. This changes the cohort of the decimal, but never its value.
+If there's no such exponent then an error is returned.
+*/
+pub(crate) fn decimal_try_fit<DFrom: BinaryBuf, DTo: BinaryBuf>(
+    decimal: &DFrom,
+) -> Result<DTo, OverflowError> {
+    let width_bytes = decimal.bytes().len();
+    let is_negative = is_sign_negative(decimal);
+
+    // ±inf
+    if is_infinite(decimal) {
+        let mut buf = DTo::try_with_at_least_storage_width_bytes(4)?;
+        encode_combination_infinity(&mut buf, is_negative);
+
+        return Ok(buf);
+    }
+
+    // ±nan(123)
+    if is_nan(decimal) {
+        let payload_digits = significant_digits(decode_significand_trailing_digits_rev(decimal));
+
+        let mut buf = DTo::try_with_at_least_precision(payload_digits + 1, None)?;
+        encode_significand_trailing_digits_rev(
+            &mut buf,
+            decode_significand_trailing_digits_rev(decimal).take(payload_digits),
+        );
+        encode_combination_nan(&mut buf, is_negative, is_signaling_nan(decimal));
+
+        return Ok(buf);
+    }
+
+    // ±123e±4
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    let exponent = exponent.to_i32().ok_or_else(|| {
+        OverflowError::exponent_out_of_range(
+            width_bytes,
+            "the decimal's exponent is too large to convert",
+        )
+    })? as i64;
+
+    encode_finite_fit(
+        is_negative,
+        || decode_significand_trailing_digits_rev(decimal).chain(iter::once(msd.get_ascii())),
+        exponent,
+        width_bytes,
+    )
+}
+
+/**
+Encode a finite decimal from its digits, adjusting its exponent to fit if needed.
+
+The digits are given from least to most significant, and may include leading zeroes.
+If the digits and exponent fit as-is then they're encoded without changes. Otherwise, trailing
+zeroes are removed from the significand, or added to it, to find the nearest exponent that fits
+in a buffer of `width_bytes`, or the widest buffer available if that's not supported.
+*/
+pub(crate) fn encode_finite_fit<D: BinaryBuf, I: Iterator<Item = u8>>(
+    is_negative: bool,
+    digits: impl Fn() -> I,
+    exponent: i64,
+    width_bytes: usize,
+) -> Result<D, OverflowError> {
+    let significant_digits = significant_digits(digits());
+
+    // If the decimal fits as-is then keep its exponent
+    let fits = i32::try_from(exponent).ok().and_then(|exponent| {
+        D::try_with_at_least_precision(
+            cmp::max(significant_digits, 1),
+            Some(&D::Exponent::from_i32(exponent)),
+        )
+        .ok()
+    });
+
+    match fits {
+        Some(buf) => Ok(encode_finite_at(
+            buf,
+            is_negative,
+            digits,
+            exponent,
+            exponent,
+        )),
+        None => {
+            // Try fit the decimal in a buffer of the same width, or the largest one available
+            let buf = D::try_with_at_least_storage_width_bytes(width_bytes).or_else(|err| {
+                (4..width_bytes)
+                    .step_by(4)
+                    .rev()
+                    .find_map(|width_bytes| {
+                        D::try_with_at_least_storage_width_bytes(width_bytes).ok()
+                    })
+                    .ok_or(err)
+            })?;
+
+            encode_finite_fit_buf(buf, is_negative, digits, exponent)
+        }
+    }
+}
+
+/**
+Encode a finite decimal from its digits into the given buffer, adjusting its exponent to fit if needed.
+
+This is like [`encode_finite_fit`], except the width of the buffer is never changed. If the digits and
+exponent fit as-is then they're encoded without changes. Otherwise, trailing zeroes are removed from
+the significand, or added to it, to find the nearest exponent that fits.
+*/
+pub(crate) fn encode_finite_fit_buf<D: BinaryBuf, I: Iterator<Item = u8>>(
+    buf: D,
+    is_negative: bool,
+    digits: impl Fn() -> I,
+    exponent: i64,
+) -> Result<D, OverflowError> {
+    let significant_digits = significant_digits(digits());
+    let trailing_zeroes = digits().take_while(|digit| *digit == b'0').count();
+
+    let precision_digits = buf.precision_digits() as i64;
+
+    let max_exponent = D::Exponent::emax(&buf)
+        .lower(buf.precision_digits() - 1)
+        .to_i32()
+        .map(|exponent| exponent as i64)
+        .unwrap_or(i64::MAX);
+    let min_exponent = D::Exponent::emin(&buf)
+        .lower(buf.precision_digits() - 1)
+        .to_i32()
+        .map(|exponent| exponent as i64)
+        .unwrap_or(i64::MIN);
+
+    // Raising the exponent removes trailing zeroes, and lowering it pads them
+    let (lowest_exponent, highest_exponent) = if significant_digits == 0 {
+        (min_exponent, max_exponent)
+    } else {
+        (
+            cmp::max(
+                exponent + significant_digits as i64 - precision_digits,
+                min_exponent,
+            ),
+            cmp::min(exponent + trailing_zeroes as i64, max_exponent),
+        )
+    };
+
+    if lowest_exponent > highest_exponent {
+        return Err(
+            if significant_digits - trailing_zeroes > buf.precision_digits() {
+                OverflowError::would_overflow(
+                    buf.bytes().len(),
+                    D::try_with_at_least_precision(significant_digits - trailing_zeroes, None)
+                        .map(|buf| buf.bytes().len())
+                        .unwrap_or(buf.bytes().len()),
+                )
+            } else {
+                OverflowError::exponent_out_of_range(
+                    buf.bytes().len(),
+                    "the decimal's exponent can't be adjusted to fit",
+                )
+            },
+        );
+    }
+
+    let fit_exponent = cmp::min(cmp::max(exponent, lowest_exponent), highest_exponent);
+
+    Ok(encode_finite_at(
+        buf,
+        is_negative,
+        digits,
+        exponent,
+        fit_exponent,
+    ))
+}
+
+/**
+Encode a finite decimal from its digits with the exponent `fit_exponent`.
+
+The digits are given with the exponent `exponent`, so they're adjusted by removing trailing zeroes
+or padding them to match.
+*/
+fn encode_finite_at<D: BinaryBuf, I: Iterator<Item = u8>>(
+    mut buf: D,
+    is_negative: bool,
+    digits: impl Fn() -> I,
+    exponent: i64,
+    fit_exponent: i64,
+) -> D {
+    let removed_zeroes = cmp::max(fit_exponent - exponent, 0) as usize;
+    let padded_zeroes = cmp::max(exponent - fit_exponent, 0) as usize;
+
+    let msd = encode_significand_trailing_digits_rev(
+        &mut buf,
+        iter::repeat_n(b'0', padded_zeroes).chain(digits().skip(removed_zeroes)),
+    );
+    encode_combination_finite(
+        &mut buf,
+        is_negative,
+        D::Exponent::from_i32(fit_exponent as i32),
+        msd,
+    );
+
+    buf
+}
+
+/**
+The number of digits up to and including the most significant non-zero one.
+
+The digits are given from least to most significant.
+*/
+fn significant_digits(digits: impl Iterator<Item = u8>) -> usize {
+    digits
+        .enumerate()
+        .filter(|(_, digit)| *digit != b'0')
+        .last()
+        .map(|(i, _)| i + 1)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::{
+            DynamicBinaryBuf,
+            FixedBinaryBuf,
+        },
+        convert::{
+            decimal_from_str,
+            decimal_to_fmt,
+        },
+    };
+
+    fn fit<DTo: BinaryBuf>(s: &str) -> Result<String, OverflowError> {
+        let d: DynamicBinaryBuf<20> = decimal_from_str(s).expect("failed to parse decimal");
+
+        let fit: DTo = decimal_try_fit(&d)?;
+
+        let mut s = String::new();
+        decimal_to_fmt(&fit, &mut s).expect("failed to format decimal");
+
+        Ok(s)
+    }
+
+    #[test]
+    fn try_fit_finite() {
+        for (d, expected) in [
+            ("0", "0"),
+            ("-0", "-0"),
+            ("1.2300", "1.2300"),
+            ("-123.456", "-123.456"),
+            ("1.230000000", "1.230000"),
+            ("1234567.000", "1234567"),
+            ("1e95", "1.00000e95"),
+            ("1.00e-100", "1.0e-100"),
+            ("0e200", "0e90"),
+            ("0e-200", "0e-101"),
+        ] {
+            assert_eq!(
+                expected,
+                fit::<FixedBinaryBuf<4, i32>>(d).expect("failed to fit decimal"),
+                "{}",
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn try_fit_special() {
+        for (d, expected) in [
+            ("inf", "inf"),
+            ("-inf", "-inf"),
+            ("nan", "nan"),
+            ("-snan(123)", "-snan(123)"),
+            ("nan(000123)", "nan(123)"),
+        ] {
+            assert_eq!(
+                expected,
+                fit::<FixedBinaryBuf<4, i32>>(d).expect("failed to fit decimal"),
+                "{}",
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn try_fit_wider() {
+        for d in ["1.2300", "-1.23456e-298", "nan(123456789)"] {
+            assert_eq!(
+                d,
+                fit::<FixedBinaryBuf<16, i32>>(d).expect("failed to fit decimal"),
+                "{}",
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn err_try_fit() {
+        for d in [
+            "12345678",
+            "1.234567800001",
+            "1e97",
+            "1e-102",
+            "1.5e-101",
+            "nan(1234567)",
+        ] {
+            assert!(fit::<FixedBinaryBuf<4, i32>>(d).is_err(), "{}", d);
+        }
+    }
+}
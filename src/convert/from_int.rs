@@ -11,20 +11,24 @@ use crate::{
     binary::{
         decode_combination_finite,
         decode_significand_trailing_declets,
+        encode_combination_finite,
+        encode_significand_trailing_int_declets_rev,
+        is_finite,
         is_sign_negative,
         BinaryBuf,
     },
-    convert::decimal_from_parsed,
+    convert::decimal_from_integer_digits,
     num::Integer,
-    text::{
-        FiniteParser,
-        ParsedDecimal,
-    },
     ConvertError,
     OverflowError,
 };
 
 pub(crate) fn decimal_to_int<D: BinaryBuf, I: Integer>(decimal: &D) -> Result<I, ConvertError> {
+    // Infinities and NaNs are out of range of any integer
+    if !is_finite(decimal) {
+        return Err(ConvertError::would_overflow(type_name::<I>()));
+    }
+
     let (exp, msd) = decode_combination_finite(decimal);
 
     match exp.to_i32() {
@@ -77,16 +81,262 @@ pub(crate) fn decimal_to_int<D: BinaryBuf, I: Integer>(decimal: &D) -> Result<I,
                 Err(ConvertError::non_integer(type_name::<I>()))
             }
         }
-        // If the exponent is very large or small then it can't be represented as an integer
+        // ±1e-10
+        //
+        // If the exponent is very small then every digit is fractional, so the number
+        // is only an integer if it's zero
+        Some(exponent) if exponent < 0 => {
+            let trailing_significand = decode_significand_trailing_declets(decimal);
+
+            let mut digits = Some(msd.get_ascii())
+                .into_iter()
+                .chain(trailing_significand.flatten());
+
+            if digits.all(|d| d == b'0') {
+                I::try_from_ascii(is_sign_negative(decimal), iter::once(b'0'))
+                    .ok_or_else(|| ConvertError::would_overflow(type_name::<I>()))
+            } else {
+                Err(ConvertError::non_integer(type_name::<I>()))
+            }
+        }
+        // If the exponent is very large then it can't be represented as an integer
         _ => Err(ConvertError::would_overflow(type_name::<I>())),
     }
 }
 
-pub(crate) fn decimal_from_int<D: BinaryBuf, I: itoa::Integer>(int: I) -> Result<D, OverflowError> {
+/**
+Convert an integer into a decimal, scaled down by `scale` powers of ten.
+
+The integer becomes the coefficient of the decimal, and `-scale` its exponent, so `12345` with a
+scale of `2` is `123.45`. The integer's digits are encoded directly, so this is the same as parsing
+`12345e-2`.
+*/
+pub(crate) fn decimal_from_scaled_int<D: BinaryBuf, I: itoa::Integer>(
+    int: I,
+    scale: u32,
+) -> Result<D, OverflowError> {
     let mut buf = itoa::Buffer::new();
-    let int = buf.format(int);
+    let int = buf.format(int).as_bytes();
+
+    let (is_negative, digits) = match int {
+        [b'-', digits @ ..] => (true, digits),
+        digits => (false, digits),
+    };
+
+    let exponent = i32::try_from(scale)
+        .map(|scale| D::Exponent::from_i32(-scale))
+        .map_err(|_| {
+            OverflowError::exponent_out_of_range(4, "the exponent would overflow an `i32`")
+        })?;
+
+    decimal_from_integer_digits(is_negative, digits, exponent)
+}
 
-    decimal_from_parsed(ParsedDecimal::Finite(
-        FiniteParser::parse_str(int).expect("primitive integers can always be parsed"),
-    ))
+/**
+Convert an integer into a decimal with an exponent of `0`.
+
+The digits of the integer are encoded directly from its magnitude, rather than formatting it as
+text and parsing it back. This is the same as parsing the integer as text, but avoids that
+roundtrip, which is most noticeable for 128-bit integers.
+*/
+pub(crate) fn decimal_from_int<D: BinaryBuf, I: IntegerMagnitude>(
+    int: I,
+) -> Result<D, OverflowError> {
+    let (is_negative, magnitude) = int.to_sign_magnitude();
+
+    decimal_from_coefficient_exponent(is_negative, magnitude, 0)
+}
+
+/**
+Convert a sign, integer coefficient, and exponent into a decimal.
+
+The value of the decimal is `coefficient * 10^exponent`. Like `decimal_from_int`, the digits of the
+coefficient are encoded directly from its magnitude, so this is the same as parsing `123456e-3`
+without formatting it first. If the decimal can't fit the coefficient's digits or the exponent then
+an error is returned.
+*/
+pub(crate) fn decimal_from_coefficient_exponent<D: BinaryBuf>(
+    is_negative: bool,
+    coefficient: u128,
+    exponent: i32,
+) -> Result<D, OverflowError> {
+    // Zero still needs a single digit
+    let significant_digits = coefficient
+        .checked_ilog10()
+        .map(|digits| digits as usize + 1)
+        .unwrap_or(1);
+
+    let exponent = D::Exponent::from_i32(exponent);
+
+    let mut buf = D::try_with_at_least_precision(significant_digits, Some(&exponent))?;
+
+    let msd =
+        encode_significand_trailing_int_declets_rev(&mut buf, magnitude_declets_rev(coefficient));
+    encode_combination_finite(&mut buf, is_negative, exponent, msd);
+
+    Ok(buf)
+}
+
+/**
+An integer that can be split into a sign and its magnitude.
+*/
+pub(crate) trait IntegerMagnitude {
+    /**
+    Get whether the integer is negative, along with its absolute value.
+    */
+    fn to_sign_magnitude(self) -> (bool, u128);
+}
+
+macro_rules! impl_integer_magnitude {
+    (signed: $($i:ty),*; unsigned: $($u:ty),*) => {
+        $(
+            impl IntegerMagnitude for $i {
+                fn to_sign_magnitude(self) -> (bool, u128) {
+                    (self < 0, self.unsigned_abs() as u128)
+                }
+            }
+        )*
+
+        $(
+            impl IntegerMagnitude for $u {
+                fn to_sign_magnitude(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_magnitude!(signed: i8, i16, i32, i64, i128; unsigned: u8, u16, u32, u64, u128);
+
+/**
+The declets of an integer, starting from the least significant.
+
+Each declet is a binary integer from `0` to `999` holding 3 of the integer's digits.
+Dividing a `u128` is much slower than dividing a `u64`, so the integer is first split into
+chunks of 18 digits that each fit in a `u64`. Only integers wider than a `u64` need the split,
+and they need at most two 128-bit divisions for it.
+*/
+fn magnitude_declets_rev(magnitude: u128) -> impl Iterator<Item = u16> {
+    const CHUNK_DECLETS: usize = 6;
+    const CHUNK: u128 = 10u128.pow(CHUNK_DECLETS as u32 * 3);
+
+    let mut chunks = [0u64; 3];
+    let mut len = 0;
+
+    let mut rest = magnitude;
+    while rest > u64::MAX as u128 {
+        chunks[len] = (rest % CHUNK) as u64;
+        rest /= CHUNK;
+        len += 1;
+    }
+
+    chunks[len] = rest as u64;
+    len += 1;
+
+    (0..len).flat_map(move |i| {
+        let is_last = i == len - 1;
+        let mut chunk = chunks[i];
+        let mut declets = 0;
+
+        // Chunks before the last are padded with leading zeroes to their full width,
+        // and the last one stops at its most significant declet
+        iter::from_fn(move || {
+            let is_done = if is_last {
+                chunk == 0 && declets > 0
+            } else {
+                declets == CHUNK_DECLETS
+            };
+
+            if is_done {
+                return None;
+            }
+
+            let declet = (chunk % 1000) as u16;
+
+            chunk /= 1000;
+            declets += 1;
+
+            Some(declet)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::{
+            DynamicBinaryBuf,
+            FixedBinaryBuf,
+        },
+        convert::decimal_from_str,
+    };
+
+    fn assert_same_as_str<I: IntegerMagnitude + itoa::Integer + Copy>(i: I) {
+        let mut buf = itoa::Buffer::new();
+        let s = buf.format(i);
+
+        let expected: DynamicBinaryBuf<20> = decimal_from_str(s).expect("failed to parse decimal");
+        let actual: DynamicBinaryBuf<20> = decimal_from_int(i).expect("failed to convert integer");
+
+        assert_eq!(expected.bytes(), actual.bytes(), "{}", s);
+    }
+
+    #[test]
+    fn from_int_matches_from_str() {
+        for i in [
+            0,
+            1,
+            -1,
+            9,
+            10,
+            999,
+            1000,
+            -1234567,
+            12345678,
+            i64::MAX as i128,
+            i64::MIN as i128,
+            u64::MAX as i128,
+            u64::MAX as i128 + 1,
+            999999999999999999,
+            1000000000000000000,
+            1000000000000000000000000000000000000,
+            -1000000000000000000000000000000000001,
+            i128::MAX,
+            i128::MIN,
+        ] {
+            assert_same_as_str(i);
+        }
+
+        for u in [0, 1, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX] {
+            assert_same_as_str(u);
+        }
+
+        for i in [i8::MIN, -1, 0, i8::MAX] {
+            assert_same_as_str(i);
+        }
+
+        for u in [0, 7, u32::MAX] {
+            assert_same_as_str(u);
+        }
+
+        // Every power of ten and its neighbours cross a boundary between digits or chunks
+        let mut pow10 = 1u128;
+        while let Some(next) = pow10.checked_mul(10) {
+            assert_same_as_str(pow10 - 1);
+            assert_same_as_str(pow10);
+            assert_same_as_str(pow10 + 1);
+
+            pow10 = next;
+        }
+    }
+
+    #[test]
+    fn from_int_fixed_overflow() {
+        assert!(decimal_from_int::<FixedBinaryBuf<4, i32>, _>(9999999).is_ok());
+        assert!(decimal_from_int::<FixedBinaryBuf<4, i32>, _>(10000000).is_err());
+        assert!(decimal_from_int::<FixedBinaryBuf<16, i32>, _>(u128::MAX).is_err());
+    }
 }
@@ -5,23 +5,192 @@ Parse numbers in text to decimal.
 use core::fmt;
 
 use crate::{
-    binary::BinaryBuf,
-    convert::decimal_from_parsed,
+    binary::{
+        BinaryBuf,
+        FixedBinaryBuf,
+    },
+    convert::{
+        decimal_from_integer_digits,
+        decimal_from_integer_digits_with_status,
+        decimal_from_parsed,
+        decimal_from_parsed_with_status,
+        decimal_from_streamed,
+    },
     text::{
         DecimalParser,
+        FiniteParser,
+        ParsedDecimal,
         TextBuf,
         TextWriter,
     },
     Error,
+    ParseError,
 };
 
 /**
 Parse and encode a decimal from its text representation.
 */
 pub(crate) fn decimal_from_str<D: BinaryBuf>(f: &str) -> Result<D, Error> {
+    if let Some((is_negative, digits)) = integer_digits(f) {
+        return Ok(decimal_from_integer_digits(
+            is_negative,
+            digits,
+            D::default_exponent(),
+        )?);
+    }
+
     Ok(decimal_from_parsed(DecimalParser::parse_str(f)?)?)
 }
 
+/**
+Split text that's just an optionally signed integer, like `-123`, into its sign and digits.
+
+Plain integers are common enough that it's worth recognizing them up-front and encoding them
+directly, rather than running them through the full parser. Anything else, including integers
+with exponents like `123e4`, returns `None` and goes through the parser.
+*/
+fn integer_digits(f: &str) -> Option<(bool, &[u8])> {
+    let (is_negative, digits) = match f.as_bytes() {
+        [b'-', digits @ ..] => (true, digits),
+        [b'+', digits @ ..] => (false, digits),
+        digits => (false, digits),
+    };
+
+    if !digits.is_empty() && digits.iter().all(u8::is_ascii_digit) {
+        Some((is_negative, digits))
+    } else {
+        None
+    }
+}
+
+/**
+Parse and encode a decimal from its text representation, rejecting it if it has too many digits.
+
+The text is parsed in-place, so the limits are checked before any buffer is allocated to encode it.
+The significand limit also applies to the payload of NaNs. Leading zeroes count towards the limits.
+*/
+#[cfg(feature = "arbitrary-precision")]
+pub(crate) fn decimal_from_str_limited<D: BinaryBuf>(
+    f: &str,
+    max_digits: usize,
+    max_exponent_digits: usize,
+) -> Result<D, Error> {
+    let parsed = DecimalParser::parse_str(f)?;
+
+    let (digits, exponent_digits) = match parsed {
+        ParsedDecimal::Finite(ref finite) => (
+            significand_digits(&finite.finite_significand),
+            finite
+                .finite_exponent
+                .as_ref()
+                .map(|exponent| exponent.exponent_range.len())
+                .unwrap_or(0),
+        ),
+        ParsedDecimal::Nan(ref nan) => (
+            nan.nan_payload
+                .as_ref()
+                .map(significand_digits)
+                .unwrap_or(0),
+            0,
+        ),
+        ParsedDecimal::Infinity(_) => (0, 0),
+    };
+
+    if digits > max_digits {
+        return Err(ParseError::limit_exceeded("significand", max_digits).into());
+    }
+
+    if exponent_digits > max_exponent_digits {
+        return Err(ParseError::limit_exceeded("exponent", max_exponent_digits).into());
+    }
+
+    Ok(decimal_from_parsed(parsed)?)
+}
+
+/**
+The number of digits in a parsed significand, not including its decimal point.
+*/
+#[cfg(feature = "arbitrary-precision")]
+fn significand_digits(significand: &crate::text::ParsedSignificand) -> usize {
+    significand.significand_range.len()
+        - significand
+            .decimal_point
+            .as_ref()
+            .map(|decimal_point| decimal_point.decimal_point_range.len())
+            .unwrap_or(0)
+}
+
+/**
+Parse and encode a decimal from its text representation, reporting whether its exponent was clamped.
+*/
+pub(crate) fn decimal_from_str_with_status<D: BinaryBuf>(
+    f: &str,
+) -> Result<(D, ParseStatus), Error> {
+    if let Some((is_negative, digits)) = integer_digits(f) {
+        return Ok(decimal_from_integer_digits_with_status(
+            is_negative,
+            digits,
+            D::default_exponent(),
+        )?);
+    }
+
+    Ok(decimal_from_parsed_with_status(DecimalParser::parse_str(
+        f,
+    )?)?)
+}
+
+/**
+Parse and encode a decimal from its text representation with a set of options.
+
+If the options are the defaults then the text is parsed in-place just like `decimal_from_str`.
+Otherwise it's parsed into the given buffer.
+*/
+pub(crate) fn decimal_from_str_with_options<B: TextWriter + TextBuf, D: BinaryBuf>(
+    f: &str,
+    options: &ParseOptions,
+    buf: B,
+) -> Result<D, Error> {
+    if !options.digit_separators {
+        return decimal_from_str(f);
+    }
+
+    let mut parser = DecimalParser::begin(buf);
+    parser.allow_digit_separators();
+
+    parser.parse_ascii(f.as_bytes())?;
+
+    Ok(decimal_from_parsed(parser.end()?)?)
+}
+
+/**
+Parse and encode a decimal from its text representation with an optional width suffix.
+
+If the text ends with a suffix like `d64` then the decimal is encoded with exactly that width.
+Otherwise it's encoded in the smallest width that fits. A suffix with any other width, like `d16`,
+or without a width at all is an error.
+*/
+pub(crate) fn decimal_from_tagged_str<D: BinaryBuf>(f: &str) -> Result<D, Error> {
+    fn exact<F: BinaryBuf, D: BinaryBuf>(f: &str) -> Result<D, Error> {
+        // Parse into the same buffer as the fixed-width decimal first so it
+        // fails in the same way as any other decimal of that width would
+        let fixed: F = decimal_from_str(f)?;
+
+        let mut buf = D::try_with_exactly_storage_width_bytes(fixed.bytes().len())?;
+        buf.bytes_mut().copy_from_slice(fixed.bytes());
+
+        Ok(buf)
+    }
+
+    match f.rsplit_once(['d', 'D']) {
+        Some((f, "32")) => exact::<FixedBinaryBuf<4, i32>, D>(f),
+        Some((f, "64")) => exact::<FixedBinaryBuf<8, i32>, D>(f),
+        Some((f, "128")) => exact::<FixedBinaryBuf<16, i32>, D>(f),
+        Some((f, "256")) => exact::<FixedBinaryBuf<32, i64>, D>(f),
+        Some(_) => Err(ParseError::unknown_width().into()),
+        None => decimal_from_str(f),
+    }
+}
+
 /**
 Parse and encode a decimal from a formattable value.
 */
@@ -29,9 +198,205 @@ pub(crate) fn decimal_from_fmt<B: TextWriter + TextBuf, D: BinaryBuf>(
     f: impl fmt::Display,
     buf: B,
 ) -> Result<D, Error> {
+    // Fixed-size buffers can hold more digits than the decimal can fit, so any
+    // trailing fractional zeroes that don't fit are dropped
+    let is_fixed_size = buf.remaining_capacity().is_some();
+
     let mut parser = DecimalParser::begin(buf);
 
     parser.parse_fmt(f)?;
 
-    Ok(decimal_from_parsed(parser.end()?)?)
+    if is_fixed_size {
+        Ok(decimal_from_streamed(parser.end()?)?)
+    } else {
+        Ok(decimal_from_parsed(parser.end()?)?)
+    }
+}
+
+/**
+Encode a decimal from a stream of ASCII digits, most significant first, and an exponent.
+
+The digits are streamed into the text buffer, so if there are more digits than it can
+fit then this will fail as soon as it fills up, without consuming the rest of them.
+*/
+pub(crate) fn decimal_from_digits<B: TextWriter + TextBuf, D: BinaryBuf>(
+    is_negative: bool,
+    digits: impl Iterator<Item = u8>,
+    exponent: i32,
+    buf: B,
+) -> Result<D, Error> {
+    let mut parser = FiniteParser::begin(buf);
+
+    if is_negative {
+        parser.parse_ascii(b"-")?;
+    }
+
+    // Leading zeroes don't change the value of the decimal, so they're skipped
+    // instead of taking up space in the buffer
+    let mut has_leading_zero = false;
+    let mut has_digits = false;
+
+    for digit in digits {
+        match digit {
+            b'0' if !has_digits => has_leading_zero = true,
+            b'0'..=b'9' => {
+                has_digits = true;
+                parser.parse_ascii(&[digit])?;
+            }
+            c => Err(ParseError::unexpected_char(c, "any digit"))?,
+        }
+    }
+
+    // If all the digits were zero then write one of them
+    if !has_digits && has_leading_zero {
+        parser.parse_ascii(b"0")?;
+    }
+
+    if exponent != 0 {
+        parser.parse_ascii(b"e")?;
+        parser.parse_ascii(itoa::Buffer::new().format(exponent).as_bytes())?;
+    }
+
+    Ok(decimal_from_parsed(ParsedDecimal::Finite(parser.end()?))?)
+}
+
+/**
+Options for parsing a decimal from text.
+
+The default options accept the same text as a decimal's `FromStr` implementation.
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    digit_separators: bool,
+}
+
+impl ParseOptions {
+    /**
+    Get the default parsing options.
+    */
+    pub const fn new() -> Self {
+        ParseOptions {
+            digit_separators: false,
+        }
+    }
+
+    /**
+    Whether to accept `_` as a separator between digits, like `1_000_000.000_1`.
+
+    Separators can appear in the significand and exponent of finite numbers, but only between
+    two digits. Text with a leading or trailing separator, like `_1` or `1_`, consecutive
+    separators, like `1__0`, or a separator next to the decimal point, like `1_.0`, is rejected.
+    */
+    pub const fn digit_separators(mut self, digit_separators: bool) -> Self {
+        self.digit_separators = digit_separators;
+        self
+    }
+}
+
+/**
+Details about how a decimal was encoded from its text representation.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseStatus {
+    clamped: bool,
+}
+
+impl ParseStatus {
+    /**
+    The status of a decimal that was encoded exactly as it was written.
+    */
+    pub const fn exact() -> Self {
+        ParseStatus { clamped: false }
+    }
+
+    pub(crate) const fn clamped() -> Self {
+        ParseStatus { clamped: true }
+    }
+
+    /**
+    Whether the decimal was encoded exactly as it was written.
+    */
+    pub const fn is_exact(&self) -> bool {
+        !self.clamped
+    }
+
+    /**
+    Whether the exponent of the decimal was clamped to fit.
+
+    A decimal like `1e91` has an exponent that's too large to encode in a decimal32.
+    It can still be encoded exactly though by padding its significand with zeroes and lowering
+    its exponent to match, as in `10e90`. The value is the same, but it belongs to a different cohort.
+    */
+    pub const fn is_clamped(&self) -> bool {
+        self.clamped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::binary::DynamicBinaryBuf;
+
+    #[test]
+    fn integer_digits_fast_path() {
+        for (f, expected) in [
+            ("0", Some((false, "0"))),
+            ("123", Some((false, "123"))),
+            ("-123", Some((true, "123"))),
+            ("+0012", Some((false, "0012"))),
+            ("", None),
+            ("-", None),
+            ("+", None),
+            ("--1", None),
+            ("1.0", None),
+            ("1e3", None),
+            ("12a", None),
+            ("inf", None),
+            ("nan", None),
+        ] {
+            assert_eq!(
+                expected.map(|(is_negative, digits)| (is_negative, digits.as_bytes())),
+                integer_digits(f),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn integer_fast_path_matches_parser() {
+        for f in [
+            "0",
+            "-0",
+            "+0",
+            "000",
+            "1",
+            "-1",
+            "1234567",
+            "12345678",
+            "-9999999999999999",
+            "00000000000000000000000000000000000000001",
+            "9999999999999999999999999999999999",
+        ] {
+            let fast: (DynamicBinaryBuf<16>, ParseStatus) =
+                decimal_from_str_with_status(f).expect("failed to parse decimal");
+            let parsed: (DynamicBinaryBuf<16>, ParseStatus) = decimal_from_parsed_with_status(
+                DecimalParser::parse_str(f).expect("failed to parse decimal"),
+            )
+            .expect("failed to parse decimal");
+
+            assert_eq!(parsed.0.bytes(), fast.0.bytes(), "{}", f);
+            assert_eq!(parsed.1, fast.1, "{}", f);
+        }
+    }
+
+    #[test]
+    fn err_integer_fast_path_overflow() {
+        assert!(
+            decimal_from_str::<DynamicBinaryBuf<16>>("12345678901234567890123456789012345")
+                .unwrap_err()
+                .is_overflow()
+        );
+    }
 }
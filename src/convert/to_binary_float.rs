@@ -0,0 +1,587 @@
+/*!
+Decoding decimals into binary floating point under an explicit rounding mode.
+
+Rather than formatting the decimal as text and parsing it with the standard library, these
+conversions scale the decimal exactly using big integers. That means the result only depends
+on the value of the decimal and the rounding mode.
+*/
+
+use core::{
+    any::type_name,
+    cmp::Ordering,
+    iter,
+};
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_declets,
+        is_finite,
+        is_sign_negative,
+        BinaryBuf,
+    },
+    convert::{
+        decimal_to_binary_float,
+        RoundingMode,
+    },
+    num::{
+        Float,
+        Integer,
+    },
+    ConvertError,
+};
+
+// The number of significant digits that are needed to round a decimal correctly.
+//
+// A number that's exactly halfway between two `f64`s has at most 767 significant digits,
+// so any digits after these can only decide whether a number is just above a tie. They're
+// folded into a single sticky bit instead of being scaled.
+const MAX_SIGNIFICANT_DIGITS: usize = 780;
+
+// The number of 32-bit limbs in the big integers used to scale decimals.
+//
+// The largest integer needed is the denominator of the smallest `f64` with `MAX_SIGNIFICANT_DIGITS`,
+// shifted by the width of the significand. That's a little over 3700 bits.
+const BIG_LIMBS: usize = 128;
+
+/**
+Convert a decimal in its binary form into a binary floating point, rounding with the given mode.
+
+If the rounded number is infinite then an error is returned, just like when the decimal is too large
+to convert with the standard library. Rounding modes that round towards zero return the largest
+finite number instead.
+*/
+pub(crate) fn decimal_to_binary_float_round<F: Float, D: BinaryBuf>(
+    decimal: &D,
+    mode: RoundingMode,
+) -> Result<F, ConvertError> {
+    // Infinities and NaNs don't need rounding
+    if !is_finite(decimal) {
+        return decimal_to_binary_float(decimal);
+    }
+
+    let is_negative = is_sign_negative(decimal);
+
+    let precision = F::MANTISSA_DIGITS as i64;
+    let min_exponent = F::MIN_EXP as i64 - precision;
+    let max_exponent = F::MAX_EXP as i64 - precision;
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    // Read the significant digits of the decimal into an integer
+    let mut significand = Big::zero();
+    let mut significant_digits = 0usize;
+    let mut dropped_digits = 0usize;
+    let mut is_sticky = false;
+
+    for digit in iter::once(msd.get_ascii())
+        .chain(decode_significand_trailing_declets(decimal).flatten())
+        .skip_while(|digit| *digit == b'0')
+    {
+        if significant_digits < MAX_SIGNIFICANT_DIGITS {
+            significand.mul_add_small(10, (digit - b'0') as u32);
+            significant_digits += 1;
+        } else {
+            dropped_digits += 1;
+            is_sticky |= digit != b'0';
+        }
+    }
+
+    // Zeroes are always exact, and keep their sign
+    if significant_digits == 0 {
+        return Ok(F::from_binary_parts(is_negative, 0, min_exponent as i32));
+    }
+
+    // Decimals that are far outside the range of the float are rounded without scaling them.
+    //
+    // Each bit of the exponent is a little more than 0.301 decimal digits. These bounds are
+    // loose enough that anything within them is handled by scaling.
+    let exponent = match exponent.to_i32() {
+        Some(exponent) => exponent as i64 + dropped_digits as i64,
+        None if exponent.is_negative() => i64::MIN / 2,
+        None => i64::MAX / 2,
+    };
+
+    let adjusted_exponent = exponent + significant_digits as i64 - 1;
+
+    if adjusted_exponent > F::MAX_EXP as i64 * 302 / 1000 + 1 {
+        return overflow(is_negative, mode);
+    }
+
+    if adjusted_exponent < min_exponent * 302 / 1000 - 2 {
+        let round_up = round_up(is_negative, mode, 0, Ordering::Less, true);
+
+        return Ok(F::from_binary_parts(
+            is_negative,
+            round_up as u64,
+            min_exponent as i32,
+        ));
+    }
+
+    // The decimal is `numerator / denominator`
+    let (numerator, denominator) = if exponent >= 0 {
+        let mut numerator = significand;
+        numerator.mul_pow10(exponent as usize);
+
+        (numerator, Big::one())
+    } else {
+        let mut denominator = Big::one();
+        denominator.mul_pow10(exponent.unsigned_abs() as usize);
+
+        (significand, denominator)
+    };
+
+    // Find the binary exponent that scales the decimal so its integer part has as many bits
+    // as the float's significand, or the smallest exponent if the float is subnormal
+    let mut binary_exponent =
+        (numerator.bit_len() as i64 - denominator.bit_len() as i64 - precision).max(min_exponent);
+
+    let (mut remainder, divisor) = loop {
+        let (numerator, denominator) = if binary_exponent < 0 {
+            (
+                numerator.shl(binary_exponent.unsigned_abs() as usize),
+                denominator.clone(),
+            )
+        } else {
+            (numerator.clone(), denominator.shl(binary_exponent as usize))
+        };
+
+        // The estimate is never too large, but may be off by one bit
+        if numerator.cmp(&denominator.shl(precision as usize)) != Ordering::Less {
+            binary_exponent += 1;
+            continue;
+        }
+
+        break (numerator, denominator);
+    };
+
+    // Divide the scaled decimal one bit at a time, like long division
+    let mut quotient = 0u64;
+
+    for bit in (0..precision as usize).rev() {
+        let shifted = divisor.shl(bit);
+
+        if remainder.cmp(&shifted) != Ordering::Less {
+            remainder.sub_assign(&shifted);
+            quotient |= 1 << bit;
+        }
+    }
+
+    // Compare what's left over to half of the divisor to decide which way to round.
+    // If any digits were dropped then the decimal is a little more than what was divided
+    let is_inexact = !remainder.is_zero() || is_sticky;
+
+    let half = match remainder.shl(1).cmp(&divisor) {
+        Ordering::Equal if is_sticky => Ordering::Greater,
+        half => half,
+    };
+
+    if round_up(is_negative, mode, quotient, half, is_inexact) {
+        quotient += 1;
+
+        // If rounding carries into a new bit then the exponent needs to be raised
+        if quotient == 1 << precision {
+            quotient >>= 1;
+            binary_exponent += 1;
+        }
+    }
+
+    if binary_exponent > max_exponent {
+        return overflow(is_negative, mode);
+    }
+
+    Ok(F::from_binary_parts(
+        is_negative,
+        quotient,
+        binary_exponent as i32,
+    ))
+}
+
+/**
+Whether to round a truncated significand up to the next representable number.
+
+The `half` ordering compares what was truncated to half of the unit in the last place.
+*/
+fn round_up(
+    is_negative: bool,
+    mode: RoundingMode,
+    significand: u64,
+    half: Ordering,
+    is_inexact: bool,
+) -> bool {
+    match mode {
+        RoundingMode::NearestTiesToEven => {
+            half == Ordering::Greater || (half == Ordering::Equal && significand & 1 == 1)
+        }
+        RoundingMode::NearestTiesToAway => half != Ordering::Less,
+        RoundingMode::TowardZero => false,
+        RoundingMode::TowardPositive => is_inexact && !is_negative,
+        RoundingMode::TowardNegative => is_inexact && is_negative,
+    }
+}
+
+/**
+Round a decimal that's too large to be represented by the float.
+*/
+fn overflow<F: Float>(is_negative: bool, mode: RoundingMode) -> Result<F, ConvertError> {
+    // Rounding towards zero gives the largest finite number instead of infinity
+    let is_finite = match mode {
+        RoundingMode::NearestTiesToEven | RoundingMode::NearestTiesToAway => false,
+        RoundingMode::TowardZero => true,
+        RoundingMode::TowardPositive => is_negative,
+        RoundingMode::TowardNegative => !is_negative,
+    };
+
+    if is_finite {
+        Ok(F::from_binary_parts(
+            is_negative,
+            (1 << F::MANTISSA_DIGITS) - 1,
+            F::MAX_EXP - F::MANTISSA_DIGITS as i32,
+        ))
+    } else {
+        Err(ConvertError::would_overflow(type_name::<F>()))
+    }
+}
+
+/**
+A fixed-size unsigned big integer, stored as little-endian 32-bit limbs.
+
+This only supports the operations needed to scale a decimal, and assumes the results of those
+operations always fit.
+*/
+#[derive(Clone)]
+struct Big([u32; BIG_LIMBS]);
+
+impl Big {
+    fn zero() -> Self {
+        Big([0; BIG_LIMBS])
+    }
+
+    fn one() -> Self {
+        let mut big = Big::zero();
+        big.0[0] = 1;
+
+        big
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|limb| *limb == 0)
+    }
+
+    fn bit_len(&self) -> usize {
+        match self.0.iter().rposition(|limb| *limb != 0) {
+            Some(i) => i * 32 + (32 - self.0[i].leading_zeros() as usize),
+            None => 0,
+        }
+    }
+
+    fn mul_add_small(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+
+        for limb in self.0.iter_mut() {
+            let product = *limb as u64 * mul as u64 + carry;
+
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+
+        debug_assert_eq!(0, carry, "big integer overflow");
+    }
+
+    fn mul_pow10(&mut self, mut n: usize) {
+        while n >= 9 {
+            self.mul_add_small(1_000_000_000, 0);
+            n -= 9;
+        }
+
+        self.mul_add_small(10u32.pow(n as u32), 0);
+    }
+
+    fn shl(&self, bits: usize) -> Self {
+        let limbs = bits / 32;
+        let bits = (bits % 32) as u32;
+
+        debug_assert!(
+            self.bit_len() + limbs * 32 + bits as usize <= BIG_LIMBS * 32,
+            "big integer overflow"
+        );
+
+        let mut shifted = Big::zero();
+
+        for i in limbs..BIG_LIMBS {
+            let lo = self.0[i - limbs];
+
+            shifted.0[i] = if bits == 0 {
+                lo
+            } else if i > limbs {
+                (lo << bits) | (self.0[i - limbs - 1] >> (32 - bits))
+            } else {
+                lo << bits
+            };
+        }
+
+        shifted
+    }
+
+    fn sub_assign(&mut self, other: &Big) {
+        let mut borrow = false;
+
+        for (limb, other) in self.0.iter_mut().zip(other.0.iter()) {
+            let (diff, overflow_a) = limb.overflowing_sub(*other);
+            let (diff, overflow_b) = diff.overflowing_sub(borrow as u32);
+
+            *limb = diff;
+            borrow = overflow_a || overflow_b;
+        }
+
+        debug_assert!(!borrow, "big integer underflow");
+    }
+
+    fn cmp(&self, other: &Big) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::DynamicBinaryBuf,
+        convert::decimal_from_str,
+    };
+
+    fn round<F: Float>(s: &str, mode: RoundingMode) -> Option<F> {
+        let d: DynamicBinaryBuf<20> = decimal_from_str(s).expect("failed to parse decimal");
+
+        decimal_to_binary_float_round(&d, mode).ok()
+    }
+
+    fn next_up(f: f64) -> f64 {
+        f64::from_bits(f.to_bits() + 1)
+    }
+
+    fn next_down(f: f64) -> f64 {
+        f64::from_bits(f.to_bits() - 1)
+    }
+
+    #[test]
+    fn round_nearest_matches_std() {
+        for s in [
+            "0",
+            "1",
+            "0.1",
+            "0.3",
+            "-2.5",
+            "123.456",
+            "1e22",
+            "1e23",
+            "9007199254740993",
+            "9007199254740995",
+            "2.2250738585072011e-308",
+            "2.2250738585072014e-308",
+            "4.9406564584124654e-324",
+            "2.4703282292062327e-324",
+            "2.4703282292062328e-324",
+            "1.7976931348623157e308",
+            "1.7976931348623158e308",
+            "3.4028235e38",
+            "1.17549435e-38",
+            "1.4e-45",
+            "0.7e-45",
+            "7.0064923216240854e-46",
+            "16777217",
+            "33554435",
+            "1.000000000000000000000000000000001",
+            "9999999999999999999999999999999999e-50",
+            "1234567890123456789012345678901234e280",
+        ] {
+            assert_eq!(
+                s.parse::<f64>().ok().filter(|f| f.is_finite()),
+                round::<f64>(s, RoundingMode::NearestTiesToEven),
+                "{}",
+                s
+            );
+
+            assert_eq!(
+                s.parse::<f32>().ok().filter(|f| f.is_finite()),
+                round::<f32>(s, RoundingMode::NearestTiesToEven),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn round_directed() {
+        let tenth = 0.1f64;
+
+        // The nearest `f64` to `0.1` is slightly larger than it
+        for (s, mode, expected) in [
+            ("0.1", RoundingMode::NearestTiesToEven, tenth),
+            ("0.1", RoundingMode::NearestTiesToAway, tenth),
+            ("0.1", RoundingMode::TowardZero, next_down(tenth)),
+            ("0.1", RoundingMode::TowardPositive, tenth),
+            ("0.1", RoundingMode::TowardNegative, next_down(tenth)),
+            ("-0.1", RoundingMode::TowardZero, -next_down(tenth)),
+            ("-0.1", RoundingMode::TowardPositive, -next_down(tenth)),
+            ("-0.1", RoundingMode::TowardNegative, -tenth),
+            ("0.5", RoundingMode::TowardZero, 0.5),
+            ("0.5", RoundingMode::TowardPositive, 0.5),
+            (
+                "1.000000000000000000000000000000001",
+                RoundingMode::NearestTiesToEven,
+                1.0,
+            ),
+            (
+                "1.000000000000000000000000000000001",
+                RoundingMode::TowardPositive,
+                next_up(1.0),
+            ),
+            (
+                "1.000000000000000000000000000000001",
+                RoundingMode::TowardNegative,
+                1.0,
+            ),
+            (
+                "-1.000000000000000000000000000000001",
+                RoundingMode::TowardNegative,
+                -next_up(1.0),
+            ),
+        ] {
+            assert_eq!(Some(expected), round::<f64>(s, mode), "{} {:?}", s, mode);
+        }
+    }
+
+    #[test]
+    fn round_ties() {
+        for (s, mode, expected) in [
+            (
+                "9007199254740993",
+                RoundingMode::NearestTiesToEven,
+                9007199254740992.0,
+            ),
+            (
+                "9007199254740993",
+                RoundingMode::NearestTiesToAway,
+                9007199254740994.0,
+            ),
+            (
+                "9007199254740995",
+                RoundingMode::NearestTiesToEven,
+                9007199254740996.0,
+            ),
+            (
+                "9007199254740995",
+                RoundingMode::NearestTiesToAway,
+                9007199254740996.0,
+            ),
+            (
+                "-9007199254740993",
+                RoundingMode::NearestTiesToAway,
+                -9007199254740994.0,
+            ),
+            (
+                "9007199254740993",
+                RoundingMode::TowardZero,
+                9007199254740992.0,
+            ),
+            (
+                "9007199254740993",
+                RoundingMode::TowardPositive,
+                9007199254740994.0,
+            ),
+            // Just above a tie always rounds away
+            (
+                "9007199254740993.000000000000000000001",
+                RoundingMode::NearestTiesToEven,
+                9007199254740994.0,
+            ),
+        ] {
+            assert_eq!(Some(expected), round::<f64>(s, mode), "{} {:?}", s, mode);
+        }
+
+        assert_eq!(
+            Some(16777216.0f32),
+            round::<f32>("16777217", RoundingMode::NearestTiesToEven)
+        );
+        assert_eq!(
+            Some(16777218.0f32),
+            round::<f32>("16777217", RoundingMode::NearestTiesToAway)
+        );
+    }
+
+    #[test]
+    fn round_subnormal() {
+        let min_positive = f64::from_bits(1);
+
+        for (s, mode, expected) in [
+            ("5e-324", RoundingMode::NearestTiesToEven, min_positive),
+            ("2e-324", RoundingMode::NearestTiesToEven, 0.0),
+            ("2e-324", RoundingMode::TowardPositive, min_positive),
+            ("2e-324", RoundingMode::TowardNegative, 0.0),
+            ("1e-400", RoundingMode::TowardPositive, min_positive),
+            ("-1e-400", RoundingMode::TowardNegative, -min_positive),
+            ("1e-6000", RoundingMode::TowardPositive, min_positive),
+            (
+                "2.2250738585072011e-308",
+                RoundingMode::TowardPositive,
+                f64::MIN_POSITIVE,
+            ),
+        ] {
+            assert_eq!(Some(expected), round::<f64>(s, mode), "{} {:?}", s, mode);
+        }
+
+        // Values that round to zero keep their sign
+        let zero = round::<f64>("-1e-400", RoundingMode::NearestTiesToEven).unwrap();
+        assert!(zero == 0.0 && zero.is_sign_negative());
+
+        let zero = round::<f64>("-0.000", RoundingMode::TowardPositive).unwrap();
+        assert!(zero == 0.0 && zero.is_sign_negative());
+    }
+
+    #[test]
+    fn round_overflow() {
+        for (s, mode, expected) in [
+            ("1e400", RoundingMode::NearestTiesToEven, None),
+            ("1e400", RoundingMode::TowardPositive, None),
+            ("1e400", RoundingMode::TowardZero, Some(f64::MAX)),
+            ("1e400", RoundingMode::TowardNegative, Some(f64::MAX)),
+            ("-1e400", RoundingMode::TowardPositive, Some(f64::MIN)),
+            ("-1e400", RoundingMode::TowardNegative, None),
+            ("1e6000", RoundingMode::TowardZero, Some(f64::MAX)),
+            ("1.7976931348623158e308", RoundingMode::TowardPositive, None),
+            (
+                "1.7976931348623158e308",
+                RoundingMode::TowardZero,
+                Some(f64::MAX),
+            ),
+        ] {
+            assert_eq!(expected, round::<f64>(s, mode), "{} {:?}", s, mode);
+        }
+
+        assert_eq!(
+            None,
+            round::<f32>("3.5e38", RoundingMode::NearestTiesToEven)
+        );
+        assert_eq!(
+            Some(f32::MAX),
+            round::<f32>("3.5e38", RoundingMode::TowardZero)
+        );
+    }
+
+    #[test]
+    fn round_special() {
+        assert_eq!(
+            Some(f64::INFINITY),
+            round::<f64>("inf", RoundingMode::TowardZero)
+        );
+        assert_eq!(
+            Some(f64::NEG_INFINITY),
+            round::<f64>("-inf", RoundingMode::NearestTiesToEven)
+        );
+        assert!(round::<f64>("nan", RoundingMode::TowardZero)
+            .unwrap()
+            .is_nan());
+    }
+}
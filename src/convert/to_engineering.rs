@@ -0,0 +1,140 @@
+/*!
+Format decimals in engineering notation.
+
+Engineering notation is like scientific notation, except the exponent is always a multiple of three,
+so there are between one and three digits before the decimal point, as in `12.3e3` or `123e-6`.
+*/
+
+use core::fmt;
+
+use crate::{
+    binary::{
+        is_finite,
+        BinaryBuf,
+    },
+    convert::{
+        decimal_to_fmt,
+        decimal_to_fmt_with_options,
+        FormatOptions,
+    },
+    ops::{
+        decimal_coefficient_and_exponent,
+        decimal_is_zero,
+    },
+};
+
+/**
+Convert a decimal in its binary form into text using engineering notation.
+
+The exponent is always written, even when it's zero, as in `123.456e0`.
+If the coefficient doesn't have enough digits to fill the integer part then it's padded with
+zeroes, so `1e5` is written as `100e3`. A zero is written with enough fractional zeroes to keep
+its exponent, so `0e2` is written as `0.0e3`.
+
+Infinities and NaNs are written the same as they are by `decimal_to_fmt`. Arbitrary precision decimals
+with exponents that don't fit in an `i32` are written in scientific notation.
+*/
+pub(crate) fn decimal_to_engineering_fmt<D: BinaryBuf>(
+    decimal: &D,
+    mut out: impl fmt::Write,
+) -> Result<(), fmt::Error> {
+    if !is_finite(decimal) {
+        return decimal_to_fmt(decimal, out);
+    }
+
+    let Some((is_negative, digits, exponent)) = decimal_coefficient_and_exponent(decimal) else {
+        return decimal_to_fmt_with_options(
+            decimal,
+            &FormatOptions::new().scientific_notation(true),
+            out,
+        );
+    };
+
+    if is_negative {
+        out.write_char('-')?;
+    }
+
+    let exponent = exponent as i64;
+
+    // ±0.00e3
+    if decimal_is_zero(decimal) {
+        // Round the exponent up to a multiple of three, then make up the difference with fractional zeroes
+        let engineering_exponent = exponent + (3 - exponent.rem_euclid(3)) % 3;
+        let fractional_digits = (engineering_exponent - exponent) as usize;
+
+        out.write_char('0')?;
+
+        if fractional_digits > 0 {
+            out.write_char('.')?;
+
+            for _ in 0..fractional_digits {
+                out.write_char('0')?;
+            }
+        }
+
+        out.write_char('e')?;
+        return write!(out, "{}", engineering_exponent);
+    }
+
+    // ±12.3e3
+    let total_digits = digits.len();
+
+    // The adjusted exponent is the exponent of the number written with one digit before the
+    // decimal point. It's rounded down to a multiple of three for the engineering exponent
+    let adjusted_exponent = exponent + total_digits as i64 - 1;
+    let engineering_exponent = adjusted_exponent - adjusted_exponent.rem_euclid(3);
+
+    let integer_digits = (adjusted_exponent - engineering_exponent) as usize + 1;
+
+    let mut digits = digits;
+
+    for _ in 0..integer_digits {
+        out.write_char(digits.next().unwrap_or(b'0') as char)?;
+    }
+
+    if total_digits > integer_digits {
+        out.write_char('.')?;
+
+        for digit in digits {
+            out.write_char(digit as char)?;
+        }
+    }
+
+    out.write_char('e')?;
+    write!(out, "{}", engineering_exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::FixedBinaryBuf,
+        convert::decimal_from_str,
+    };
+
+    fn engineering(s: &str) -> String {
+        let d: FixedBinaryBuf<8, i32> = decimal_from_str(s).expect("failed to parse decimal");
+
+        let mut out = String::new();
+        decimal_to_engineering_fmt(&d, &mut out).expect("failed to format decimal");
+
+        out
+    }
+
+    #[test]
+    fn engineering_zero() {
+        for (f, expected) in [
+            ("0", "0e0"),
+            ("-0", "-0e0"),
+            ("0.00", "0.00e0"),
+            ("0.000", "0e-3"),
+            ("0e1", "0.00e3"),
+            ("0e2", "0.0e3"),
+            ("0e3", "0e3"),
+            ("0e-7", "0.0e-6"),
+        ] {
+            assert_eq!(expected, engineering(f), "{}", f);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+/*!
+Format decimals using the General Decimal Arithmetic `to-scientific-string` rules.
+
+These rules are defined in the [General Decimal Arithmetic specification](https://speleotrove.com/decimal/daconvs.html#reftostr),
+and are what the `.decTest` conformance vectors expect results to be written as.
+They differ from this crate's `Display` implementation in a few ways:
+
+- Exponential notation is used when the exponent is positive, or when the adjusted exponent is
+  less than `-6`, rather than whenever the exponent doesn't fit.
+- The exponent is written as `E`, always followed by its sign, as in `1.23E+5`.
+- Infinities are written as `Infinity`, and NaNs as `NaN` or `sNaN` with their payload directly
+  following, as in `NaN123`.
+*/
+
+use core::{
+    cmp,
+    fmt,
+    iter,
+};
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_declets,
+        is_infinite,
+        is_nan,
+        is_sign_negative,
+        is_signaling_nan,
+        BinaryBuf,
+        BinaryExponent,
+    },
+    num::Integer,
+};
+
+/**
+Convert a decimal in its binary form into text using the GDA `to-scientific-string` rules.
+*/
+pub(crate) fn decimal_to_gda_fmt<D: BinaryBuf>(
+    decimal: &D,
+    mut out: impl fmt::Write,
+) -> Result<(), fmt::Error> {
+    // The sign is written for every negative decimal, including `-0` and `-NaN`
+    if is_sign_negative(decimal) {
+        out.write_char('-')?;
+    }
+
+    // ±Infinity
+    if is_infinite(decimal) {
+        return out.write_str("Infinity");
+    }
+
+    // ±NaN123
+    //
+    // The payload is written without leading zeroes, and is omitted entirely if it's zero
+    if is_nan(decimal) {
+        out.write_str(if is_signaling_nan(decimal) {
+            "sNaN"
+        } else {
+            "NaN"
+        })?;
+
+        return write_digits(
+            decode_significand_trailing_declets(decimal)
+                .flatten()
+                .skip_while(|digit| *digit == b'0'),
+            &mut out,
+        );
+    }
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    // The coefficient is written without leading zeroes, but always has at least one digit
+    let significant_digits = || {
+        iter::once(msd.get_ascii())
+            .chain(decode_significand_trailing_declets(decimal).flatten())
+            .skip_while(|digit| *digit == b'0')
+    };
+
+    let len = cmp::max(significant_digits().count(), 1);
+    let coefficient = || significant_digits().chain(iter::once(b'0')).take(len);
+
+    // The adjusted exponent is the exponent of the number written with one digit before
+    // the decimal point, like `1.23E+5`
+    let adjusted_exponent = exponent.raise(len - 1);
+
+    // "If the exponent is less than or equal to zero and the adjusted exponent is greater than
+    // or equal to -6, the number will be converted to a character form without using
+    // exponential notation."
+    if exponent <= D::Exponent::zero() && adjusted_exponent >= D::Exponent::from_i32(-6) {
+        // Since the adjusted exponent is at least `-6`, the exponent is within
+        // the number of digits in the coefficient of it
+        let fractional_digits = exponent
+            .to_i32()
+            .map(|exponent| exponent.unsigned_abs() as usize)
+            .ok_or(fmt::Error)?;
+
+        // "If the exponent is 0, the coefficient is written with no decimal point"
+        if fractional_digits == 0 {
+            return write_digits(coefficient(), &mut out);
+        }
+
+        // "Otherwise... the decimal point will be inserted... with the number of digits after
+        // the decimal point being the negation of the exponent. If there are no characters
+        // remaining before the decimal point then a single 0 is prefixed."
+        if fractional_digits >= len {
+            out.write_str("0.")?;
+
+            write_digits(
+                iter::repeat_n(b'0', fractional_digits - len).chain(coefficient()),
+                &mut out,
+            )
+        } else {
+            write_digits(coefficient().take(len - fractional_digits), &mut out)?;
+            out.write_char('.')?;
+            write_digits(coefficient().skip(len - fractional_digits), &mut out)
+        }
+    }
+    // "Otherwise... exponential notation will be used. ... if there is more than one digit in
+    // the coefficient a decimal point is inserted after the first digit. An exponent in
+    // character form is then suffixed... the character 'E', followed by the sign of the
+    // adjusted exponent, followed by its absolute value."
+    else {
+        write_digits(coefficient().take(1), &mut out)?;
+
+        if len > 1 {
+            out.write_char('.')?;
+            write_digits(coefficient().skip(1), &mut out)?;
+        }
+
+        out.write_char('E')?;
+
+        if !adjusted_exponent.is_negative() {
+            out.write_char('+')?;
+        }
+
+        adjusted_exponent.to_fmt(&mut out)
+    }
+}
+
+fn write_digits(
+    digits: impl Iterator<Item = u8>,
+    mut out: impl fmt::Write,
+) -> Result<(), fmt::Error> {
+    for digit in digits {
+        out.write_char(digit as char)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::DynamicBinaryBuf,
+        convert::decimal_from_str,
+    };
+
+    fn gda(s: &str) -> String {
+        let d: DynamicBinaryBuf<20> = decimal_from_str(s).expect("failed to parse decimal");
+
+        let mut s = String::new();
+        decimal_to_gda_fmt(&d, &mut s).expect("failed to format decimal");
+
+        s
+    }
+
+    #[test]
+    fn to_gda_finite() {
+        // Examples from the `to-scientific-string` section of the specification
+        for (d, expected) in [
+            ("123", "123"),
+            ("-123", "-123"),
+            ("123e1", "1.23E+3"),
+            ("123e3", "1.23E+5"),
+            ("123e-1", "12.3"),
+            ("123e-5", "0.00123"),
+            ("123e-10", "1.23E-8"),
+            ("-123e-12", "-1.23E-10"),
+            ("0", "0"),
+            ("0e-2", "0.00"),
+            ("0e2", "0E+2"),
+            ("-0", "-0"),
+            ("5e-6", "0.000005"),
+            ("50e-7", "0.0000050"),
+            ("5e-7", "5E-7"),
+            ("0e-6", "0.000000"),
+            ("0e-7", "0E-7"),
+            ("-0e-8", "-0E-8"),
+            ("1e6144", "1E+6144"),
+            ("1234567e-10", "0.0001234567"),
+            ("00123.4500", "123.4500"),
+        ] {
+            assert_eq!(expected, gda(d), "{}", d);
+        }
+    }
+
+    #[test]
+    fn to_gda_special() {
+        for (d, expected) in [
+            ("inf", "Infinity"),
+            ("-inf", "-Infinity"),
+            ("nan", "NaN"),
+            ("-nan", "-NaN"),
+            ("snan", "sNaN"),
+            ("-snan(42)", "-sNaN42"),
+            ("nan(00123)", "NaN123"),
+        ] {
+            assert_eq!(expected, gda(d), "{}", d);
+        }
+    }
+}
@@ -6,12 +6,116 @@ An error encountered while working with decimals.
 #[derive(Debug)]
 pub struct Error {
     kind: ErrorKind,
+    index: Option<usize>,
+}
+
+impl Error {
+    /**
+    The index of the decimal that caused the error, if it came from working with a slice of them.
+
+    Methods that work with slices of decimals, like `rescale_all`, stop at the first decimal that
+    produces an error, and report its index here.
+    */
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /**
+    Attach the index of the decimal in a slice that caused this error.
+    */
+    pub(crate) fn at_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /**
+    Whether or not the error was caused by malformed text.
+
+    A parse error means the input isn't a valid decimal number at all, so retrying it
+    with a wider decimal won't help.
+    */
+    pub fn is_parse(&self) -> bool {
+        matches!(self.kind, ErrorKind::Parse(_))
+    }
+
+    /**
+    Whether or not the error was caused by text having more digits than a given limit allows.
+
+    A limit error is also a parse error. The text may be a valid decimal number, but it was
+    rejected before being encoded because it was too large to accept.
+    */
+    pub fn is_limit_exceeded(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::Parse(ParseError {
+                kind: ParseErrorKind::LimitExceeded { .. },
+                ..
+            })
+        )
+    }
+
+    /**
+    Whether or not the error was caused by a value being too large for its decimal.
+
+    An overflow error means the input is a valid decimal number, but it can't be encoded
+    without rounding in a decimal of the requested width. It may fit in a wider decimal.
+    */
+    pub fn is_overflow(&self) -> bool {
+        matches!(self.kind, ErrorKind::Overflow(_))
+    }
+
+    /**
+    Whether or not the error was caused by converting a decimal into a primitive number.
+    */
+    pub fn is_convert(&self) -> bool {
+        matches!(self.kind, ErrorKind::Convert(_))
+    }
+
+    /**
+    Whether or not the error was caused by bytes that aren't the canonical encoding of a decimal.
+
+    A non-canonical error is also a convert error. The bytes encode a valid decimal, but some of
+    their bits are different from the ones this library would encode the same value with.
+    */
+    pub fn is_non_canonical(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::Convert(ConvertError {
+                kind: ConvertErrorKind::NonCanonical,
+                ..
+            })
+        )
+    }
+
+    /**
+    Whether or not the error was caused by a decimal needing to be rounded to fit an exponent.
+
+    An inexact error is also a convert error. The decimal has non-zero digits below the requested
+    exponent, so it can't be encoded with it without rounding them away.
+    */
+    pub fn is_inexact(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::Convert(ConvertError {
+                kind: ConvertErrorKind::Inexact { .. },
+                ..
+            })
+        )
+    }
+
+    /**
+    Whether or not the error was caused by an output failing while a decimal was written to it.
+    */
+    pub fn is_fmt(&self) -> bool {
+        matches!(self.kind, ErrorKind::Fmt(_))
+    }
 }
 
 impl From<ParseError> for Error {
     fn from(err: ParseError) -> Self {
         Error {
             kind: ErrorKind::Parse(err),
+            index: None,
         }
     }
 }
@@ -20,6 +124,7 @@ impl From<OverflowError> for Error {
     fn from(err: OverflowError) -> Self {
         Error {
             kind: ErrorKind::Overflow(err),
+            index: None,
         }
     }
 }
@@ -28,6 +133,16 @@ impl From<ConvertError> for Error {
     fn from(err: ConvertError) -> Self {
         Error {
             kind: ErrorKind::Convert(err),
+            index: None,
+        }
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(err: fmt::Error) -> Self {
+        Error {
+            kind: ErrorKind::Fmt(err),
+            index: None,
         }
     }
 }
@@ -38,7 +153,14 @@ impl fmt::Display for Error {
             ErrorKind::Parse(ref err) => fmt::Display::fmt(err, f),
             ErrorKind::Overflow(ref err) => fmt::Display::fmt(err, f),
             ErrorKind::Convert(ref err) => fmt::Display::fmt(err, f),
+            ErrorKind::Fmt(_) => write!(f, "the output produced an error while formatting"),
+        }?;
+
+        if let Some(index) = self.index {
+            write!(f, " (at index `{}`)", index)?;
         }
+
+        Ok(())
     }
 }
 
@@ -48,6 +170,7 @@ enum ErrorKind {
     Parse(ParseError),
     Overflow(OverflowError),
     Convert(ConvertError),
+    Fmt(fmt::Error),
 }
 
 /**
@@ -61,9 +184,18 @@ pub struct ParseError {
 
 #[derive(Debug)]
 enum ParseErrorKind {
-    Char { got: u8 },
+    Char {
+        got: u8,
+    },
     End,
     BufferTooSmall,
+    UnknownWidth,
+    // Limits are only checked when parsing arbitrary precision decimals
+    #[cfg_attr(not(feature = "arbitrary-precision"), allow(dead_code))]
+    LimitExceeded {
+        part: &'static str,
+        max_digits: usize,
+    },
     Source,
 }
 
@@ -88,6 +220,27 @@ impl ParseError {
         }
     }
 
+    /**
+    A suffix that pins the width of the number wasn't a width that's supported.
+    */
+    pub(crate) fn unknown_width() -> Self {
+        ParseError {
+            expected: "a width of `32`, `64`, `128`, or `256`",
+            kind: ParseErrorKind::UnknownWidth,
+        }
+    }
+
+    /**
+    A part of the number had more digits than the caller allows.
+    */
+    #[cfg(feature = "arbitrary-precision")]
+    pub(crate) fn limit_exceeded(part: &'static str, max_digits: usize) -> Self {
+        ParseError {
+            expected: "",
+            kind: ParseErrorKind::LimitExceeded { part, max_digits },
+        }
+    }
+
     /**
     Encountered an unexpected character while parsing a number.
     */
@@ -121,6 +274,12 @@ impl fmt::Display for ParseError {
             ParseErrorKind::BufferTooSmall => {
                 write!(f, "the buffer is too small")?;
             }
+            ParseErrorKind::UnknownWidth => {
+                write!(f, "unknown width suffix")?;
+            }
+            ParseErrorKind::LimitExceeded { part, max_digits } => {
+                write!(f, "the {} has more than {} digits", part, max_digits)?;
+            }
             ParseErrorKind::Source => {
                 write!(f, "the source produced an error while parsing")?;
             }
@@ -210,6 +369,35 @@ impl OverflowError {
         }
     }
 
+    /**
+    Formatting a decimal as text needed more bytes than the buffer had.
+    */
+    pub(crate) fn text_buffer_too_small(
+        max_width_bytes: usize,
+        required_width_bytes: usize,
+    ) -> OverflowError {
+        OverflowError {
+            max_width_bytes,
+            required_width_bytes: Some(required_width_bytes),
+            note: "the buffer is too small to format the decimal into",
+        }
+    }
+
+    /**
+    A significand couldn't fit in a buffer of the given width.
+    */
+    #[cfg(feature = "raw-encoding")]
+    pub(crate) fn significand_out_of_range(
+        max_width_bytes: usize,
+        note: &'static str,
+    ) -> OverflowError {
+        OverflowError {
+            max_width_bytes,
+            required_width_bytes: None,
+            note,
+        }
+    }
+
     /**
     The maximum width supported by the given buffer.
     */
@@ -231,7 +419,20 @@ An error encountered converting between decimals and primitive types.
 #[derive(Debug)]
 pub struct ConvertError {
     target: &'static str,
-    reason: &'static str,
+    kind: ConvertErrorKind,
+}
+
+#[derive(Debug)]
+enum ConvertErrorKind {
+    Overflow,
+    NonInteger,
+    Inexact {
+        exponent: i32,
+    },
+    NonCanonical,
+    // Significant digits are only requested when converting from floats
+    #[cfg_attr(not(feature = "float"), allow(dead_code))]
+    NoSignificantDigits,
 }
 
 impl ConvertError {
@@ -241,7 +442,7 @@ impl ConvertError {
     pub(crate) fn would_overflow(target: &'static str) -> Self {
         ConvertError {
             target,
-            reason: "would overflow",
+            kind: ConvertErrorKind::Overflow,
         }
     }
 
@@ -251,14 +452,99 @@ impl ConvertError {
     pub(crate) fn non_integer(target: &'static str) -> Self {
         ConvertError {
             target,
-            reason: "would require rounding to an integer",
+            kind: ConvertErrorKind::NonInteger,
         }
     }
+
+    /**
+    Encoding a decimal with the given exponent would require rounding away some of its digits.
+    */
+    pub(crate) fn inexact(target: &'static str, exponent: i32) -> Self {
+        ConvertError {
+            target,
+            kind: ConvertErrorKind::Inexact { exponent },
+        }
+    }
+
+    /**
+    The bytes of a decimal aren't the canonical encoding of its value.
+    */
+    pub(crate) fn non_canonical(target: &'static str) -> Self {
+        ConvertError {
+            target,
+            kind: ConvertErrorKind::NonCanonical,
+        }
+    }
+
+    /**
+    Converting into a decimal with no significant digits was requested.
+    */
+    #[cfg(feature = "float")]
+    pub(crate) fn no_significant_digits(target: &'static str) -> Self {
+        ConvertError {
+            target,
+            kind: ConvertErrorKind::NoSignificantDigits,
+        }
+    }
+
+    /**
+    Whether the value was too large to fit in the target type.
+    */
+    pub fn is_overflow(&self) -> bool {
+        matches!(self.kind, ConvertErrorKind::Overflow)
+    }
+
+    /**
+    Whether the value had a fractional part, so it couldn't be converted into an integer without rounding.
+    */
+    pub fn is_non_integer(&self) -> bool {
+        matches!(self.kind, ConvertErrorKind::NonInteger)
+    }
+
+    /**
+    Whether the value had more digits than the requested exponent could keep, so it couldn't be converted without rounding.
+    */
+    pub fn is_inexact(&self) -> bool {
+        matches!(self.kind, ConvertErrorKind::Inexact { .. })
+    }
+
+    /**
+    Whether the bytes of a decimal weren't the canonical encoding of its value.
+    */
+    pub fn is_non_canonical(&self) -> bool {
+        matches!(self.kind, ConvertErrorKind::NonCanonical)
+    }
 }
 
 impl fmt::Display for ConvertError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "conversion to `{}` {}", self.target, self.reason)
+        let reason = match self.kind {
+            ConvertErrorKind::Overflow => "would overflow",
+            ConvertErrorKind::NonInteger => "would require rounding to an integer",
+            ConvertErrorKind::Inexact { exponent } => {
+                return write!(
+                    f,
+                    "conversion to `{}` with an exponent of `{}` would require rounding",
+                    self.target, exponent
+                )
+            }
+            ConvertErrorKind::NoSignificantDigits => {
+                return write!(
+                    f,
+                    "conversion to `{}` needs at least one significant digit",
+                    self.target
+                )
+            }
+            ConvertErrorKind::NonCanonical => {
+                return write!(
+                    f,
+                    "the bytes aren't a canonical encoding of a `{}`",
+                    self.target
+                )
+            }
+        };
+
+        write!(f, "conversion to `{}` {}", self.target, reason)
     }
 }
 
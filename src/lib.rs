@@ -40,6 +40,8 @@ where:
 - `g`: The combination field.
 - `t`: The trailing significand.
 
+The fixed-width bitstrings implement `fmt::Binary`, so you can print their bits in this layout using `{:#b}`.
+
 Note that this library _always_ encodes in little-endian byte-order, regardless of the endianness of the underlying platform.
 Also note, this encoding is different on big-endian platforms than `libdecimal`'s internal encoding, which isn't specified, but
 currently uses arrays of 32bit integers.
@@ -66,9 +68,10 @@ portability of binary bitstrings.
 
 # Features and limitations
 
-This library _only_ does conversions between Rust's primitive number types, numbers encoded as text, and decimal bitstrings.
-It's not an implementation of decimal arithmetic. It also doesn't do rounding. If a number can't be encoded in a decimal
-bitstring of a given width then you'll get `None`s instead of infinities or rounded values.
+This library mostly does conversions between Rust's primitive number types, numbers encoded as text, and decimal bitstrings.
+It's not an implementation of decimal arithmetic. Decimals can be added, subtracted, and multiplied with `checked_add`,
+`checked_sub`, and `checked_mul`, but only when the result can be encoded exactly. It doesn't do rounding. If a number can't
+be encoded in a decimal bitstring of a given width then you'll get `None`s instead of infinities or rounded values.
 
 Decimal numbers in IEEE 754 are non-normalized by-design. The number `1.00` will encode differently to `1` or `1.0`.
 
@@ -79,11 +82,14 @@ This library does support very high precision in no-std, and can work with arbit
 
 ## Binary floating point
 
-This library can convert binary floating points (`f32` and `f64`) into decimals.
+This library can convert binary floating points (`f32` and `f64`) into decimals when the
+`float` feature is enabled (it is by default).
 It uses [ryū](https://docs.rs/ryu) to pick an appropriate decimal representation and faithfully encodes that.
+If you only need to work with text and integers then you can disable default features to drop
+the dependency on ryū along with any conversions to or from binary floating points.
 The following cases are worth calling out:
 
-- `0f64` will encode as `0.0`, which is different to `0`.
+- Integral floats encode with an exponent of zero, so `0f64` will encode as `0`, the same as the integer `0`.
 - A signaling NaN is encoded as a quiet NaN.
 - NaN payloads are discarded.
 
@@ -97,6 +103,76 @@ For example, the following all encode the same number at the edge of decimal64's
 - `100e369`
 - `10.0e370`
 - `1.00e371`
+
+If an exponent is too large to encode as written then parsing fails, even if the number could still be
+encoded exactly by padding its significand with trailing zeroes. The `parse_with_status` methods clamp the
+exponent instead, and report whether that happened. For example, `1e91` fails to parse into a decimal32,
+but `parse_with_status` encodes it as `10e90`. Zeroes don't have any digits to lose, so `parse_with_status`
+clamps their exponent into range in either direction. For example, `0e100` is encoded as `0e90` in a decimal32.
+
+## Constants
+
+The 32, 64, and 128 bit fixed-width decimals can be created in `const` items with `from_coefficient_exponent_const`,
+which takes an integer coefficient and an exponent, like `Bitstring64::from_coefficient_exponent_const(525, -4)`
+for `0.0525`. If the coefficient or exponent don't fit then it's a compile error. Constants can also be
+created from their encoded bytes with `from_le_bytes`.
+
+## NaNs
+
+NaNs are parsed case-insensitively, so `NaN`, `nan`, and `NAN` are all the same quiet NaN, and `sNaN`
+is a signaling NaN. A quiet NaN may also be written with an explicit `q`, like `qNaN`.
+They're always formatted in lowercase as `nan` or `snan`, along with their sign.
+A NaN's payload is formatted between parentheses, like `nan(123)`, without any leading zeroes.
+A zero payload isn't formatted at all, so `nan(0)` is formatted as `nan`. Any NaN that can be
+parsed is formatted in a way that parses back to the same bitstring.
+
+## Serialization
+
+When the `serde` feature is enabled, decimals implement `Serialize` and `Deserialize`. In human-readable
+formats like JSON they're serialized as strings, so trailing zeroes like the one in `1.50` aren't lost.
+See the [`serde`] module for details.
+
+## Casting to bytes
+
+When the `bytemuck` feature is enabled, the fixed-width decimals implement [`Pod`](https://docs.rs/bytemuck/1/bytemuck/trait.Pod.html)
+and [`Zeroable`](https://docs.rs/bytemuck/1/bytemuck/trait.Zeroable.html), so slices of them can be cast to
+and from their little-endian bytes without copying. Every bit pattern is a valid decimal, but zero-filled memory
+isn't the same as the `ZERO` constant. It's a zero with the smallest possible exponent, so while it compares
+equal to `ZERO`, it formats as something like `0e-398` instead of `0`.
+
+## Property testing
+
+When the `proptest` feature is enabled, decimals implement `proptest`'s `Arbitrary` trait, so they can be
+generated as inputs to property tests. See the [`proptest`] module for details.
+
+## Generic numeric code
+
+When the `num-traits` feature is enabled, decimals implement [`Zero`](https://docs.rs/num-traits/0.2/num_traits/identities/trait.Zero.html),
+[`One`](https://docs.rs/num-traits/0.2/num_traits/identities/trait.One.html), and the [`CheckedAdd`](https://docs.rs/num-traits/0.2/num_traits/ops/checked/trait.CheckedAdd.html)
+and [`CheckedMul`](https://docs.rs/num-traits/0.2/num_traits/ops/checked/trait.CheckedMul.html) traits so they can be used
+in generic code. The checked traits use the exact `checked_add` and `checked_mul` methods, which return `None` instead of
+rounding. `is_zero` is true for zeroes in any cohort, like `0` and `-0.00`.
+
+Decimals also implement [`FromPrimitive`](https://docs.rs/num-traits/0.2/num_traits/cast/trait.FromPrimitive.html)
+and [`ToPrimitive`](https://docs.rs/num-traits/0.2/num_traits/cast/trait.ToPrimitive.html). These follow the same rules as
+the inherent conversion methods, so a fractional decimal won't convert into an integer, and a number that can't be encoded
+exactly won't convert into a decimal. Binary floating points are only converted when the `float` feature is also enabled.
+
+Traits like `Num` and `Signed` aren't implemented, because they require the `Div` and `Rem` operators. The quotient of two
+decimals usually can't be encoded exactly, like `1 / 3`, and this library doesn't round. The sign methods `Signed` would
+provide are available on decimals directly, like `abs`, `is_sign_negative`, and `is_sign_positive`.
+
+### Panicking operators
+
+`Zero`, `One`, `CheckedAdd`, and `CheckedMul` require the `Add` and `Mul` operators, so the `num-traits` feature also
+implements `+` and `*` for decimals. This library never rounds, so **these operators panic** if their exact result can't
+be encoded in the width of the wider decimal. For example, `1e90 + 1e-101` panics for a `Bitstring32`, because the exact
+sum needs far more than 7 digits. Cargo enables a feature for every crate in a build if any of them enables it, so these
+operators might be available even if your own crate doesn't enable `num-traits`. Prefer the `checked_add` and
+`checked_mul` methods, which return `None` instead of panicking.
+
+`CheckedSub` isn't implemented, because it requires the `Sub` operator, and nothing else needs `Sub`. Leaving it out
+means there's no `-` operator to panic. Use the `checked_sub` method to subtract decimals instead.
 */
 
 #![deny(missing_docs)]
@@ -105,6 +181,9 @@ For example, the following all encode the same number at the edge of decimal64's
 
 extern crate core;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /*
 If you're exploring the source, there are a few root modules to look at:
 
@@ -116,6 +195,7 @@ exponent. There's some bit-twiddling involved to encode the bits for a number ac
 but it's all explained along the way.
 - `convert`: Combines the `text` and `binary` modules to convert between strings and Rust primitive
 numbers and encoded bitstrings.
+- `ops`: Operations like comparison that work on the value of a decimal rather than its encoding.
 - `bitstring`: The user-facing types.
 - `num`: Some generic infrastructure for working with integers and floating points that support
 conversion and arithmetic.
@@ -129,10 +209,28 @@ mod bitstring;
 mod convert;
 mod error;
 mod num;
-mod text;
+mod ops;
+
+pub mod text;
+
+#[cfg(feature = "raw-encoding")]
+pub mod raw;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
 
 pub use self::{
+    binary::CoefficientDigits,
     bitstring::*,
+    convert::{
+        FormatOptions,
+        ParseOptions,
+        ParseStatus,
+        RoundingMode,
+    },
     error::*,
 };
 
@@ -156,6 +254,7 @@ mod tests {
         s
     }
 
+    #[cfg(feature = "float")]
     fn nan32(payload: u32) -> f32 {
         let f = f32::from_bits(f32::NAN.to_bits() | (payload & 0x7fffff));
 
@@ -164,6 +263,7 @@ mod tests {
         f
     }
 
+    #[cfg(feature = "float")]
     fn nan64(payload: u64) -> f64 {
         let f = f64::from_bits(f64::NAN.to_bits() | (payload & 0x7ffffffffffff));
 
@@ -189,6 +289,7 @@ mod tests {
             let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
 
             assert_eq!(is_negative, d.is_sign_negative(), "{}", f);
+            assert_eq!(!is_negative, d.is_sign_positive(), "{}", f);
         }
     }
 
@@ -212,6 +313,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_special() {
+        for (f, is_special) in [
+            ("0", false),
+            ("-0", false),
+            ("123", false),
+            ("1e-101", false),
+            ("inf", true),
+            ("-inf", true),
+            ("nan", true),
+            ("-nan(123)", true),
+            ("snan", true),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(is_special, d.is_special(), "{}", f);
+            assert_eq!(!d.is_finite(), d.is_special(), "{}", f);
+        }
+    }
+
     #[test]
     fn is_infinite() {
         for (f, is_infinite) in [
@@ -283,246 +404,3219 @@ mod tests {
     }
 
     #[test]
-    fn decimal_roundtrip_i128() {
-        for i in [0i128, 42i128, i128::MIN, i128::MAX] {
-            let d = Bitstring::from(i);
-            let di = d.try_into().unwrap();
+    fn decimal_neg() {
+        for (f, expected) in [
+            ("0", "-0"),
+            ("-0", "0"),
+            ("0e3", "-0e3"),
+            ("123.456", "-123.456"),
+            ("-123.456", "123.456"),
+            ("inf", "-inf"),
+            ("-inf", "inf"),
+            ("nan", "-nan"),
+            ("-nan(123)", "nan(123)"),
+            ("snan(42)", "-snan(42)"),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
 
-            assert_eq!(i, di);
+            assert_eq!(expected, (-d).to_string(), "{}", f);
+            assert_eq!(expected, (-&d).to_string(), "{}", f);
+
+            // Negating a reference is the same as negating an owned copy
+            assert_eq!((-(&d)).as_le_bytes(), (-d).as_le_bytes(), "{}", f);
+
+            // Negating twice gives back the original bits
+            assert_eq!(d.as_le_bytes(), (-(-d)).as_le_bytes(), "{}", f);
         }
+
+        let d = Bitstring64::try_parse_str("snan(42)").expect("failed to parse decimal");
+        assert!((-&d).is_signaling_nan());
+        assert!((-&d).is_sign_negative());
     }
 
     #[test]
-    fn decimal_roundtrip_u128() {
-        for i in [0u128, 42u128, u128::MAX] {
-            let d = Bitstring::from_u128(i);
-            let di = d.to_u128().unwrap();
+    fn decimal_neg_fixed() {
+        macro_rules! assert_neg {
+            ($d:ident) => {
+                for (f, expected) in [
+                    ("1.5", "-1.5"),
+                    ("-0", "0"),
+                    ("0", "-0"),
+                    ("inf", "-inf"),
+                    ("nan(123)", "-nan(123)"),
+                    ("-snan(7)", "snan(7)"),
+                ] {
+                    let d = $d::try_parse_str(f).expect("failed to parse decimal");
+                    let negated = -&d;
 
-            assert_eq!(i, di);
+                    assert_eq!(expected, negated.to_string(), "{}", f);
+
+                    // Only the sign bit is changed
+                    assert_eq!(d.is_nan(), negated.is_nan(), "{}", f);
+                    assert_eq!(d.is_signaling_nan(), negated.is_signaling_nan(), "{}", f);
+                    assert_eq!(
+                        d.as_le_bytes()[..d.as_le_bytes().len() - 1],
+                        negated.as_le_bytes()[..negated.as_le_bytes().len() - 1],
+                        "{}",
+                        f
+                    );
+                }
+            };
         }
+
+        assert_neg!(Bitstring32);
+        assert_neg!(Bitstring64);
+        assert_neg!(Bitstring128);
     }
 
     #[test]
-    fn decimal_to_int_with_exponent() {
-        for (f, i) in [("17e1", 170i32), ("4e7", 40000000i32), ("170e-1", 17i32)] {
-            let d = Bitstring::try_parse_str(f).unwrap();
+    fn decimal_abs_copysign() {
+        for (f, expected) in [
+            ("-1.50", "1.50"),
+            ("1.50", "1.50"),
+            ("-0", "0"),
+            ("-inf", "inf"),
+            ("-nan(42)", "nan(42)"),
+            ("-snan(7)", "snan(7)"),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
 
-            assert_eq!(i, d.to_i32().unwrap(), "{}", f);
+            assert_eq!(expected, d.abs().to_string(), "{}", f);
         }
-    }
 
-    #[test]
-    fn err_decimal_to_int_exponent_overflow() {
-        for f in ["4e618", "17e-1", "1e-1"] {
-            let d = Bitstring::try_parse_str(f).unwrap();
+        assert!(Bitstring64::try_parse_str("-snan(7)")
+            .expect("failed to parse decimal")
+            .abs()
+            .is_signaling_nan());
 
-            assert!(d.to_i32().is_none(), "{}", f);
+        let neg = Bitstring64::try_parse_str("-2").expect("failed to parse decimal");
+        let pos = Bitstring64::try_parse_str("3.5").expect("failed to parse decimal");
+
+        for (f, sign, expected) in [
+            ("1.5", &neg, "-1.5"),
+            ("-1.5", &pos, "1.5"),
+            ("-1.5", &neg, "-1.5"),
+            ("0", &neg, "-0"),
+            ("inf", &neg, "-inf"),
+            ("nan", &neg, "-nan"),
+            ("-snan(3)", &pos, "snan(3)"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.copysign(sign).to_string(), "{}", f);
         }
+
+        // The sign of a NaN can be copied too
+        assert_eq!(
+            "-1",
+            Bitstring64::ONE.copysign(&-Bitstring64::NAN).to_string()
+        );
     }
 
     #[test]
-    fn decimal_roundtrip_f32() {
-        for f in [
-            0.0f32,
-            17.05e2f32,
-            f32::MIN,
-            f32::MAX,
-            f32::INFINITY,
-            f32::NEG_INFINITY,
+    fn decimal_eq() {
+        for (a, b, expected) in [
+            ("1.0", "1.00", true),
+            ("1.0", "1e0", true),
+            ("100", "1e2", true),
+            ("0", "-0", true),
+            ("0", "0e5", true),
+            ("-0.000", "0e-5", true),
+            ("1.5", "1.50", true),
+            ("1.5", "1.51", false),
+            ("-1", "1", false),
+            ("inf", "inf", true),
+            ("-inf", "-inf", true),
+            ("inf", "-inf", false),
+            ("inf", "9.999999999999999e384", false),
+            ("nan", "nan", false),
+            ("snan", "snan", false),
+            ("nan", "1", false),
         ] {
-            let d = Bitstring::from_f32(f);
-            let df = d.to_f32().unwrap();
+            let a = Bitstring64::try_parse_str(a).expect("failed to parse decimal");
+            let b = Bitstring64::try_parse_str(b).expect("failed to parse decimal");
 
-            assert_eq!(f, df);
+            assert_eq!(expected, a == b, "{} == {}", a, b);
+            assert_eq!(expected, b == a, "{} == {}", b, a);
         }
+
+        // NaNs aren't equal to themselves
+        let nan = Bitstring32::NAN;
+        #[allow(clippy::eq_op)]
+        let is_eq = nan == nan;
+        assert!(!is_eq);
+
+        // Dynamic decimals of different widths are equal if their values are
+        let a = Bitstring::try_parse_str("1.5").expect("failed to parse decimal");
+        let b = Bitstring::try_parse_str("1.500000000000000").expect("failed to parse decimal");
+        assert_ne!(a.as_le_bytes().len(), b.as_le_bytes().len());
+        assert_eq!(a, b);
     }
 
     #[test]
-    fn decimal_roundtrip_f32_nan() {
-        let f = nan32(0);
+    fn decimal_partial_ord() {
+        use core::cmp::Ordering;
 
-        let d = Bitstring::from_f32(f);
+        let mut ds = [
+            "1e3", "1000", "999.9", "-inf", "0.00", "-0", "inf", "-1000.1",
+        ]
+        .map(|f| Bitstring128::try_parse_str(f).expect("failed to parse decimal"));
 
-        assert!(d.is_nan());
+        ds.sort_by(|a, b| a.partial_cmp(b).expect("decimals are ordered"));
 
-        let df = d.to_f32().unwrap();
+        // Sorting is stable, so equal values keep their original order
+        assert_eq!(
+            ["-inf", "-1000.1", "0.00", "-0", "999.9", "1e3", "1000", "inf"],
+            ds.map(|d| d.to_string())
+        );
 
-        assert_eq!(f.to_bits(), df.to_bits());
+        let one = Bitstring128::ONE;
+        let nan = Bitstring128::NAN;
+
+        assert!(one < Bitstring128::INFINITY);
+        assert!(-Bitstring128::INFINITY < -one);
+        assert!(one <= Bitstring128::try_parse_str("1.000").expect("failed to parse decimal"));
+        assert_eq!(None, one.partial_cmp(&nan));
+        assert_eq!(None, nan.partial_cmp(&nan));
+        assert!(!nan.lt(&one) && !nan.gt(&one) && !nan.le(&one));
+        assert_eq!(
+            Some(Ordering::Equal),
+            Bitstring128::ZERO.partial_cmp(&-Bitstring128::ZERO)
+        );
     }
 
     #[test]
-    fn decimal_f32_nan_ignores_payload() {
-        let d1 = Bitstring::from_f32(nan32(0));
-        let d2 = Bitstring::from_f32(nan32(42));
+    fn decimal_total_cmp() {
+        use core::cmp::Ordering;
 
-        assert!(d1.is_nan());
-        assert!(d2.is_nan());
+        let mut ds = ["1.00", "nan(2)", "0", "inf", "-nan", "1.0", "snan(1)", "-0"]
+            .map(|f| Bitstring64::try_parse_str(f).expect("failed to parse decimal"));
 
-        assert_eq!(d1.to_string(), d2.to_string());
-    }
+        ds.sort_by(Bitstring64::total_cmp);
 
-    #[test]
-    fn err_decimal_to_f32_overflow() {
-        let d = Bitstring::try_parse_str("1e106").unwrap();
+        assert_eq!(
+            ["-nan", "-0", "0", "1.00", "1.0", "inf", "snan(1)", "nan(2)"],
+            ds.map(|d| d.to_string())
+        );
 
-        assert!(d.to_f32().is_none());
+        let one = Bitstring64::ONE;
+
+        assert_eq!(Ordering::Equal, one.total_cmp(&one));
+        assert_eq!(
+            Ordering::Equal,
+            Bitstring64::NAN.total_cmp(&Bitstring64::NAN)
+        );
+        assert_eq!(
+            Ordering::Less,
+            (-Bitstring64::ZERO).total_cmp(&Bitstring64::ZERO)
+        );
+        assert_eq!(
+            Ordering::Less,
+            (-Bitstring64::NAN).total_cmp(&-Bitstring64::INFINITY)
+        );
+        assert_eq!(
+            Ordering::Greater,
+            Bitstring64::NAN.total_cmp(&Bitstring64::INFINITY)
+        );
     }
 
     #[test]
-    fn decimal_roundtrip_f64() {
-        for f in [
-            0.0f64,
-            17.05e2f64,
-            f64::MIN,
-            f64::MAX,
-            f64::INFINITY,
-            f64::NEG_INFINITY,
+    fn decimal_min_max() {
+        for (a, b, expected_min, expected_max) in [
+            ("1", "2", "1", "2"),
+            ("-1.5", "0.25", "-1.5", "0.25"),
+            ("1.5", "1.50", "1.50", "1.5"),
+            ("-1.5", "-1.50", "-1.5", "-1.50"),
+            ("1e2", "99.99", "99.99", "1e2"),
+            ("120", "1.2e2", "120", "1.2e2"),
+            ("0", "-0", "-0", "0"),
+            ("-inf", "inf", "-inf", "inf"),
+            ("nan", "1.5", "1.5", "1.5"),
+            ("-2", "snan(4)", "-2", "-2"),
+            ("nan(1)", "snan(2)", "nan(1)", "nan(1)"),
+            ("snan(1)", "nan(2)", "nan(1)", "nan(1)"),
         ] {
-            let d = Bitstring::from_f64(f);
-            let df = d.to_f64().unwrap();
+            let a_dec = Bitstring64::try_parse_str(a).expect("failed to parse decimal");
+            let b_dec = Bitstring64::try_parse_str(b).expect("failed to parse decimal");
 
-            assert_eq!(f, df);
+            assert_eq!(
+                expected_min,
+                a_dec.min_num(&b_dec).to_string(),
+                "{} min {}",
+                a,
+                b
+            );
+            assert_eq!(
+                expected_max,
+                a_dec.max_num(&b_dec).to_string(),
+                "{} max {}",
+                a,
+                b
+            );
         }
+
+        let one = Bitstring::try_parse_str("1").expect("failed to parse decimal");
+        let wide =
+            Bitstring::try_parse_str("1.0000000000000000000").expect("failed to parse decimal");
+
+        assert_eq!("1.0000000000000000000", one.min_num(&wide).to_string());
+        assert_eq!("1", one.max_num(&wide).to_string());
     }
 
     #[test]
-    fn decimal_roundtrip_f64_nan() {
-        let f = nan64(0);
+    fn decimal_clamp() {
+        let min = Bitstring64::try_parse_str("-1.0").expect("failed to parse decimal");
+        let max = Bitstring64::try_parse_str("2.500").expect("failed to parse decimal");
 
-        let d = Bitstring::from_f64(f);
+        for (f, expected) in [
+            // Inside the range, including at the bounds in a different cohort
+            ("0", "0"),
+            ("1.23", "1.23"),
+            ("-1", "-1"),
+            ("2.5", "2.5"),
+            ("25e-1", "2.5"),
+            // Below the range
+            ("-1.01", "-1.0"),
+            ("-1e10", "-1.0"),
+            ("-inf", "-1.0"),
+            // Above the range
+            ("2.5000001", "2.500"),
+            ("3e2", "2.500"),
+            ("inf", "2.500"),
+            // NaNs aren't clamped
+            ("nan(7)", "nan(7)"),
+            ("-snan", "-snan"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
 
-        assert!(d.is_nan());
+            assert_eq!(expected, d.clamp(&min, &max).to_string(), "{}", f);
+        }
 
-        let df = d.to_f64().unwrap();
+        let one = Bitstring::try_parse_str("1").expect("failed to parse decimal");
+        let min =
+            Bitstring::try_parse_str("1.0000000000000000001").expect("failed to parse decimal");
 
-        assert_eq!(f.to_bits(), df.to_bits());
+        assert_eq!(
+            "1.0000000000000000001",
+            one.clamp(
+                &min,
+                &Bitstring::try_parse_str("2").expect("failed to parse decimal")
+            )
+            .to_string()
+        );
     }
 
     #[test]
-    fn decimal_f64_nan_ignores_payload() {
-        let d1 = Bitstring::from_f64(nan64(0));
-        let d2 = Bitstring::from_f64(nan64(42));
+    fn decimal_hash() {
+        use std::{
+            collections::hash_map::DefaultHasher,
+            hash::{
+                Hash,
+                Hasher,
+            },
+        };
 
-        assert!(d1.is_nan());
-        assert!(d2.is_nan());
+        fn hash(d: &Bitstring) -> u64 {
+            let mut state = DefaultHasher::new();
+            d.hash(&mut state);
 
-        assert_eq!(d1.as_le_bytes(), d2.as_le_bytes());
+            state.finish()
+        }
+
+        for (a, b) in [
+            ("1.0", "1.00"),
+            ("1e3", "1000"),
+            ("0", "-0"),
+            ("0e5", "-0.000"),
+            ("1.5", "1.500000000000000000000000000000000"),
+        ] {
+            let a = Bitstring::try_parse_str(a).expect("failed to parse decimal");
+            let b = Bitstring::try_parse_str(b).expect("failed to parse decimal");
+
+            assert_eq!(a, b);
+            assert_eq!(hash(&a), hash(&b));
+        }
     }
 
     #[test]
-    fn err_decimal_to_f64_overflow() {
-        let d = Bitstring::try_parse_str("1e4513").unwrap();
+    fn approx_eq() {
+        let expected = Bitstring64::try_parse_str("0.3").expect("failed to parse decimal");
+        let actual = Bitstring64::try_parse_str("0.30000001").expect("failed to parse decimal");
 
-        assert!(d.to_f64().is_none());
+        let tolerance = Bitstring64::try_parse_str("1e-7").expect("failed to parse decimal");
+        assert!(actual.approx_eq(&expected, &tolerance));
+
+        let tolerance = Bitstring64::try_parse_str("1e-9").expect("failed to parse decimal");
+        assert!(!actual.approx_eq(&expected, &tolerance));
+
+        assert!(!Bitstring64::NAN.approx_eq(&Bitstring64::NAN, &Bitstring64::INFINITY));
     }
 
     #[test]
-    fn decimal_roundtrip_str() {
-        for f in [
-            "0",
-            "-0",
-            "0.0",
-            "-0.0",
-            "435",
-            "-435",
-            "547473436755",
-            "-547473436755",
-            "354.55",
-            "-354.55",
-            "3546.8764256732",
-            "-3546.8764256732",
-            "0.00012532",
-            "-0.00012532",
-            "0e1",
-            "120e2",
-            "-120e2",
-            "123e456",
-            "-123e456",
-            "123e-3",
-            "-123e-3",
-            "1.2354e-7",
-            "-1.2354e-7",
-            "1e96",
-            "1e-95",
-            "1e384",
-            "1e-383",
-            "1e6144",
-            "1e-1643",
-            "nan",
-            "nan(123)",
-            "inf",
-            "-inf",
-            "snan",
-            "snan(123)",
+    fn is_between() {
+        for (f, lo, hi, inclusive, is_between) in [
+            ("1", "0", "2", false, true),
+            ("1.0", "1", "2", true, true),
+            ("1.0", "1", "2", false, false),
+            ("2", "1", "2.00", true, true),
+            ("2", "1", "2.00", false, false),
+            ("-0", "0", "1", true, true),
+            ("-1.5", "-2", "-1", false, true),
+            ("3", "1", "2", true, false),
+            ("inf", "1", "inf", true, true),
+            ("inf", "1", "inf", false, false),
+            ("1e10", "-inf", "inf", false, true),
+            // Reversed bounds never contain anything
+            ("1", "2", "0", true, false),
+            ("1", "1", "1", false, false),
+            ("1", "1", "1", true, true),
+            // NaNs are never between anything
+            ("nan", "-inf", "inf", true, false),
+            ("1", "nan", "2", true, false),
+            ("1", "0", "snan", true, false),
         ] {
             let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+            let lo = Bitstring::try_parse_str(lo).expect("failed to parse decimal");
+            let hi = Bitstring::try_parse_str(hi).expect("failed to parse decimal");
 
-            // Ensure bitstrings roundtrip through from_le_bytes and to_le_bytes
             assert_eq!(
-                bitstr(d.as_le_bytes()),
-                bitstr(
-                    Bitstring::try_from_le_bytes(d.as_le_bytes())
-                        .expect("failed to convert bytes to decimal")
-                        .as_le_bytes()
-                ),
-                "{}",
-                f
+                is_between,
+                d.is_between(&lo, &hi, inclusive),
+                "{} between {} and {} (inclusive: {})",
+                f,
+                lo,
+                hi,
+                inclusive
             );
+        }
+    }
 
-            let s = d.to_string();
+    #[test]
+    fn search_sorted_le() {
+        let mut column = Vec::new();
+        for d in ["-inf", "-1", "0", "1.0", "1", "2.5", "nan"] {
+            column.extend_from_slice(
+                &Bitstring64::try_parse_str(d)
+                    .expect("failed to parse decimal")
+                    .to_le_bytes(),
+            );
+        }
+
+        for (needle, expected) in [
+            ("-inf", Ok(0)),
+            ("1", Ok(4)),
+            ("nan", Ok(6)),
+            ("0.5", Err(3)),
+            ("1.00", Err(3)),
+            ("3", Err(6)),
+        ] {
+            let needle = Bitstring64::try_parse_str(needle).expect("failed to parse decimal");
 
-            // Ensure bitstrings roundtrip through to_string and try_parse_str
             assert_eq!(
-                bitstr(d.as_le_bytes()),
-                bitstr(
-                    Bitstring::try_parse_str(&s)
-                        .expect("failed to parse decimal")
-                        .as_le_bytes()
-                ),
-                "{} -> {}",
-                f,
-                s
+                expected,
+                Bitstring64::search_sorted_le(&column, 8, &needle),
+                "{}",
+                needle
             );
         }
+
+        // Decimals can be searched through a wider type
+        let needle = Bitstring::try_parse_str("2.5").expect("failed to parse decimal");
+        assert_eq!(Ok(5), Bitstring::search_sorted_le(&column, 8, &needle));
     }
 
     #[test]
-    fn decimal_zero() {
-        let zero_from_str = Bitstring::try_parse_str("0").expect("failed to parse");
-        let zero_from_const = Bitstring::zero();
-        let zero_from_int = Bitstring::from(0);
-        let zero_from_float = Bitstring::from(0f64);
+    fn total_ord() {
+        use std::collections::BTreeSet;
 
-        assert_eq!("0", zero_from_str.to_string());
-        assert_eq!("0", zero_from_const.to_string());
-        assert_eq!("0", zero_from_int.to_string());
+        // These decimals are in total order
+        let expected = [
+            "-nan(1)", "-snan", "-inf", "-1e300", "-10", "-1.0", "-1.00", "-0.001", "-0", "0",
+            "0.001", "1.00", "1.0", "10", "1e300", "inf", "snan", "nan(1)",
+        ];
 
-        // NOTE: We may want to special case `0f64` so it encodes as `0`
-        assert_eq!("0.0", zero_from_float.to_string());
+        let set = expected
+            .iter()
+            .rev()
+            .map(|f| TotalOrd(Bitstring64::try_parse_str(f).expect("failed to parse decimal")))
+            .collect::<BTreeSet<_>>();
 
-        assert_eq!(zero_from_str.as_le_bytes(), zero_from_const.as_le_bytes());
-        assert_eq!(zero_from_str.as_le_bytes(), zero_from_int.as_le_bytes());
-    }
+        assert_eq!(
+            expected.to_vec(),
+            set.iter().map(|d| d.0.to_string()).collect::<Vec<_>>()
+        );
 
-    #[test]
-    fn decimal_size_small_significand_large_exponent() {
-        for i in ["1e6100", "1e-6100"] {
-            // We only have a single digit, but the exponent is too large for anything smaller than 128bit
-            let d = Bitstring::try_parse_str(i).expect("failed to parse decimal");
+        // Different encodings of the same value are different keys
+        let a = TotalOrd(Bitstring::try_parse_str("1.0").expect("failed to parse decimal"));
+        let b = TotalOrd(Bitstring::try_parse_str("1.00").expect("failed to parse decimal"));
 
-            assert!(d.as_le_bytes().len() * 8 >= 128, "{}", i);
-        }
+        assert_ne!(a, b);
+        assert!(b < a);
+        assert_eq!(
+            a,
+            TotalOrd(Bitstring::try_parse_str("1.0").expect("failed to parse decimal"))
+        );
     }
 
     #[test]
-    fn decimal_size_small_significand() {
-        let d = Bitstring::try_parse_str("1").expect("failed to parse decimal");
+    #[cfg(feature = "alloc")]
+    fn to_ord_key() {
+        // These decimals are in total order
+        let expected = [
+            "-nan(1)", "-snan", "-inf", "-1e300", "-10", "-1.0", "-1.00", "-0.001", "-0", "0",
+            "0.001", "1.00", "1.0", "10", "1e300", "inf", "snan", "nan(1)",
+        ];
 
-        assert_eq!(32, d.as_le_bytes().len() * 8);
-    }
+        let mut ds = expected
+            .iter()
+            .rev()
+            .map(|f| Bitstring64::try_parse_str(f).expect("failed to parse decimal"))
+            .collect::<Vec<_>>();
 
-    #[test]
-    fn err_decimal_overflow_exponent() {
+        ds.sort_by_key(|d| d.to_ord_key());
+
+        assert_eq!(
+            expected.to_vec(),
+            ds.iter().map(|d| d.to_string()).collect::<Vec<_>>()
+        );
+
+        // Keys are independent of the width of the decimal
+        assert_eq!(
+            Bitstring32::try_parse_str("-1.5")
+                .expect("failed to parse decimal")
+                .to_ord_key(),
+            Bitstring128::try_parse_str("-1.5")
+                .expect("failed to parse decimal")
+                .to_ord_key()
+        );
+    }
+
+    #[test]
+    fn decimal_roundtrip_i128() {
+        for i in [0i128, 42i128, i128::MIN, i128::MAX] {
+            let d = Bitstring::from(i);
+            let di = d.try_into().unwrap();
+
+            assert_eq!(i, di);
+        }
+    }
+
+    #[test]
+    fn decimal_roundtrip_u128() {
+        for i in [0u128, 42u128, u128::MAX] {
+            let d = Bitstring::from_u128(i);
+            let di = d.to_u128().unwrap();
+
+            assert_eq!(i, di);
+        }
+    }
+
+    #[test]
+    fn decimal_to_int_with_exponent() {
+        for (f, i) in [("17e1", 170i32), ("4e7", 40000000i32), ("170e-1", 17i32)] {
+            let d = Bitstring::try_parse_str(f).unwrap();
+
+            assert_eq!(i, d.to_i32().unwrap(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_is_integer() {
+        for (f, is_integer) in [
+            ("0", true),
+            ("-0", true),
+            ("0.000", true),
+            ("0e-618", true),
+            ("0e5", true),
+            ("1", true),
+            ("-1", true),
+            ("1.0", true),
+            ("1.000", true),
+            ("120e2", true),
+            ("17e1", true),
+            ("4e7", true),
+            ("4e618", true),
+            ("170e-1", true),
+            ("1200e-2", true),
+            ("1.5", false),
+            ("-1.5", false),
+            ("1e-1", false),
+            ("17e-1", false),
+            ("1200e-3", false),
+            ("0.001", false),
+            ("inf", false),
+            ("-inf", false),
+            ("nan", false),
+            ("snan(1)", false),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(is_integer, d.is_integer(), "{}", f);
+
+            // Decimals with a fractional part can't be converted to an integer
+            if !is_integer {
+                assert!(d.to_i32().is_none(), "{}", f);
+            }
+        }
+    }
+
+    #[test]
+    fn err_decimal_to_int_exponent_overflow() {
+        for f in ["4e618", "17e-1", "1e-1"] {
+            let d = Bitstring::try_parse_str(f).unwrap();
+
+            assert!(d.to_i32().is_none(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_to_i128_exact() {
+        for (f, expected) in [
+            ("0", 0),
+            ("-123", -123),
+            ("1.000", 1),
+            ("12e3", 12000),
+            ("0e-618", 0),
+            ("-170141183460469231731687303715884105728", i128::MIN),
+        ] {
+            let d = Bitstring::try_parse_str(f).unwrap();
+
+            assert_eq!(
+                expected,
+                d.to_i128_exact().expect("failed to convert"),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_from_scaled_i64() {
+        for (value, scale, expected) in [
+            (12345, 2, "123.45"),
+            (-12345, 2, "-123.45"),
+            (1200, 2, "12.00"),
+            (5, 0, "5"),
+            (5, 3, "0.005"),
+            (0, 2, "0.00"),
+            (1234567, 7, "0.1234567"),
+            (1, 101, "1e-101"),
+        ] {
+            let d = Bitstring32::from_scaled_i64(value, scale).expect("failed to convert");
+            assert_eq!(expected, d.to_string(), "{} {}", value, scale);
+
+            // The result is the same as parsing the integer with a negative exponent
+            let parsed = Bitstring32::try_parse_str(&format!("{}e-{}", value, scale))
+                .expect("failed to parse decimal");
+            assert_eq!(parsed.as_le_bytes(), d.as_le_bytes(), "{} {}", value, scale);
+        }
+
+        let d = Bitstring128::from_scaled_i64(i64::MIN, 4).expect("failed to convert");
+        assert_eq!("-922337203685477.5808", d.to_string());
+
+        let d = Bitstring::from_scaled_i64(i64::MAX, 18).expect("failed to convert");
+        assert_eq!("9.223372036854775807", d.to_string());
+        assert_eq!(12, d.as_le_bytes().len());
+
+        let d = Bitstring128::from_scaled_i64(-1, 6176).expect("failed to convert");
+        assert_eq!("-1e-6176", d.to_string());
+    }
+
+    #[test]
+    fn err_decimal_from_scaled_i64() {
+        for (value, scale) in [(12345678, 2), (1, 102), (1, u32::MAX)] {
+            assert!(
+                Bitstring32::from_scaled_i64(value, scale).is_err(),
+                "{} {}",
+                value,
+                scale
+            );
+        }
+
+        assert!(Bitstring64::from_scaled_i64(i64::MAX, 0).is_err());
+    }
+
+    #[test]
+    fn decimal_from_coefficient_exponent_const() {
+        const PRICE: Bitstring32 = Bitstring32::from_coefficient_exponent_const(-1999, -2);
+        const RATE: Bitstring64 = Bitstring64::from_coefficient_exponent_const(525, -4);
+        const BIG: Bitstring128 =
+            Bitstring128::from_coefficient_exponent_const(i128::MAX / 100000, 6111);
+
+        assert_eq!(
+            Bitstring32::try_parse_str("-19.99")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            PRICE.as_le_bytes()
+        );
+        assert_eq!(
+            Bitstring64::try_parse_str("0.0525")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            RATE.as_le_bytes()
+        );
+        assert_eq!(
+            Bitstring128::try_parse_str("1701411834604692317316873037158841e6111")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            BIG.as_le_bytes()
+        );
+
+        assert_eq!(
+            Bitstring64::ONE.as_le_bytes(),
+            Bitstring64::from_coefficient_exponent_const(1, 0).as_le_bytes()
+        );
+    }
+
+    #[test]
+    fn decimal_from_bool() {
+        assert_eq!(
+            Bitstring32::ONE.as_le_bytes(),
+            Bitstring32::from(true).as_le_bytes()
+        );
+        assert_eq!(
+            Bitstring32::ZERO.as_le_bytes(),
+            Bitstring32::from(false).as_le_bytes()
+        );
+
+        assert_eq!("1", Bitstring::from(true).to_string());
+        assert_eq!("0", Bitstring::from(false).to_string());
+    }
+
+    #[test]
+    fn decimal_to_nonzero() {
+        use core::num::{
+            NonZeroI64,
+            NonZeroU64,
+            NonZeroU8,
+        };
+
+        for (f, expected) in [
+            ("1", NonZeroI64::new(1)),
+            ("-42", NonZeroI64::new(-42)),
+            ("1.000", NonZeroI64::new(1)),
+            ("12e3", NonZeroI64::new(12000)),
+            ("-9223372036854775808", NonZeroI64::new(i64::MIN)),
+            ("0", None),
+            ("-0", None),
+            ("0.000", None),
+            ("0e10", None),
+            ("1.5", None),
+            ("9223372036854775808", None),
+            ("inf", None),
+            ("nan", None),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.to_nonzero_i64(), "{}", f);
+            assert_eq!(
+                d.to_i64().and_then(NonZeroI64::new),
+                d.to_nonzero_i64(),
+                "{}",
+                f
+            );
+        }
+
+        let d = Bitstring64::try_parse_str("255").expect("failed to parse decimal");
+        assert_eq!(NonZeroU8::new(255), d.to_nonzero_u8());
+        assert_eq!(NonZeroU64::new(255), d.to_nonzero_u64());
+
+        let d = Bitstring64::try_parse_str("-1").expect("failed to parse decimal");
+        assert_eq!(None, d.to_nonzero_u64());
+    }
+
+    #[test]
+    fn err_decimal_to_i128_exact() {
+        for (f, is_overflow) in [
+            ("1.5", false),
+            ("-0.001", false),
+            ("170141183460469231731687303715884105728", true),
+            ("1e39", true),
+            ("4e618", true),
+            ("1e-618", false),
+            ("inf", true),
+            ("nan", true),
+        ] {
+            let d = Bitstring::try_parse_str(f).unwrap();
+            let err = d.to_i128_exact().unwrap_err();
+
+            assert_eq!(is_overflow, err.is_overflow(), "{}", f);
+            assert_eq!(!is_overflow, err.is_non_integer(), "{}", f);
+
+            assert!(d.to_i128().is_none(), "{}", f);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn decimal_roundtrip_f32() {
+        for f in [
+            0.0f32,
+            17.05e2f32,
+            f32::MIN,
+            f32::MAX,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ] {
+            let d = Bitstring::from_f32(f);
+            let df = d.to_f32().unwrap();
+
+            assert_eq!(f, df);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn decimal_roundtrip_f32_nan() {
+        let f = nan32(0);
+
+        let d = Bitstring::from_f32(f);
+
+        assert!(d.is_nan());
+
+        let df = d.to_f32().unwrap();
+
+        assert_eq!(f.to_bits(), df.to_bits());
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn decimal_f32_nan_ignores_payload() {
+        let d1 = Bitstring::from_f32(nan32(0));
+        let d2 = Bitstring::from_f32(nan32(42));
+
+        assert!(d1.is_nan());
+        assert!(d2.is_nan());
+
+        assert_eq!(d1.to_string(), d2.to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn err_decimal_to_f32_overflow() {
+        let d = Bitstring::try_parse_str("1e106").unwrap();
+
+        assert!(d.to_f32().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn decimal_roundtrip_f64() {
+        for f in [
+            0.0f64,
+            17.05e2f64,
+            f64::MIN,
+            f64::MAX,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            let d = Bitstring::from_f64(f);
+            let df = d.to_f64().unwrap();
+
+            assert_eq!(f, df);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn decimal_roundtrip_f64_nan() {
+        let f = nan64(0);
+
+        let d = Bitstring::from_f64(f);
+
+        assert!(d.is_nan());
+
+        let df = d.to_f64().unwrap();
+
+        assert_eq!(f.to_bits(), df.to_bits());
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn decimal_f64_nan_ignores_payload() {
+        let d1 = Bitstring::from_f64(nan64(0));
+        let d2 = Bitstring::from_f64(nan64(42));
+
+        assert!(d1.is_nan());
+        assert!(d2.is_nan());
+
+        assert_eq!(d1.as_le_bytes(), d2.as_le_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn decimal_from_f64_precision() {
+        for (f, sig_digits, expected) in [
+            (0.1, 17, "0.10000000000000001"),
+            (0.1, 1, "0.1"),
+            (0.5, 3, "0.500"),
+            (-2.5, 1, "-2"),
+            (123456.0, 2, "1.2e5"),
+            (1e300, 17, "1.0000000000000001e300"),
+            (0.0, 3, "0.00"),
+            (f64::MIN_POSITIVE, 17, "2.2250738585072014e-308"),
+            (0.1, 34, "0.1000000000000000055511151231257827"),
+        ] {
+            assert_eq!(
+                expected,
+                Bitstring128::from_f64_precision(f, sig_digits)
+                    .expect("failed to convert float")
+                    .to_string(),
+                "{} with {} digits",
+                f,
+                sig_digits
+            );
+        }
+
+        assert!(Bitstring128::from_f64_precision(f64::NAN, 17)
+            .expect("failed to convert float")
+            .is_nan());
+        assert!(Bitstring128::from_f64_precision(f64::NEG_INFINITY, 17)
+            .expect("failed to convert float")
+            .is_infinite());
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn err_decimal_from_f64_precision() {
+        for sig_digits in [0, 35, u8::MAX] {
+            assert!(
+                Bitstring128::from_f64_precision(0.1, sig_digits).is_err(),
+                "{}",
+                sig_digits
+            );
+        }
+
+        // At least one digit is needed, whatever the float is
+        for f in [0.1, 0.0, f64::NAN] {
+            let err = Bitstring128::from_f64_precision(f, 0).unwrap_err();
+
+            assert!(err.is_convert(), "{}", f);
+            assert!(!err.is_overflow(), "{}", f);
+            assert_eq!(
+                "conversion to `decimal` needs at least one significant digit",
+                err.to_string()
+            );
+        }
+
+        // Only as many digits as the decimal's precision fit
+        assert!(Bitstring128::from_f64_precision(0.1, 34).is_ok());
+
+        let err = Bitstring128::from_f64_precision(0.1, 35).unwrap_err();
+        assert!(err.is_overflow());
+        assert_eq!(
+            "the value cannot fit into a decimal of `16` bytes; the width needed is `20` bytes",
+            err.to_string()
+        );
+
+        assert!(Bitstring256::from_f64_precision(0.1, 70).is_ok());
+        assert!(Bitstring256::from_f64_precision(0.1, 71)
+            .unwrap_err()
+            .is_overflow());
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn err_decimal_to_f64_overflow() {
+        let d = Bitstring::try_parse_str("1e4513").unwrap();
+
+        assert!(d.to_f64().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn decimal_to_f64_round() {
+        let d = Bitstring64::try_parse_str("0.1").expect("failed to parse decimal");
+
+        assert_eq!(Some(0.1), d.to_f64_round(RoundingMode::NearestTiesToEven));
+        assert_eq!(d.to_f64(), d.to_f64_round(RoundingMode::default()));
+        assert_eq!(
+            Some(f64::from_bits(0.1f64.to_bits() - 1)),
+            d.to_f64_round(RoundingMode::TowardZero)
+        );
+
+        let d = Bitstring64::try_parse_str("16777217").expect("failed to parse decimal");
+
+        assert_eq!(
+            Some(16777216.0),
+            d.to_f32_round(RoundingMode::NearestTiesToEven)
+        );
+        assert_eq!(
+            Some(16777218.0),
+            d.to_f32_round(RoundingMode::TowardPositive)
+        );
+        assert_eq!(
+            Some(16777217.0),
+            d.to_f64_round(RoundingMode::TowardPositive)
+        );
+
+        let d = Bitstring::try_parse_str("1e4513").expect("failed to parse decimal");
+
+        assert!(d.to_f64_round(RoundingMode::NearestTiesToEven).is_none());
+        assert_eq!(Some(f64::MAX), d.to_f64_round(RoundingMode::TowardZero));
+    }
+
+    #[test]
+    fn decimal_roundtrip_str() {
+        for f in [
+            "0",
+            "-0",
+            "0.0",
+            "-0.0",
+            "435",
+            "-435",
+            "547473436755",
+            "-547473436755",
+            "354.55",
+            "-354.55",
+            "3546.8764256732",
+            "-3546.8764256732",
+            "0.00012532",
+            "-0.00012532",
+            "0e1",
+            "120e2",
+            "-120e2",
+            "123e456",
+            "-123e456",
+            "123e-3",
+            "-123e-3",
+            "1.2354e-7",
+            "-1.2354e-7",
+            "1e96",
+            "1e-95",
+            "1e384",
+            "1e-383",
+            "1e6144",
+            "1e-1643",
+            "nan",
+            "nan(123)",
+            "inf",
+            "-inf",
+            "snan",
+            "snan(123)",
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            // Ensure bitstrings roundtrip through from_le_bytes and to_le_bytes
+            assert_eq!(
+                bitstr(d.as_le_bytes()),
+                bitstr(
+                    Bitstring::try_from_le_bytes(d.as_le_bytes())
+                        .expect("failed to convert bytes to decimal")
+                        .as_le_bytes()
+                ),
+                "{}",
+                f
+            );
+
+            let s = d.to_string();
+
+            // Ensure bitstrings roundtrip through to_string and try_parse_str
+            assert_eq!(
+                bitstr(d.as_le_bytes()),
+                bitstr(
+                    Bitstring::try_parse_str(&s)
+                        .expect("failed to parse decimal")
+                        .as_le_bytes()
+                ),
+                "{} -> {}",
+                f,
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_roundtrip_nan_str() {
+        for (f, expected) in [
+            ("nan", "nan"),
+            ("nan(0)", "nan"),
+            ("nan()", "nan"),
+            ("-nan(456)", "-nan(456)"),
+            ("snan", "snan"),
+            ("snan(0)", "snan"),
+            ("snan()", "snan"),
+            ("-snan(456)", "-snan(456)"),
+            ("+snan(1)", "snan(1)"),
+            ("NaN", "nan"),
+            ("-NAN(12)", "-nan(12)"),
+            ("sNaN", "snan"),
+            ("-SNAN(456)", "-snan(456)"),
+            ("nan(000123)", "nan(123)"),
+            ("snan(0000000000123)", "snan(123)"),
+            ("qnan", "nan"),
+            ("-qNaN", "-nan"),
+            ("+QNAN", "nan"),
+            ("qnan(5)", "nan(5)"),
+            ("-qnan(0123)", "-nan(123)"),
+        ] {
+            let d32 = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d32.to_string(), "{}", f);
+            assert_eq!(expected, d.to_string(), "{}", f);
+
+            // Leading zeroes in the payload don't make the decimal any wider
+            assert_eq!(4, d.as_le_bytes().len(), "{}", f);
+
+            // Ensure NaNs roundtrip through to_string and try_parse_str
+            assert_eq!(
+                d32.as_le_bytes(),
+                Bitstring32::try_parse_str(&d32.to_string())
+                    .expect("failed to parse decimal")
+                    .as_le_bytes(),
+                "{}",
+                f
+            );
+            assert_eq!(
+                d.as_le_bytes(),
+                Bitstring::try_parse_str(&d.to_string())
+                    .expect("failed to parse decimal")
+                    .as_le_bytes(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn decimal_serde_json_roundtrip() {
+        for (f, expected) in [
+            ("1.50", "\"1.50\""),
+            ("-0.000", "\"-0.000\""),
+            ("150", "\"150\""),
+            ("1.5e10", "\"1.5e10\""),
+            ("inf", "\"inf\""),
+            ("-snan(42)", "\"-snan(42)\""),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            let json = serde_json::to_string(&d).expect("failed to serialize decimal");
+            assert_eq!(expected, json, "{}", f);
+
+            let d: Bitstring64 =
+                serde_json::from_str(&json).expect("failed to deserialize decimal");
+            assert_eq!(f, d.to_string(), "{}", f);
+
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            let json = serde_json::to_string(&d).expect("failed to serialize decimal");
+            assert_eq!(expected, json, "{}", f);
+
+            let d: Bitstring = serde_json::from_str(&json).expect("failed to deserialize decimal");
+            assert_eq!(f, d.to_string(), "{}", f);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn decimal_serde_json_as_number() {
+        for (f, expected) in [
+            ("150", "150"),
+            ("-42", "-42"),
+            ("0", "0"),
+            ("18446744073709551615", "18446744073709551615"),
+            // These decimals would lose information as JSON numbers
+            ("1.50", "\"1.50\""),
+            ("15e1", "\"1.5e2\""),
+            ("-0", "\"-0\""),
+            ("18446744073709551616", "\"18446744073709551616\""),
+            ("nan", "\"nan\""),
+        ] {
+            let d = Bitstring128::try_parse_str(f).expect("failed to parse decimal");
+
+            let mut json = Vec::new();
+            serde::serialize_as_number(&d, &mut serde_json::Serializer::new(&mut json))
+                .expect("failed to serialize decimal");
+
+            let json = String::from_utf8(json).expect("invalid JSON");
+            assert_eq!(expected, json, "{}", f);
+
+            // Whether it's a number or a string, the decimal deserializes back to the same value
+            let roundtrip: Bitstring128 =
+                serde_json::from_str(&json).expect("failed to deserialize decimal");
+            assert_eq!(d.to_string(), roundtrip.to_string(), "{}", f);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn err_decimal_serde_json() {
+        for json in ["\"1.5x\"", "1.5", "true", "\"12345678\""] {
+            assert!(
+                serde_json::from_str::<Bitstring32>(json).is_err(),
+                "{}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn decimal_serde_binary_roundtrip() {
+        use serde_test::{
+            assert_de_tokens_error,
+            assert_ser_tokens,
+            assert_tokens,
+            Configure,
+            Token,
+        };
+
+        for f in ["1.50", "-0.000", "1e-398", "inf", "-inf"] {
+            // Tokens need to be `'static`, so the bytes are leaked
+            fn bytes(b: &[u8]) -> &'static [u8] {
+                Box::leak(b.to_vec().into_boxed_slice())
+            }
+
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+            assert_tokens(&d.compact(), &[Token::Bytes(bytes(d.as_le_bytes()))]);
+            assert_tokens(&d.readable(), &[Token::Str(f)]);
+
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+            assert_tokens(&d.compact(), &[Token::Bytes(bytes(d.as_le_bytes()))]);
+            assert_tokens(&d.readable(), &[Token::Str(f)]);
+        }
+
+        // NaNs aren't equal to themselves, so they can only be checked in one direction
+        let nan = Bitstring64::try_parse_str("-snan(42)").expect("failed to parse decimal");
+        assert_ser_tokens(
+            &nan.compact(),
+            &[Token::Bytes(&[0x42, 0, 0, 0, 0, 0, 0, 0xfe])],
+        );
+        assert_ser_tokens(&nan.readable(), &[Token::Str("-snan(42)")]);
+
+        assert_de_tokens_error::<serde_test::Compact<Bitstring64>>(
+            &[Token::Bytes(&[0; 4])],
+            "invalid length 4, expected exactly 8 bytes",
+        );
+        assert_de_tokens_error::<serde_test::Compact<Bitstring128>>(
+            &[Token::Bytes(&[0; 17])],
+            "invalid length 17, expected exactly 16 bytes",
+        );
+        assert_de_tokens_error::<serde_test::Compact<Bitstring>>(
+            &[Token::Bytes(&[0; 6])],
+            "invalid length 6, expected a multiple of 4 bytes, up to 32 bytes",
+        );
+    }
+
+    #[test]
+    fn decimal_display_with() {
+        let gda = FormatOptions::new()
+            .uppercase_exponent(true)
+            .explicit_positive_exponent(true);
+
+        for (f, expected_default, expected_plus, expected_gda) in [
+            ("1e5", "1e5", "1e+5", "1E+5"),
+            ("-1.5e10", "-1.5e10", "-1.5e+10", "-1.5E+10"),
+            ("1.5e-10", "1.5e-10", "1.5e-10", "1.5E-10"),
+            ("0e5", "0e5", "0e+5", "0E+5"),
+            ("123.456", "123.456", "123.456", "123.456"),
+            ("-inf", "-inf", "-inf", "-inf"),
+            ("nan(1)", "nan(1)", "nan(1)", "nan(1)"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected_default, d.to_string(), "{}", f);
+            assert_eq!(
+                expected_default,
+                d.display_with(FormatOptions::default()).to_string(),
+                "{}",
+                f
+            );
+            assert_eq!(
+                expected_plus,
+                d.display_with(FormatOptions::new().explicit_positive_exponent(true))
+                    .to_string(),
+                "{}",
+                f
+            );
+
+            let s = d.display_with(gda).to_string();
+            assert_eq!(expected_gda, s, "{}", f);
+
+            // Ensure the formatted text parses back to the same decimal
+            assert_eq!(
+                d.as_le_bytes(),
+                Bitstring64::try_parse_str(&s)
+                    .expect("failed to parse decimal")
+                    .as_le_bytes(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_display_precision() {
+        for (f, expected_0, expected_2, expected_8) in [
+            ("0", "0", "0.00", "0.00000000"),
+            ("-0.000", "-0", "-0.00", "-0.00000000"),
+            ("0e5", "0", "0.00", "0.00000000"),
+            ("1", "1", "1.00", "1.00000000"),
+            ("1.5", "1", "1.50", "1.50000000"),
+            ("1.999", "1", "1.99", "1.99900000"),
+            ("-123.456", "-123", "-123.45", "-123.45600000"),
+            ("0.0123", "0", "0.01", "0.01230000"),
+            ("1.5e-10", "0", "0.00", "0.00000000"),
+            ("1.5e3", "1500", "1500.00", "1500.00000000"),
+            ("12e2", "1200", "1200.00", "1200.00000000"),
+            ("0.000000001234", "0", "0.00", "0.00000000"),
+            ("0.00000123456789", "0", "0.00", "0.00000123"),
+            ("-inf", "-inf", "-inf", "-inf"),
+            ("nan(1)", "nan(1)", "nan(1)", "nan(1)"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected_0, format!("{:.0}", d), "{}", f);
+            assert_eq!(expected_2, format!("{:.2}", d), "{}", f);
+            assert_eq!(expected_8, format!("{:.8}", d), "{}", f);
+        }
+
+        // Without a precision the decimal is written as usual
+        let d = Bitstring64::try_parse_str("1.50").expect("failed to parse decimal");
+        assert_eq!("1.50", format!("{}", d));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decimal_to_engineering_string() {
+        for (f, expected) in [
+            ("0", "0e0"),
+            ("1", "1e0"),
+            ("-1", "-1e0"),
+            ("123.456", "123.456e0"),
+            ("1234", "1.234e3"),
+            ("1.23e4", "12.3e3"),
+            ("-1.23e5", "-123e3"),
+            ("1e5", "100e3"),
+            ("1.5e10", "15e9"),
+            ("0.5", "500e-3"),
+            ("0.0123", "12.3e-3"),
+            ("0.0000123", "12.3e-6"),
+            ("-1.5e-10", "-150e-12"),
+            ("1234567890123456e10", "12.34567890123456e24"),
+            ("-inf", "-inf"),
+            ("nan(1)", "nan(1)"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            let s = d.to_engineering_string();
+            assert_eq!(expected, s, "{}", f);
+
+            let mut w = String::new();
+            d.write_engineering_to(&mut w)
+                .expect("failed to format decimal");
+            assert_eq!(expected, w, "{}", f);
+
+            // Ensure the formatted text parses back to an equal decimal
+            if !d.is_nan() {
+                assert_eq!(
+                    d,
+                    Bitstring64::try_parse_str(&s).expect("failed to parse decimal"),
+                    "{}",
+                    f
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn decimal_write_str_into() {
+        for f in [
+            "0",
+            "-1.5",
+            "123.456",
+            "1.5e-10",
+            "-9999999999999999e369",
+            "-inf",
+            "snan(123)",
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+            let expected = d.to_string();
+
+            let mut buf = [0; 64];
+            assert_eq!(
+                expected,
+                d.write_str_into(&mut buf)
+                    .expect("failed to format decimal"),
+                "{}",
+                f
+            );
+
+            // A buffer that's exactly the right size is big enough
+            let mut buf = [0; 64];
+            assert_eq!(
+                expected,
+                d.write_str_into(&mut buf[..expected.len()])
+                    .expect("failed to format decimal"),
+                "{}",
+                f
+            );
+
+            // A buffer that's too small fails
+            let mut buf = [0; 64];
+            let err = d
+                .write_str_into(&mut buf[..expected.len() - 1])
+                .unwrap_err();
+            assert_eq!(expected.len() - 1, err.max_width_bytes(), "{}", f);
+            assert_eq!(Some(expected.len()), err.required_width_bytes(), "{}", f);
+        }
+
+        let d = Bitstring32::try_parse_str("1").expect("failed to parse decimal");
+        assert!(d.write_str_into(&mut []).is_err());
+    }
+
+    #[test]
+    fn decimal_lower_upper_exp() {
+        for (f, expected_lower, expected_upper) in [
+            ("0", "0e0", "0E0"),
+            ("-0", "-0e0", "-0E0"),
+            ("0.00", "0e-2", "0E-2"),
+            ("1", "1e0", "1E0"),
+            ("10", "1.0e1", "1.0E1"),
+            ("1234", "1.234e3", "1.234E3"),
+            ("-123.456", "-1.23456e2", "-1.23456E2"),
+            ("0.0123", "1.23e-2", "1.23E-2"),
+            ("1.5e-10", "1.5e-10", "1.5E-10"),
+            ("1e5", "1e5", "1E5"),
+            (
+                "1234567890123456e10",
+                "1.234567890123456e25",
+                "1.234567890123456E25",
+            ),
+            ("-inf", "-inf", "-inf"),
+            ("nan(1)", "nan(1)", "nan(1)"),
+            ("snan", "snan", "snan"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected_lower, format!("{:e}", d), "{}", f);
+            assert_eq!(expected_upper, format!("{:E}", d), "{}", f);
+
+            // Ensure the formatted text parses back to the same decimal
+            assert_eq!(
+                d.as_le_bytes(),
+                Bitstring64::try_parse_str(&format!("{:E}", d))
+                    .expect("failed to parse decimal")
+                    .as_le_bytes(),
+                "{}",
+                f
+            );
+        }
+
+        let d = Bitstring32::try_parse_str("1234567e5").expect("failed to parse decimal");
+        assert_eq!("1.234567e11", format!("{:e}", d));
+        assert_eq!("1.234567e11", d.to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decimal_to_gda_string() {
+        for (f, expected) in [
+            ("123", "123"),
+            ("-1.5", "-1.5"),
+            ("15e1", "1.5E+2"),
+            ("0.0000001", "1E-7"),
+            ("0.000001", "0.000001"),
+            ("-0e3", "-0E+3"),
+            ("inf", "Infinity"),
+            ("snan(1)", "sNaN1"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.to_gda_string(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_fmt_binary() {
+        // These are the examples from the encoding table in the crate docs
+        for (f, expected) in [
+            ("0", "00000000_00000000_01010000_00100010"),
+            ("-0", "00000000_00000000_01010000_10100010"),
+            ("0e1", "00000000_00000000_01100000_00100010"),
+            ("123", "10100011_00000000_01010000_00100010"),
+            ("-123", "10100011_00000000_01010000_10100010"),
+            ("123.456", "01010110_10001110_00100010_00100010"),
+            ("-123.456", "01010110_10001110_00100010_10100010"),
+            ("inf", "00000000_00000000_00000000_01111000"),
+            ("-inf", "00000000_00000000_00000000_11111000"),
+            ("nan", "00000000_00000000_00000000_01111100"),
+            ("snan", "00000000_00000000_00000000_01111110"),
+            ("-nan", "00000000_00000000_00000000_11111100"),
+            ("-snan", "00000000_00000000_00000000_11111110"),
+            ("nan(123)", "10100011_00000000_00000000_01111100"),
+            ("snan(123)", "10100011_00000000_00000000_01111110"),
+        ] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, format!("{:#b}", d), "{}", f);
+            assert_eq!(bitstr(d.as_le_bytes()), format!("{:#b}", d), "{}", f);
+            assert_eq!(expected.replace('_', ""), format!("{:b}", d), "{}", f);
+        }
+
+        let d = Bitstring128::try_parse_str("-123.456").expect("failed to parse decimal");
+        assert_eq!(bitstr(d.as_le_bytes()), format!("{:#b}", d));
+        assert_eq!(128, format!("{:b}", d).len());
+    }
+
+    #[test]
+    fn decimal_256() {
+        let digits = "1234567890123456789012345678901234567890123456789012345678901234567890";
+
+        for f in [
+            digits,
+            "-1.234567890123456789012345678901234567890123456789012345678901234567890",
+            "1.5e1572795",
+            "1e-1572932",
+            "-0",
+            "inf",
+            "nan(123)",
+        ] {
+            let d = Bitstring256::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(f, d.to_string());
+            assert_eq!(
+                d.as_le_bytes(),
+                Bitstring256::from_be_bytes(d.to_be_bytes()).as_le_bytes()
+            );
+        }
+
+        // Decimals with more than 70 digits don't fit
+        assert!(Bitstring256::try_parse_str(&format!("{}1", digits)).is_err());
+
+        // All 128 bit integers fit exactly
+        assert_eq!(
+            i128::MIN.to_string(),
+            Bitstring256::from(i128::MIN).to_string()
+        );
+        assert_eq!(
+            u128::MAX.to_string(),
+            Bitstring256::from(u128::MAX).to_string()
+        );
+
+        // Decimals can be converted to narrower widths as long as their value doesn't change
+        let d = Bitstring256::try_parse_str("1.500").expect("failed to parse decimal");
+        assert_eq!(
+            Bitstring32::try_parse_str("1.500")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            Bitstring32::try_from(d)
+                .expect("failed to convert")
+                .as_le_bytes()
+        );
+        assert!(Bitstring128::try_from(Bitstring256::from(u128::MAX)).is_err());
+    }
+
+    #[test]
+    fn decimal_parse_with_status() {
+        for (f, expected, is_clamped) in [
+            ("123", "123", false),
+            ("1e90", "1e90", false),
+            ("1e91", "1.0e91", true),
+            ("-1.5e95", "-1.50000e95", true),
+            ("1.2345e95", "1.23450e95", true),
+            ("1.2345e93", "1.2345e93", false),
+            ("0e100", "0e90", true),
+            ("-0.0e-200", "-0e-101", true),
+            ("0.0000050", "0.0000050", false),
+            ("000.00", "0.00", false),
+            ("inf", "inf", false),
+            ("nan(1)", "nan(1)", false),
+        ] {
+            let (d, status) = Bitstring32::parse_with_status(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.to_string(), "{}", f);
+            assert_eq!(is_clamped, status.is_clamped(), "{}", f);
+            assert_eq!(!is_clamped, status.is_exact(), "{}", f);
+
+            // Parsing without the status doesn't clamp
+            if is_clamped {
+                assert!(Bitstring32::try_parse_str(f).is_err(), "{}", f);
+            } else {
+                assert_eq!(
+                    d.as_le_bytes(),
+                    Bitstring32::try_parse_str(f)
+                        .expect("failed to parse decimal")
+                        .as_le_bytes(),
+                    "{}",
+                    f
+                );
+            }
+        }
+
+        // Dynamically sized decimals grow to fit their exponent instead of clamping
+        let (_, status) = Bitstring::parse_with_status("1e91").expect("failed to parse decimal");
+        assert_eq!(ParseStatus::exact(), status);
+    }
+
+    #[test]
+    fn decimal_parse_clamped_emax() {
+        macro_rules! assert_clamped_emax {
+            ($d:ident, $emax:literal, $overflow:literal) => {{
+                let f = concat!("1e", $emax);
+                let (d, status) = $d::parse_with_status(f).expect("failed to parse decimal");
+
+                assert!(status.is_clamped(), "{}", f);
+                assert_eq!(Some($emax), d.adjusted_exponent(), "{}", f);
+                assert_eq!(d.max_exponent(), $emax, "{}", f);
+
+                assert!($d::try_parse_str($overflow).is_err(), "{}", $overflow);
+            }};
+        }
+
+        assert_clamped_emax!(Bitstring32, 96, "1e97");
+        assert_clamped_emax!(Bitstring64, 384, "1e385");
+        assert_clamped_emax!(Bitstring128, 6144, "1e6145");
+        assert_clamped_emax!(Bitstring256, 1572864, "1e1572865");
+    }
+
+    #[test]
+    fn decimal_try_from_parsed_chunks() {
+        use core::fmt::Write;
+
+        use crate::text::{
+            ArrayTextBuf,
+            DecimalParser,
+        };
+
+        for (chunks, expected) in [
+            (&["1", "23.4", "5"][..], "123.45"),
+            (&["-i", "nf"], "-inf"),
+            (&["sn", "an(1", "2)"], "snan(12)"),
+        ] {
+            let mut parser = DecimalParser::begin(ArrayTextBuf::<32>::default());
+
+            for chunk in chunks {
+                write!(parser, "{}", chunk).expect("failed to parse chunk");
+            }
+
+            let parsed = parser.end().expect("failed to parse decimal");
+
+            assert_eq!(
+                expected,
+                Bitstring::try_from_parsed(parsed)
+                    .expect("failed to encode decimal")
+                    .to_string()
+            );
+        }
+
+        // The buffer has to fit the whole decimal
+        let mut parser = DecimalParser::begin(ArrayTextBuf::<4>::default());
+        assert!(parser.parse_ascii(b"12345").is_err());
+
+        // The decimal has to be complete
+        let mut parser = DecimalParser::begin(ArrayTextBuf::<32>::default());
+        parser.parse_ascii(b"1.5e").expect("failed to parse chunk");
+        assert!(parser.end().is_err());
+    }
+
+    #[test]
+    fn decimal_parse_digit_separators() {
+        let options = ParseOptions::new().digit_separators(true);
+
+        for (f, expected) in [
+            ("1_000", "1000"),
+            ("-1_000_000", "-1000000"),
+            ("+1_0", "10"),
+            ("1_000.000_1", "1000.0001"),
+            ("0.1_2", "0.12"),
+            ("1_2e1_0", "12e10"),
+            ("1.5e-1_0", "1.5e-10"),
+            ("1000", "1000"),
+            ("inf", "inf"),
+            ("nan", "nan"),
+        ] {
+            assert_eq!(
+                Bitstring64::try_parse_str(expected)
+                    .expect("failed to parse decimal")
+                    .as_le_bytes(),
+                Bitstring64::try_parse_str_with(f, options)
+                    .expect("failed to parse decimal")
+                    .as_le_bytes(),
+                "{}",
+                f
+            );
+            assert_eq!(
+                Bitstring::try_parse_str(expected)
+                    .expect("failed to parse decimal")
+                    .as_le_bytes(),
+                Bitstring::try_parse_str_with(f, options)
+                    .expect("failed to parse decimal")
+                    .as_le_bytes(),
+                "{}",
+                f
+            );
+        }
+
+        for f in [
+            "_1", "-_1", "1_", "1__0", "1_.0", "1._0", "1.0_", "1_e5", "1e_5", "1e-_5", "1e5_",
+            "_", "1_000_",
+        ] {
+            assert!(
+                Bitstring64::try_parse_str_with(f, options).is_err(),
+                "{}",
+                f
+            );
+        }
+
+        // Separators aren't accepted by default
+        for f in ["1_000", "1.000_1"] {
+            assert!(Bitstring64::try_parse_str(f).is_err(), "{}", f);
+            assert!(
+                Bitstring64::try_parse_str_with(f, ParseOptions::new()).is_err(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn err_decimal_parse_nan() {
+        for f in [
+            "q", "qn", "qsnan", "sqnan", "qqnan", "nanq", "qinf", "q1", "-q",
+        ] {
+            assert!(Bitstring32::try_parse_str(f).is_err(), "{}", f);
+            assert!(
+                Bitstring32::try_parse(format_args!("{}", f)).is_err(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn err_decimal_parse_unclamped() {
+        // Only `parse_with_status` clamps exponents
+        for f in ["1e91", "12e95", "0e100", "-0e-200", "0.0e-101"] {
+            assert!(Bitstring32::try_parse_str(f).is_err(), "{}", f);
+            assert!(f.parse::<Bitstring32>().is_err(), "{}", f);
+            assert!(Bitstring32::try_from(f).is_err(), "{}", f);
+            assert!(
+                Bitstring32::try_parse(format_args!("{}", f)).is_err(),
+                "{}",
+                f
+            );
+
+            let (_, status) = Bitstring32::parse_with_status(f).expect("failed to parse decimal");
+            assert!(status.is_clamped(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn err_decimal_parse_with_status_clamped_overflow() {
+        for f in ["1e97", "12e96", "1e-102"] {
+            assert!(Bitstring32::parse_with_status(f).is_err(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_parse_trailing_zeroes() {
+        for (f, expected) in [
+            ("1.2300000000000000".to_owned(), "1.230000"),
+            ("-1.2300000000000000".to_owned(), "-1.230000"),
+            (
+                "123.000000000000000000000000000000000".to_owned(),
+                "123.0000",
+            ),
+            ("1.2300000000000000e1".to_owned(), "12.30000"),
+            (format!("1.{}e1", "0".repeat(40)), "10.00000"),
+            (format!("-1.{}e-5", "0".repeat(40)), "-0.00001000000"),
+        ] {
+            let d = Bitstring32::try_parse(format_args!("{}", f)).expect("failed to parse decimal");
+            assert_eq!(expected, d.to_string(), "{}", f);
+
+            // Only decimals streamed through `try_parse` have their trailing zeroes dropped
+            assert!(Bitstring32::try_parse_str(&f).is_err(), "{}", f);
+            assert!(Bitstring32::parse_with_status(&f).is_err(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn err_decimal_parse_trailing_zeroes() {
+        for f in [
+            "1.23000000000000000000000000000000001",
+            "1.23000000000000000000000000000000001e1",
+            "12345678.0",
+        ] {
+            assert!(Bitstring32::try_parse_str(f).is_err(), "{}", f);
+            assert!(
+                Bitstring32::try_parse(format_args!("{}", f)).is_err(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_coefficient_trailing_zeros() {
+        for (f, expected) in [
+            ("1.200", 2),
+            ("120", 1),
+            ("-1.5e10", 0),
+            ("9999990", 1),
+            ("0.00", 0),
+            ("inf", 0),
+            ("snan(10)", 0),
+        ] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.coefficient_trailing_zeros(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_adjusted_exponent() {
+        for (f, expected) in [
+            ("123.456", Some(2)),
+            ("0.00123", Some(-3)),
+            ("1.200", Some(0)),
+            ("-0.0", Some(-1)),
+            ("inf", None),
+        ] {
+            assert_eq!(
+                expected,
+                Bitstring64::try_parse_str(f)
+                    .expect("failed to parse decimal")
+                    .adjusted_exponent(),
+                "{}",
+                f
+            );
+            assert_eq!(
+                expected,
+                Bitstring::try_parse_str(f)
+                    .expect("failed to parse decimal")
+                    .adjusted_exponent(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_same_quantum() {
+        let parse = |f| Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+        assert!(parse("1.23").same_quantum(&parse("-4.56")));
+        assert!(!parse("1.23").same_quantum(&parse("1.230")));
+        assert!(parse("inf").same_quantum(&parse("-inf")));
+        assert!(parse("nan").same_quantum(&parse("snan")));
+        assert!(!parse("nan").same_quantum(&parse("inf")));
+    }
+
+    #[test]
+    fn decimal_cohort_exponent_range() {
+        for (f, expected) in [
+            ("1.200", Some((-6, -1))),
+            ("1234567", Some((0, 0))),
+            ("1e-101", Some((-101, -101))),
+            ("1000000e90", Some((90, 90))),
+            ("0", Some((-101, 90))),
+            ("-inf", None),
+            ("nan", None),
+        ] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.cohort_exponent_range(), "{}", f);
+        }
+
+        // Wider decimals have a wider range of exponents
+        let d = Bitstring128::try_parse_str("1.200").expect("failed to parse decimal");
+        assert_eq!(Some((-33, -1)), d.cohort_exponent_range());
+    }
+
+    #[test]
+    fn decimal_rescale() {
+        let d = Bitstring64::try_parse_str("1.2").expect("failed to parse decimal");
+
+        assert_eq!(
+            "1.200",
+            d.rescale(-3).expect("failed to rescale").to_string()
+        );
+        assert_eq!("1.2", d.rescale(-1).expect("failed to rescale").to_string());
+        assert!(d.rescale(0).unwrap_err().is_overflow());
+
+        let d = Bitstring::try_parse_str("-0.00").expect("failed to parse decimal");
+        assert_eq!(
+            "-0.0000",
+            d.rescale(-4).expect("failed to rescale").to_string()
+        );
+    }
+
+    #[test]
+    fn decimal_parse_at_scale() {
+        for (f, expected) in [
+            ("1.5", "1.5000"),
+            ("-42", "-42.0000"),
+            ("0.1234", "0.1234"),
+            ("1.23400", "1.2340"),
+            ("0", "0.0000"),
+        ] {
+            let d = Bitstring64::parse_at_scale(f, -4).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.to_string(), "{}", f);
+        }
+
+        // Dynamic decimals grow to fit the padded zeroes
+        let d = Bitstring::parse_at_scale("123456.5", -4).expect("failed to parse decimal");
+        assert_eq!("123456.5000", d.to_string());
+    }
+
+    #[test]
+    fn err_decimal_parse_at_scale() {
+        for f in ["1.23456", "inf", "nan", "", "1.2.3"] {
+            assert!(Bitstring64::parse_at_scale(f, -4).is_err(), "{}", f);
+        }
+
+        // Fixed width decimals don't grow to fit the padded zeroes
+        assert!(Bitstring32::parse_at_scale("123456.5", -4).is_err());
+    }
+
+    #[test]
+    fn decimal_quantize_like() {
+        let price = Bitstring64::try_parse_str("19.99").expect("failed to parse decimal");
+
+        for (f, expected) in [
+            ("3", "3.00"),
+            ("4.5", "4.50"),
+            ("-12.340", "-12.34"),
+            ("0", "0.00"),
+            ("inf", "nan"),
+            ("nan", "nan"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(
+                expected,
+                d.quantize_like(&price)
+                    .expect("failed to quantize")
+                    .to_string(),
+                "{}",
+                f
+            );
+        }
+
+        let d = Bitstring64::try_parse_str("1.005").expect("failed to parse decimal");
+        assert!(d.quantize_like(&price).unwrap_err().is_inexact());
+
+        let d = Bitstring64::try_parse_str("1234567890123456").expect("failed to parse decimal");
+        assert!(d.quantize_like(&price).unwrap_err().is_overflow());
+
+        // Dynamic decimals keep their own width
+        let reference = Bitstring::try_parse_str("0.1").expect("failed to parse decimal");
+        let d = Bitstring::try_parse_str("12345678901234567890").expect("failed to parse decimal");
+
+        assert_eq!(
+            "12345678901234567890.0",
+            d.quantize_like(&reference)
+                .expect("failed to quantize")
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn decimal_quantize() {
+        let d = Bitstring64::try_parse_str("1.5").expect("failed to parse decimal");
+        assert_eq!(
+            "1.50",
+            d.quantize(-2).expect("failed to quantize").to_string()
+        );
+
+        let err = Bitstring64::try_parse_str("1.234")
+            .expect("failed to parse decimal")
+            .quantize(-2)
+            .unwrap_err();
+        assert!(err.is_inexact());
+        assert!(!err.is_overflow());
+        assert_eq!(
+            "conversion to `decimal` with an exponent of `-2` would require rounding",
+            err.to_string()
+        );
+
+        let err = Bitstring32::try_parse_str("1.5")
+            .expect("failed to parse decimal")
+            .quantize(-7)
+            .unwrap_err();
+        assert!(err.is_overflow());
+        assert!(!err.is_inexact());
+    }
+
+    #[test]
+    fn decimal_rescale_all() {
+        let mut prices = ["1", "2.5", "3.750", "0"]
+            .map(|f| Bitstring64::try_parse_str(f).expect("failed to parse decimal"));
+
+        Bitstring64::rescale_all(&mut prices, -2).expect("failed to rescale");
+
+        assert_eq!(
+            ["1.00", "2.50", "3.75", "0.00"],
+            prices.map(|d| d.to_string())
+        );
+
+        let mut prices = ["1", "2.555", "3.75", "inf"]
+            .map(|f| Bitstring64::try_parse_str(f).expect("failed to parse decimal"));
+
+        let err = Bitstring64::rescale_all(&mut prices, -2).unwrap_err();
+
+        assert_eq!(Some(1), err.index());
+        assert!(err.to_string().ends_with("(at index `1`)"), "{}", err);
+
+        // Decimals before the error are rescaled, and the rest are left as they were
+        assert_eq!(
+            ["1.00", "2.555", "3.75", "inf"],
+            prices.map(|d| d.to_string())
+        );
+
+        // Errors that didn't come from a slice don't have an index
+        assert_eq!(
+            None,
+            Bitstring32::try_parse_str("1.5")
+                .unwrap()
+                .rescale(0)
+                .unwrap_err()
+                .index()
+        );
+    }
+
+    #[test]
+    fn decimal_reduce() {
+        for (f, expected) in [
+            ("1.2300", "1.23"),
+            ("1.00", "1"),
+            ("100", "1e2"),
+            ("-0.000", "-0"),
+            ("inf", "inf"),
+            ("nan", "nan"),
+        ] {
+            assert_eq!(
+                expected,
+                Bitstring64::try_parse_str(f)
+                    .expect("failed to parse decimal")
+                    .reduce()
+                    .to_string(),
+                "{}",
+                f
+            );
+
+            assert_eq!(
+                expected,
+                Bitstring::try_parse_str(f)
+                    .expect("failed to parse decimal")
+                    .reduce()
+                    .to_string(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_divmod_pow10() {
+        for (f, n, quotient, remainder) in [
+            ("12345", 2, "123", "45"),
+            ("-1234.567", 0, "-1234", "-0.567"),
+            ("9.999999e90", 90, "9", "9.99999e89"),
+        ] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+            let (q, r) = d.divmod_pow10(n).expect("failed to split decimal");
+
+            assert_eq!(quotient, q.to_string(), "{}", f);
+            assert_eq!(remainder, r.to_string(), "{}", f);
+        }
+
+        // The quotient is too large to encode
+        let d = Bitstring32::try_parse_str("1e90").expect("failed to parse decimal");
+        assert!(d.divmod_pow10(-1).is_none());
+
+        // Dynamic decimals grow to fit the quotient instead
+        let d = Bitstring::try_parse_str("1e90").expect("failed to parse decimal");
+        let (q, _) = d.divmod_pow10(-1).expect("failed to split decimal");
+        assert_eq!("1e91", q.to_string());
+
+        // Splitting by extreme powers of ten doesn't overflow the exponent
+        let d = Bitstring32::try_parse_str("1e90").expect("failed to parse decimal");
+        assert!(d.divmod_pow10(i32::MIN).is_none());
+        assert!(d.divmod_pow10(i32::MIN + 5).is_none());
+
+        let (q, r) = d.divmod_pow10(i32::MAX).expect("failed to split decimal");
+        assert_eq!("0", q.to_string());
+        assert_eq!("1e90", r.to_string());
+
+        #[cfg(feature = "arbitrary-precision")]
+        {
+            let d = BigBitstring::try_parse_str("1e90").expect("failed to parse decimal");
+            let (q, _) = d.divmod_pow10(i32::MIN).expect("failed to split decimal");
+            assert_eq!("1e2147483738", q.to_string());
+        }
+
+        assert!(Bitstring32::try_parse_str("inf")
+            .expect("failed to parse decimal")
+            .divmod_pow10(0)
+            .is_none());
+    }
+
+    #[test]
+    fn decimal_ulp() {
+        for (f, expected) in [
+            ("1.23", "0.01"),
+            ("1.2300", "0.0001"),
+            ("-1.23", "0.01"),
+            ("1.2e5", "1e4"),
+            ("120000", "1"),
+            ("0", "1e-398"),
+            ("-inf", "inf"),
+            ("snan(1)", "nan"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.ulp().to_string(), "{}", f);
+        }
+
+        // Every decimal in a cohort has the same value, but a different unit in the last place
+        let d = Bitstring32::try_parse_str("1e2").expect("failed to parse decimal");
+        assert_eq!("1e2", d.ulp().to_string());
+
+        let d = Bitstring32::try_parse_str("100").expect("failed to parse decimal");
+        assert_eq!("1", d.ulp().to_string());
+
+        let d = Bitstring::try_parse_str("1.5e-6000").expect("failed to parse decimal");
+        assert_eq!("1e-6001", d.ulp().to_string());
+    }
+
+    #[test]
+    fn decimal_next_up_down() {
+        for (f, expected_up, expected_down) in [
+            ("1", "1.000000000000001", "0.9999999999999999"),
+            ("-1", "-0.9999999999999999", "-1.000000000000001"),
+            ("0", "1e-398", "-1e-398"),
+            ("-0", "1e-398", "-1e-398"),
+            ("1e-398", "2e-398", "0e-398"),
+            ("inf", "inf", "9.999999999999999e384"),
+            ("-inf", "-9.999999999999999e384", "-inf"),
+            ("9.999999999999999e384", "inf", "9.999999999999998e384"),
+            ("nan(1)", "nan(1)", "nan(1)"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected_up, d.next_up().to_string(), "{}", f);
+            assert_eq!(expected_down, d.next_down().to_string(), "{}", f);
+        }
+
+        assert_eq!(Bitstring64::MIN_POSITIVE, Bitstring64::ZERO.next_up());
+        assert_eq!("0e-398", Bitstring64::MIN_POSITIVE.next_down().to_string());
+
+        assert!(Bitstring64::MAX.next_up() > Bitstring64::MAX);
+        assert!(Bitstring64::MAX.next_down() < Bitstring64::MAX);
+        assert_eq!(Bitstring64::MAX, Bitstring64::MAX.next_up().next_down());
+        assert_eq!(Bitstring64::MIN, Bitstring64::MIN.next_down().next_up());
+
+        assert!(Bitstring64::INFINITY.next_down().next_up().is_infinite());
+        assert!(Bitstring64::NEG_INFINITY
+            .next_up()
+            .next_down()
+            .is_infinite());
+        assert!(Bitstring64::NEG_INFINITY.next_up().is_finite());
+
+        // Dynamically sized decimals step within their current width
+        for (f, expected_up, expected_down) in [
+            ("1", "1.000001", "0.9999999"),
+            ("0", "1e-101", "-1e-101"),
+            ("9.999999e96", "inf", "9.999998e96"),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(4, d.as_le_bytes().len(), "{}", f);
+
+            let up = d.next_up();
+            let down = d.next_down();
+
+            assert_eq!(expected_up, up.to_string(), "{}", f);
+            assert_eq!(expected_down, down.to_string(), "{}", f);
+
+            assert_eq!(4, up.as_le_bytes().len(), "{}", f);
+            assert_eq!(4, down.as_le_bytes().len(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_saturating_scaleb() {
+        for (f, n, expected) in [
+            ("1.5", 2, "1.5e2"),
+            ("-1.5", -2, "-0.015"),
+            ("1e90", 1, "1.0e91"),
+            ("-1e90", 5, "-1.00000e95"),
+            ("1e-101", 0, "1e-101"),
+            ("1.000e-98", -3, "1e-101"),
+            ("inf", i32::MIN, "inf"),
+            ("nan(42)", i32::MAX, "nan(42)"),
+        ] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.saturating_scaleb(n).to_string(), "{}", f);
+        }
+
+        let d = Bitstring32::try_parse_str("1e90").expect("failed to parse decimal");
+        assert_eq!(
+            Bitstring32::max().as_le_bytes(),
+            d.saturating_scaleb(7).as_le_bytes()
+        );
+        assert_eq!(
+            Bitstring32::min().as_le_bytes(),
+            (-d).saturating_scaleb(i32::MAX).as_le_bytes()
+        );
+
+        let d = Bitstring32::try_parse_str("1.5e-100").expect("failed to parse decimal");
+        assert_eq!(
+            Bitstring32::zero().as_le_bytes(),
+            d.saturating_scaleb(-1).as_le_bytes()
+        );
+
+        // Zeroes have their exponent clamped instead of saturating
+        for (f, n, expected) in [
+            ("0", 1000, "0e90"),
+            ("0", -1000, "0e-101"),
+            ("-0", i32::MAX, "-0e90"),
+            ("-0", i32::MIN, "-0e-101"),
+            ("0e89", 1, "0e90"),
+            ("0.00", 2, "0"),
+        ] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.saturating_scaleb(n).to_string(), "{} {}", f, n);
+        }
+        assert_eq!(
+            (-Bitstring32::zero()).as_le_bytes(),
+            (-d).saturating_scaleb(i32::MIN).as_le_bytes()
+        );
+    }
+
+    #[test]
+    fn decimal_to_dynamic_roundtrip() {
+        for f in ["0", "-123.456", "1e-101", "inf", "-snan(42)"] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+            let dynamic = d.to_dynamic();
+
+            assert_eq!(d.as_le_bytes(), dynamic.as_le_bytes(), "{}", f);
+            assert_eq!(
+                d.as_le_bytes(),
+                Bitstring32::try_from(Bitstring::from(d))
+                    .expect("failed to convert decimal")
+                    .as_le_bytes(),
+                "{}",
+                f
+            );
+        }
+
+        for f in ["0", "-1234567890.123456", "1e-398", "inf", "-snan(42)"] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+            let dynamic = d.to_dynamic();
+
+            assert_eq!(d.as_le_bytes(), dynamic.as_le_bytes(), "{}", f);
+            assert_eq!(
+                d.as_le_bytes(),
+                Bitstring64::try_from(Bitstring::from(d))
+                    .expect("failed to convert decimal")
+                    .as_le_bytes(),
+                "{}",
+                f
+            );
+        }
+
+        for f in [
+            "0",
+            "-1234567890123456789.012345678901234",
+            "1e-6176",
+            "inf",
+            "-snan(42)",
+        ] {
+            let d = Bitstring128::try_parse_str(f).expect("failed to parse decimal");
+            let dynamic = d.to_dynamic();
+
+            assert_eq!(d.as_le_bytes(), dynamic.as_le_bytes(), "{}", f);
+            assert_eq!(
+                d.as_le_bytes(),
+                Bitstring128::try_from(Bitstring::from(d))
+                    .expect("failed to convert decimal")
+                    .as_le_bytes(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn err_decimal_from_dynamic_width_mismatch() {
+        let d = Bitstring::try_parse_str("1.5").expect("failed to parse decimal");
+        assert_eq!(4, d.as_le_bytes().len());
+
+        assert!(Bitstring32::try_from(d).is_ok());
+        assert!(Bitstring64::try_from(d).is_err());
+        assert!(Bitstring128::try_from(d).is_err());
+
+        let d = Bitstring::try_parse_str("1e1000").expect("failed to parse decimal");
+
+        assert!(Bitstring32::try_from(d).is_err());
+        assert!(Bitstring64::try_from(d).is_err());
+        assert!(Bitstring128::try_from(d)
+            .expect_err("expected conversion to fail")
+            .is_overflow());
+    }
+
+    #[test]
+    fn decimal_try_fit() {
+        for (f, expected) in [
+            ("1.2300", "1.2300"),
+            ("1.230000000000", "1.230000"),
+            ("-123456.7000000", "-123456.7"),
+            ("1.00e-100", "1.0e-100"),
+            ("-inf", "-inf"),
+            ("snan(42)", "snan(42)"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+            let fit: Bitstring32 = d.try_fit().expect("failed to fit decimal");
+
+            assert_eq!(expected, fit.to_string(), "{}", f);
+        }
+
+        // Wider decimals keep their cohort
+        let d = Bitstring32::try_parse_str("1.200").expect("failed to parse decimal");
+        assert_eq!("1.200", d.try_fit::<Bitstring128>().unwrap().to_string());
+        assert_eq!("1.200", d.try_fit::<Bitstring>().unwrap().to_string());
+
+        // Decimals that are wider than the target fit into its widest width
+        #[cfg(feature = "arbitrary-precision")]
+        {
+            let d = BigBitstring::try_parse_str(&format!("1234567890123{}", "0".repeat(60)))
+                .expect("failed to parse decimal");
+
+            let expected =
+                Bitstring::try_parse_str("1234567890123e60").expect("failed to parse decimal");
+
+            let fit = d.try_fit::<Bitstring>().expect("failed to fit decimal");
+            assert_eq!(expected, fit);
+            assert_eq!(32, fit.as_le_bytes().len());
+
+            let fit = d.try_fit::<Bitstring128>().expect("failed to fit decimal");
+            assert_eq!(
+                Bitstring128::try_parse_str("1234567890123e60").unwrap(),
+                fit
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_write_to() {
+        use core::fmt::Write;
+
+        let mut out = String::new();
+
+        for f in ["1.5", "-0.00", "1e-101", "inf", "-nan(42)"] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+
+            d.write_to(&mut out).expect("failed to write decimal");
+            out.push(',');
+
+            // The text is the same as the `Display` implementation
+            let mut expected = String::new();
+            write!(&mut expected, "{}", d).expect("failed to format decimal");
+
+            let mut written = String::new();
+            d.write_to(&mut written).expect("failed to write decimal");
+
+            assert_eq!(expected, written, "{}", f);
+        }
+
+        assert_eq!("1.5,-0.00,1e-101,inf,-nan(42),", out);
+
+        // A writer that fails is reported
+        struct Failing;
+
+        impl Write for Failing {
+            fn write_str(&mut self, _: &str) -> core::fmt::Result {
+                Err(core::fmt::Error)
+            }
+        }
+
+        assert!(Bitstring64::ONE.write_to(&mut Failing).is_err());
+    }
+
+    #[test]
+    fn decimal_coefficient_display() {
+        for (f, expected, expected_with_zeroes) in [
+            ("12.34", "1234", "0000000000001234"),
+            ("-1.5e3", "15", "0000000000000015"),
+            ("1.50", "150", "0000000000000150"),
+            ("0.05", "5", "0000000000000005"),
+            ("0", "0", "0000000000000000"),
+            ("-0.000", "0", "0000000000000000"),
+            ("9999999999999999", "9999999999999999", "9999999999999999"),
+            ("1000000000000000e5", "1000000000000000", "1000000000000000"),
+            ("inf", "0", "000000000000000"),
+            ("nan(123)", "123", "000000000000123"),
+            ("-snan", "0", "000000000000000"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.coefficient_display(false).to_string(), "{}", f);
+            assert_eq!(
+                expected_with_zeroes,
+                d.coefficient_display(true).to_string(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_sign_bit() {
+        for f in [
+            "0",
+            "-0",
+            "1.5",
+            "-1.5",
+            "inf",
+            "-inf",
+            "nan",
+            "-snan(42)",
+            "1e-101",
+        ] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(d.is_sign_negative(), d.sign_bit(), "{}", f);
+
+            // Flipping the sign bit is the same as negating
+            let neg = -d;
+            assert_eq!(
+                neg.as_le_bytes(),
+                d.with_sign_bit(!d.sign_bit()).as_le_bytes(),
+                "{}",
+                f
+            );
+
+            // Setting the sign bit to what it already is doesn't change anything
+            assert_eq!(
+                d.as_le_bytes(),
+                d.with_sign_bit(d.sign_bit()).as_le_bytes(),
+                "{}",
+                f
+            );
+
+            // Only the sign bit is changed
+            let positive = d.with_sign_bit(false);
+            let negative = d.with_sign_bit(true);
+
+            assert!(!positive.sign_bit(), "{}", f);
+            assert!(negative.sign_bit(), "{}", f);
+            assert_eq!(
+                positive.as_le_bytes()[..3],
+                negative.as_le_bytes()[..3],
+                "{}",
+                f
+            );
+            assert_eq!(
+                positive.as_le_bytes()[3] | 0x80,
+                negative.as_le_bytes()[3],
+                "{}",
+                f
+            );
+        }
+
+        let d = Bitstring::try_parse_str("-1.5e-30").expect("failed to parse decimal");
+        assert_eq!("1.5e-30", d.with_sign_bit(false).to_string());
+    }
+
+    #[test]
+    fn decimal_subnormal() {
+        assert_eq!("1e-101", Bitstring32::subnormal(6).to_string());
+        assert_eq!("1.00000e-96", Bitstring32::subnormal(1).to_string());
+        assert_eq!("1.00e-99", Bitstring32::subnormal(4).to_string());
+        assert_eq!("1e-398", Bitstring64::subnormal(15).to_string());
+        assert_eq!(
+            "1.00000000000000e-384",
+            Bitstring64::subnormal(1).to_string()
+        );
+        assert_eq!("1e-6176", Bitstring128::subnormal(33).to_string());
+        assert_eq!(
+            "1.00000000000000000000000000000000e-6144",
+            Bitstring128::subnormal(1).to_string()
+        );
+
+        assert_eq!(
+            Bitstring64::min_positive().as_le_bytes(),
+            Bitstring64::subnormal(15).as_le_bytes()
+        );
+
+        // Each leading zero makes the subnormal 10 times smaller
+        for leading_zeros in 1..15 {
+            let d = Bitstring64::subnormal(leading_zeros);
+            let smaller = Bitstring64::subnormal(leading_zeros + 1);
+
+            assert_eq!(
+                Some(core::cmp::Ordering::Equal),
+                d.cmp_value(&smaller.saturating_scaleb(1))
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_precision_exponent_range() {
+        let d = Bitstring32::zero();
+        assert_eq!(Bitstring32::DIGITS, d.precision());
+        assert_eq!(96, d.max_exponent());
+        assert_eq!(-95, d.min_exponent());
+
+        let d = Bitstring64::zero();
+        assert_eq!(Bitstring64::DIGITS, d.precision());
+        assert_eq!(384, d.max_exponent());
+        assert_eq!(-383, d.min_exponent());
+
+        let d = Bitstring128::zero();
+        assert_eq!(Bitstring128::DIGITS, d.precision());
+        assert_eq!(6144, d.max_exponent());
+        assert_eq!(-6143, d.min_exponent());
+
+        let d = Bitstring256::zero();
+        assert_eq!(Bitstring256::DIGITS, d.precision());
+        assert_eq!(1572864, d.max_exponent());
+        assert_eq!(-1572863, d.min_exponent());
+
+        // The exponent of the coefficient is bounded by the adjusted exponent range
+        let d = Bitstring64::zero();
+        assert_eq!(
+            Bitstring64::MAX_10_EXP,
+            d.max_exponent() - d.precision() as i32 + 1
+        );
+
+        // Dynamically sized decimals depend on their width
+        for (f, precision, max_exponent) in
+            [("1", 7, 96), ("1e100", 16, 384), ("1e-6000", 34, 6144)]
+        {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(precision, d.precision(), "{}", f);
+            assert_eq!(max_exponent, d.max_exponent(), "{}", f);
+            assert_eq!(1 - max_exponent, d.min_exponent(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_is_zero() {
+        for f in [
+            "0",
+            "-0",
+            "0.00",
+            "0e5",
+            "-0e-6176",
+            "0.000000000000000000000000000000000000000",
+        ] {
+            assert!(
+                Bitstring::try_parse_str(f)
+                    .expect("failed to parse decimal")
+                    .is_zero(),
+                "{}",
+                f
+            );
+        }
+
+        for f in ["inf", "-inf", "nan", "snan", "1e-400", "-0.001", "1e10"] {
+            assert!(
+                !Bitstring::try_parse_str(f)
+                    .expect("failed to parse decimal")
+                    .is_zero(),
+                "{}",
+                f
+            );
+        }
+
+        assert!(Bitstring32::ZERO.is_zero());
+        assert!(!Bitstring64::min_positive().is_zero());
+        assert!(!Bitstring128::NAN.is_zero());
+    }
+
+    #[test]
+    fn decimal_classify() {
+        for (f, expected) in [
+            ("0", DecimalClass::Zero),
+            ("-0", DecimalClass::Zero),
+            ("0e-101", DecimalClass::Zero),
+            ("-0.000e90", DecimalClass::Zero),
+            ("1", DecimalClass::Normal),
+            ("-123.45", DecimalClass::Normal),
+            ("1e-95", DecimalClass::Normal),
+            ("1.000000e-95", DecimalClass::Normal),
+            ("9.999999e96", DecimalClass::Normal),
+            ("0.9e-95", DecimalClass::Subnormal),
+            ("-1e-101", DecimalClass::Subnormal),
+            ("1.00000e-96", DecimalClass::Subnormal),
+            ("inf", DecimalClass::Infinite),
+            ("-inf", DecimalClass::Infinite),
+            ("nan", DecimalClass::QuietNan),
+            ("-nan(12)", DecimalClass::QuietNan),
+            ("snan", DecimalClass::SignalingNan),
+            ("-snan(3)", DecimalClass::SignalingNan),
+        ] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.classify(), "{}", f);
+        }
+
+        assert_eq!(
+            DecimalClass::Subnormal,
+            Bitstring64::min_positive().classify()
+        );
+        assert_eq!(
+            DecimalClass::Subnormal,
+            Bitstring128::subnormal(1).classify()
+        );
+        assert_eq!(DecimalClass::Normal, Bitstring128::MAX.classify());
+        assert_eq!(
+            DecimalClass::Subnormal,
+            Bitstring::try_parse_str("1e-398")
+                .expect("failed to parse decimal")
+                .classify()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn err_decimal_subnormal_out_of_range() {
+        let _ = Bitstring32::subnormal(7);
+    }
+
+    #[test]
+    fn decimal_cmp_value() {
+        use core::cmp::Ordering;
+
+        for (a, b, expected) in [
+            ("1.5", "1.50", Some(Ordering::Equal)),
+            ("0", "-0.000", Some(Ordering::Equal)),
+            ("1e90", "1e91", Some(Ordering::Less)),
+            ("-1e-101", "-1e-398", Some(Ordering::Less)),
+            ("9999999", "9999999.000000001", Some(Ordering::Less)),
+            ("inf", "9999999999999999e369", Some(Ordering::Greater)),
+            ("-inf", "-inf", Some(Ordering::Equal)),
+            ("nan", "1", None),
+            ("1", "snan", None),
+        ] {
+            let a = Bitstring32::try_parse_str(a).expect("failed to parse decimal");
+            let b = Bitstring64::try_parse_str(b).expect("failed to parse decimal");
+
+            assert_eq!(expected, a.cmp_value(&b), "{} cmp {}", a, b);
+            assert_eq!(
+                expected.map(Ordering::reverse),
+                b.cmp_value(&a),
+                "{} cmp {}",
+                b,
+                a
+            );
+        }
+
+        // Decimals of the same width can be compared too
+        let a = Bitstring::try_parse_str("1.0").expect("failed to parse decimal");
+        let b =
+            Bitstring::try_parse_str("1.00000000000000000001").expect("failed to parse decimal");
+
+        assert_eq!(Some(Ordering::Less), a.cmp_value(&b));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary-precision")]
+    fn bigdecimal_formatted() {
+        let mut f = String::from("-1.");
+        f.extend(core::iter::repeat_n('7', 2000));
+        f.push_str("e-40000");
+
+        let d = BigBitstring::try_parse_str(&f).expect("failed to parse decimal");
+        let formatted = d.formatted();
+
+        // Displaying the value more than once gives the same text each time
+        assert_eq!(d.to_string(), formatted.to_string());
+        assert_eq!(d.to_string(), formatted.to_string());
+        assert_eq!(format!("[{}]", d), format!("[{}]", formatted));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary-precision")]
+    fn bigdecimal_rescale_all() {
+        let mut values =
+            ["1.5", "20"].map(|f| BigBitstring::try_parse_str(f).expect("failed to parse decimal"));
+
+        BigBitstring::rescale_all(&mut values, -1).expect("failed to rescale");
+
+        assert_eq!(["1.5", "20.0"], values.map(|d| d.to_string()));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary-precision")]
+    fn bigdecimal_cmp_value() {
+        use core::cmp::Ordering;
+
+        for (a, b, expected) in [
+            ("1e30000000000", "1e30000000000", Ordering::Equal),
+            ("1e30000000000", "1e30000000001", Ordering::Less),
+            ("1e30000000000", "1e-30000000000", Ordering::Greater),
+            ("1e-30000000000", "1e-30000000001", Ordering::Greater),
+            ("-1e30000000000", "-1e29999999999", Ordering::Less),
+            ("1e30000000000", "1e3000000000", Ordering::Greater),
+            ("100e29999999998", "1e30000000000", Ordering::Equal),
+        ] {
+            let a = BigBitstring::try_parse_str(a).expect("failed to parse decimal");
+            let b = BigBitstring::try_parse_str(b).expect("failed to parse decimal");
+
+            assert_eq!(Some(expected), a.cmp_value(&b), "{} cmp {}", a, b);
+            assert_eq!(Some(expected.reverse()), b.cmp_value(&a), "{} cmp {}", b, a);
+        }
+
+        let a = BigBitstring::try_parse_str("1e30000000000").expect("failed to parse decimal");
+        let b = Bitstring128::try_parse_str("9.990000000000000000000000000000000e6144")
+            .expect("failed to parse decimal");
+
+        assert_eq!(Some(Ordering::Greater), a.cmp_value(&b));
+        assert_eq!(Some(Ordering::Less), b.cmp_value(&a));
+    }
+
+    #[test]
+    fn err_decimal_try_fit() {
+        for f in [
+            "12345678",
+            "1.2345678000",
+            "1e97",
+            "1.5e-101",
+            "nan(1234567)",
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert!(d.try_fit::<Bitstring32>().is_err(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_try_from_narrower() {
+        for (f, expected) in [
+            ("1.5", "1.5"),
+            ("-1.230000000000000000000000000000000", "-1.230000000000000"),
+            ("9999999999999999e369", "9999999999999999e369"),
+            ("1e-398", "1e-398"),
+            ("1.000000000000000e-383", "1.000000000000000e-383"),
+            ("-inf", "-inf"),
+            ("-snan(123456789012345)", "-snan(123456789012345)"),
+        ] {
+            let d = Bitstring128::try_parse_str(f).expect("failed to parse decimal");
+            let narrow = Bitstring64::try_from(d).expect("failed to narrow decimal");
+            let expected = Bitstring64::try_parse_str(expected).expect("failed to parse decimal");
+
+            assert_eq!(expected.as_le_bytes(), narrow.as_le_bytes(), "{}", f);
+        }
+
+        for f in [
+            "1234567890123456789012345678901234",
+            "1e385",
+            "9999999999999999e370",
+            "1e-399",
+            "1.5e-398",
+            "nan(1234567890123456)",
+        ] {
+            let d = Bitstring128::try_parse_str(f).expect("failed to parse decimal");
+
+            assert!(Bitstring64::try_from(d).is_err(), "{}", f);
+        }
+
+        let d = Bitstring128::try_parse_str("1e96").expect("failed to parse decimal");
+        assert_eq!(
+            Bitstring32::try_parse_str("1000000e90")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            Bitstring32::try_from(d)
+                .expect("failed to narrow decimal")
+                .as_le_bytes()
+        );
+
+        let d = Bitstring64::try_parse_str("1e97").expect("failed to parse decimal");
+        assert!(Bitstring32::try_from(d).is_err());
+    }
+
+    #[test]
+    fn decimal_parse_tagged() {
+        for (f, expected, width) in [
+            ("1.5", "1.5", 32),
+            ("1.5d32", "1.5", 32),
+            ("1.5d64", "1.5", 64),
+            ("1.5D128", "1.5", 128),
+            ("-123.456e7d64", "-1.23456e9", 64),
+            ("1e91", "1e91", 64),
+            ("infd64", "inf", 64),
+            ("-nan(42)d128", "-nan(42)", 128),
+        ] {
+            let d = Bitstring::parse_tagged(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d.to_string(), "{}", f);
+            assert_eq!(width, d.as_le_bytes().len() * 8, "{}", f);
+        }
+    }
+
+    #[test]
+    fn err_decimal_parse_tagged() {
+        for f in [
+            "12345678d32",
+            "1e91d32",
+            "1.2300000000000000d32",
+            "1e97d32",
+            "1.5d",
+            "1.5d96",
+            "1.5d64d64",
+            "d64",
+        ] {
+            assert!(Bitstring::parse_tagged(f).is_err(), "{}", f);
+        }
+
+        // The value is larger than the tagged width can fit
+        let err = Bitstring::parse_tagged("1.5e6145d128").unwrap_err();
+        assert!(err.is_overflow());
+
+        // The suffix isn't a supported width
+        for f in ["1.5d16", "1.5d", "1.5D", "1.5d0032", "infd512"] {
+            let err = Bitstring::parse_tagged(f).unwrap_err();
+
+            assert!(err.is_parse(), "{}", f);
+            assert_eq!(
+                "unknown width suffix, expected a width of `32`, `64`, `128`, or `256`",
+                err.to_string(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_parse_tagged_matches_fixed_width() {
+        for f in [
+            "1.5",
+            "-123.456e7",
+            "1e-1572932",
+            "9999999999999999999999999999999999999999999999999999999999999999999999e1572795",
+            "-nan(42)",
+        ] {
+            let tagged =
+                Bitstring::parse_tagged(&format!("{}d256", f)).expect("failed to parse decimal");
+            let fixed = Bitstring256::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(fixed.as_le_bytes(), tagged.as_le_bytes(), "{}", f);
+        }
+
+        for f in ["1e1572796", "1e-1572933"] {
+            assert!(
+                Bitstring::parse_tagged(&format!("{}d256", f)).is_err(),
+                "{}",
+                f
+            );
+            assert!(Bitstring256::try_parse_str(f).is_err(), "{}", f);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary-precision")]
+    fn bigdecimal_roundtrip_large_biased_exponent() {
+        // These decimals have a biased exponent that fills its most significant byte
+        for f in ["1e28", "9413835e56", "-1e90", "1e-6", "1.5e7000"] {
+            let d = BigBitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(
+                Bitstring::try_parse_str(f)
+                    .expect("failed to parse decimal")
+                    .to_string(),
+                d.to_string(),
+                "{}",
+                f
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary-precision")]
+    fn bigdecimal_parse_tagged() {
+        let d = BigBitstring::parse_tagged("1.5d256").expect("failed to parse decimal");
+
+        assert_eq!("1.5", d.to_string());
+        assert_eq!(256, d.as_le_bytes().len() * 8);
+    }
+
+    #[test]
+    fn decimal_map_nan_payload() {
+        let d = Bitstring64::try_parse_str("-snan(41)").expect("failed to parse decimal");
+        assert_eq!("-snan(42)", d.map_nan_payload(|p| p + 1).to_string());
+
+        let d = Bitstring32::try_parse_str("nan").expect("failed to parse decimal");
+        assert_eq!("nan(999999)", d.map_nan_payload(|_| 1_000_000).to_string());
+
+        let d = Bitstring128::try_parse_str("1.5").expect("failed to parse decimal");
+        assert_eq!("1.5", d.map_nan_payload(|p| p + 1).to_string());
+    }
+
+    #[test]
+    fn decimal_to_quiet_signaling() {
+        for (f, quiet, signaling) in [
+            ("snan(5)", "nan(5)", "snan(5)"),
+            ("nan(5)", "nan(5)", "snan(5)"),
+            ("-snan", "-nan", "-snan"),
+            ("-nan(123)", "-nan(123)", "-snan(123)"),
+            ("1.5", "1.5", "1.5"),
+            ("-0", "-0", "-0"),
+            ("inf", "inf", "inf"),
+        ] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(quiet, d.to_quiet().to_string(), "{}", f);
+            assert_eq!(signaling, d.to_signaling().to_string(), "{}", f);
+        }
+
+        let d = Bitstring128::try_parse_str("-snan(42)").expect("failed to parse decimal");
+
+        assert!(d.to_quiet().is_quiet_nan());
+        assert_eq!(d.as_le_bytes(), d.to_quiet().to_signaling().as_le_bytes());
+    }
+
+    #[test]
+    fn decimal_coefficient_and_exponent() {
+        for (f, expected_negative, expected_digits, expected_exponent) in [
+            ("123.456", false, "123456", -3),
+            ("-123.456", true, "123456", -3),
+            ("1.200", false, "1200", -3),
+            ("12e5", false, "12", 5),
+            ("0", false, "0", 0),
+            ("-0.00", true, "0", -2),
+            (
+                "9999999999999999999999999999999999",
+                false,
+                "9999999999999999999999999999999999",
+                0,
+            ),
+            ("1e-6176", false, "1", -6176),
+        ] {
+            let d = Bitstring128::try_parse_str(f).expect("failed to parse decimal");
+
+            let (is_negative, digits, exponent) = d
+                .coefficient_and_exponent()
+                .expect("failed to decompose decimal");
+
+            assert_eq!(expected_digits.len(), digits.len(), "{}", f);
+            assert_eq!(expected_negative, is_negative, "{}", f);
+            assert_eq!(
+                expected_digits,
+                digits.clone().map(char::from).collect::<String>(),
+                "{}",
+                f
+            );
+            assert_eq!(expected_exponent, exponent, "{}", f);
+
+            let roundtrip = Bitstring128::from_digits(is_negative, digits, exponent)
+                .expect("failed to encode decimal");
+
+            assert_eq!(d.as_le_bytes(), roundtrip.as_le_bytes(), "{}", f);
+        }
+
+        for f in ["inf", "-inf", "nan", "snan(1)"] {
+            let d = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+
+            assert!(d.coefficient_and_exponent().is_none(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_from_coefficient_exponent() {
+        for (is_negative, coefficient, exponent, expected) in [
+            (true, 123456, -3, "-123.456"),
+            (false, 1200, -2, "12.00"),
+            (false, 0, 0, "0"),
+            (true, 0, -2, "-0.00"),
+            (false, 9999999999999999, 369, "9999999999999999e369"),
+            (false, 1, -398, "1e-398"),
+        ] {
+            let d = Bitstring64::from_coefficient_exponent(is_negative, coefficient, exponent)
+                .expect("failed to encode decimal");
+            let expected = Bitstring64::try_parse_str(expected).expect("failed to parse decimal");
+
+            assert_eq!(expected.as_le_bytes(), d.as_le_bytes(), "{:?}", expected);
+        }
+
+        for (coefficient, exponent) in [(12345678901234567, 0), (1, 370), (1, -399), (u128::MAX, 0)]
+        {
+            assert!(
+                Bitstring64::from_coefficient_exponent(false, coefficient, exponent).is_err(),
+                "{}e{}",
+                coefficient,
+                exponent
+            );
+        }
+
+        let d =
+            Bitstring128::from_coefficient_exponent(false, 9999999999999999999999999999999999, -10)
+                .expect("failed to encode decimal");
+        assert_eq!("999999999999999999999999.9999999999", d.to_string());
+
+        assert!(Bitstring128::from_coefficient_exponent(false, u128::MAX, 0).is_err());
+
+        // A decimal can be rebuilt from its decomposed parts
+        let d = Bitstring::try_parse_str("-1.500e-42").expect("failed to parse decimal");
+        let (is_negative, digits, exponent) = d
+            .coefficient_and_exponent()
+            .expect("failed to decompose decimal");
+        let coefficient = digits.fold(0u128, |c, digit| c * 10 + (digit - b'0') as u128);
+
+        assert_eq!(
+            d.as_le_bytes(),
+            Bitstring::from_coefficient_exponent(is_negative, coefficient, exponent)
+                .expect("failed to encode decimal")
+                .as_le_bytes()
+        );
+    }
+
+    #[test]
+    fn decimal_from_digits() {
+        for (is_negative, digits, exponent, expected) in [
+            (false, "12345", 0, "12345"),
+            (true, "12345", -2, "-123.45"),
+            (false, "12345", 2, "1.2345e6"),
+            (false, "0000012", 0, "12"),
+            (false, "000", -1, "0.0"),
+            (true, "0", 0, "-0"),
+            (false, "123456", 85, "1.23456e90"),
+        ] {
+            let d = Bitstring32::from_digits(is_negative, digits.bytes(), exponent)
+                .expect("failed to encode decimal");
+
+            assert_eq!(expected, d.to_string(), "{}e{}", digits, exponent);
+        }
+    }
+
+    #[test]
+    fn decimal_from_digits_roundtrip() {
+        for f in [
+            "-123.456",
+            "1.5e-30",
+            "0.000",
+            "98765432101234567890",
+            "1.0e42",
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            // Pull the digits and exponent back out of the decimal and feed them through `from_digits`
+            let s = d.to_string();
+
+            let (significand, exponent) = match s.split_once('e') {
+                Some((significand, exponent)) => {
+                    (significand, exponent.parse().expect("invalid exponent"))
+                }
+                None => (&*s, 0),
+            };
+
+            let fractional_digits = significand
+                .split_once('.')
+                .map(|(_, fractional)| fractional.len() as i32)
+                .unwrap_or(0);
+
+            let roundtrip = Bitstring::from_digits(
+                d.is_sign_negative(),
+                significand.bytes().filter(u8::is_ascii_digit),
+                exponent - fractional_digits,
+            )
+            .expect("failed to encode decimal");
+
+            assert_eq!(d.as_le_bytes(), roundtrip.as_le_bytes(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn err_decimal_from_digits() {
+        assert!(Bitstring32::from_digits(false, "12345678".bytes(), 0).is_err());
+        assert!(Bitstring32::from_digits(false, "1".bytes(), 97).is_err());
+        assert!(Bitstring32::from_digits(false, "1.5".bytes(), 0).is_err());
+        assert!(Bitstring32::from_digits(false, "-15".bytes(), 0).is_err());
+        assert!(Bitstring32::from_digits(false, "".bytes(), 0).is_err());
+
+        // An endless stream of digits fails without consuming all of them
+        assert!(Bitstring32::from_digits(false, core::iter::repeat(b'1'), 0).is_err());
+    }
+
+    #[test]
+    fn decimal_zero() {
+        let zero_from_str = Bitstring::try_parse_str("0").expect("failed to parse");
+        let zero_from_const = Bitstring::zero();
+        let zero_from_int = Bitstring::from(0);
+
+        assert_eq!("0", zero_from_str.to_string());
+        assert_eq!("0", zero_from_const.to_string());
+        assert_eq!("0", zero_from_int.to_string());
+
+        assert_eq!(zero_from_str.as_le_bytes(), zero_from_const.as_le_bytes());
+        assert_eq!(zero_from_str.as_le_bytes(), zero_from_int.as_le_bytes());
+    }
+
+    #[test]
+    fn decimal_default() {
+        #[derive(Default)]
+        struct Prices {
+            bid: Bitstring64,
+            ask: Bitstring,
+        }
+
+        let prices = Prices::default();
+
+        assert_eq!(
+            Bitstring64::try_parse_str("0")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            prices.bid.as_le_bytes()
+        );
+        assert_eq!(Bitstring::zero().as_le_bytes(), prices.ask.as_le_bytes());
+
+        assert_eq!(
+            Bitstring32::try_parse_str("0")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            Bitstring32::default().as_le_bytes()
+        );
+        assert_eq!(
+            Bitstring128::try_parse_str("0")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            Bitstring128::default().as_le_bytes()
+        );
+        assert_eq!(
+            Bitstring::try_parse_str("0")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            Bitstring::default().as_le_bytes()
+        );
+
+        // The default isn't an all-zero buffer, which would have a very small exponent
+        assert_ne!(&[0; 8], Bitstring64::default().as_le_bytes());
+
+        #[cfg(feature = "arbitrary-precision")]
+        assert_eq!(
+            BigBitstring::try_parse_str("0")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            BigBitstring::default().as_le_bytes()
+        );
+    }
+
+    #[test]
+    fn decimal_zero_cohorts() {
+        let zeroes = [
+            ("0", "0"),
+            ("-0", "-0"),
+            ("+0", "0"),
+            ("0.0", "0.0"),
+            ("-0.0", "-0.0"),
+            ("0.00", "0.00"),
+            ("-0.00", "-0.00"),
+            ("000.00", "0.00"),
+            ("0.0000000", "0e-7"),
+            ("-0.0000000", "-0e-7"),
+            ("0e3", "0e3"),
+            ("-0e3", "-0e3"),
+            ("0e-3", "0.000"),
+            ("-0e-3", "-0.000"),
+            ("0.00e2", "0"),
+            ("0e90", "0e90"),
+            ("-0e90", "-0e90"),
+            ("0e-101", "0e-101"),
+            ("-0e-101", "-0e-101"),
+        ];
+
+        for (f, expected) in zeroes {
+            let d32 = Bitstring32::try_parse_str(f).expect("failed to parse decimal");
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(expected, d32.to_string(), "{}", f);
+            assert_eq!(expected, d.to_string(), "{}", f);
+
+            // The zero roundtrips through its bytes
+            let from_bytes = Bitstring32::from_le_bytes(d32.to_le_bytes());
+            assert_eq!(expected, from_bytes.to_string(), "{}", f);
+
+            let from_bytes =
+                Bitstring::try_from_le_bytes(d.as_le_bytes()).expect("failed to read decimal");
+            assert_eq!(expected, from_bytes.to_string(), "{}", f);
+
+            // The zero roundtrips through its text
+            let reparsed =
+                Bitstring32::try_parse_str(&d32.to_string()).expect("failed to parse decimal");
+            assert_eq!(d32.as_le_bytes(), reparsed.as_le_bytes(), "{}", f);
+
+            assert_eq!(f.starts_with('-'), d32.is_sign_negative(), "{}", f);
+        }
+
+        // Each distinct zero has a distinct encoding
+        for (a, expected_a) in zeroes {
+            for (b, expected_b) in zeroes {
+                let a = Bitstring32::try_parse_str(a).expect("failed to parse decimal");
+                let b = Bitstring32::try_parse_str(b).expect("failed to parse decimal");
+
+                assert_eq!(
+                    expected_a == expected_b,
+                    a.as_le_bytes() == b.as_le_bytes(),
+                    "{} {}",
+                    expected_a,
+                    expected_b
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn decimal_zero_from_float() {
+        let zero_from_float = Bitstring::from(0f64);
+
+        assert_eq!("0", zero_from_float.to_string());
+        assert_eq!(
+            Bitstring::from(0).as_le_bytes(),
+            zero_from_float.as_le_bytes()
+        );
+
+        let neg_zero_from_float = Bitstring::from(-0f64);
+
+        assert_eq!("-0", neg_zero_from_float.to_string());
+        assert!(neg_zero_from_float.is_sign_negative());
+
+        for (f, i) in [(1f64, 1), (-42f64, -42), (1e15, 1_000_000_000_000_000i64)] {
+            assert_eq!(
+                Bitstring::from(i).as_le_bytes(),
+                Bitstring::from(f).as_le_bytes(),
+                "{}",
+                f
+            );
+        }
+
+        assert_eq!(
+            Bitstring::from(0).as_le_bytes(),
+            Bitstring::from(0f32).as_le_bytes()
+        );
+        assert_eq!("1.5", Bitstring::from(1.5f64).to_string());
+        assert_eq!("1e16", Bitstring::from(1e16f64).to_string());
+    }
+
+    #[test]
+    fn decimal_size_small_significand_large_exponent() {
+        for i in ["1e6100", "1e-6100"] {
+            // We only have a single digit, but the exponent is too large for anything smaller than 128bit
+            let d = Bitstring::try_parse_str(i).expect("failed to parse decimal");
+
+            assert!(d.as_le_bytes().len() * 8 >= 128, "{}", i);
+        }
+    }
+
+    #[test]
+    fn decimal_size_small_significand() {
+        let d = Bitstring::try_parse_str("1").expect("failed to parse decimal");
+
+        assert_eq!(32, d.as_le_bytes().len() * 8);
+    }
+
+    #[test]
+    fn err_decimal_overflow_exponent() {
         for i in ["1e2147483648", "1e1073741823"] {
             assert!(Bitstring::try_parse_str(i).is_err());
             assert!(Bitstring::try_parse(format_args!("{}", i)).is_err());
@@ -532,14 +3626,582 @@ mod tests {
     #[test]
     fn err_decimal_overflow_digits() {
         for i in [
-            "123456789012345678901234567890123456789012345678901234567890",
-            "1234567890123456789.1234567890123456789012345678901234567890",
+            "12345678901234567890123456789012345678901234567890123456789012345678901",
+            "1234567890123456789.1234567890123456789012345678901234567890123456789012",
         ] {
             assert!(Bitstring::try_parse_str(i).is_err());
             assert!(Bitstring::try_parse(format_args!("{}", i)).is_err());
         }
     }
 
+    #[test]
+    fn err_decimal_kind() {
+        let err = Bitstring32::try_parse_str("1e2000").unwrap_err();
+        assert!(err.is_overflow());
+        assert!(!err.is_parse());
+
+        let err = Bitstring32::try_parse_str("1x").unwrap_err();
+        assert!(err.is_parse());
+        assert!(!err.is_overflow());
+
+        let err = i8::try_from(Bitstring::try_parse_str("1000").unwrap()).unwrap_err();
+        assert!(err.is_convert());
+        assert!(!err.is_overflow());
+        assert!(!err.is_parse());
+    }
+
+    #[test]
+    fn decimal_to_le_bytes() {
+        let d = Bitstring64::try_parse_str("-123.456").expect("failed to parse decimal");
+
+        assert_eq!(*d.as_le_bytes(), d.to_le_bytes());
+        assert_eq!(
+            d.as_le_bytes(),
+            Bitstring64::from_le_bytes(d.to_le_bytes()).as_le_bytes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decimal_to_le_bytes_vec() {
+        let d = Bitstring32::try_parse_str("-123.456").expect("failed to parse decimal");
+        assert_eq!(&d.as_le_bytes()[..], &d.to_le_bytes_vec()[..]);
+
+        let d = Bitstring::try_parse_str("-123.456").expect("failed to parse decimal");
+        assert_eq!(4, d.to_le_bytes_vec().len());
+        assert_eq!(d.as_le_bytes(), &d.to_le_bytes_vec()[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn decimal_bytemuck() {
+        let decimals = [
+            Bitstring64::try_parse_str("1.50").expect("failed to parse decimal"),
+            Bitstring64::try_parse_str("-inf").expect("failed to parse decimal"),
+            Bitstring64::try_parse_str("nan(42)").expect("failed to parse decimal"),
+        ];
+
+        let bytes: &[u8] = bytemuck::cast_slice(&decimals);
+
+        assert_eq!(24, bytes.len());
+        assert_eq!(decimals[0].as_le_bytes(), &bytes[..8]);
+
+        let cast: &[Bitstring64] = bytemuck::cast_slice(bytes);
+
+        assert_eq!(
+            decimals.map(|d| d.to_string()).as_slice(),
+            cast.iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+
+        // Zero-filled memory is a zero, but not the same zero as `ZERO`
+        let zeroed: Bitstring64 = bytemuck::Zeroable::zeroed();
+
+        assert!(zeroed.is_zero());
+        assert_eq!(Bitstring64::ZERO, zeroed);
+        assert_eq!("0e-398", zeroed.to_string());
+    }
+
+    #[test]
+    fn decimal_checked_arith() {
+        macro_rules! assert_checked_arith {
+            ($d:ty) => {{
+                let a = <$d>::try_parse_str("1.50").expect("failed to parse decimal");
+                let b = <$d>::try_parse_str("-2.5").expect("failed to parse decimal");
+
+                assert_eq!(
+                    "-1.00",
+                    a.checked_add(&b).expect("failed to add").to_string()
+                );
+                assert_eq!(
+                    "4.00",
+                    a.checked_sub(&b).expect("failed to subtract").to_string()
+                );
+                assert_eq!(
+                    "-3.750",
+                    a.checked_mul(&b).expect("failed to multiply").to_string()
+                );
+                assert_eq!(
+                    "0.00",
+                    a.checked_sub(&a).expect("failed to subtract").to_string()
+                );
+
+                let tiny = <$d>::try_parse_str("1e-20").expect("failed to parse decimal");
+                assert!(tiny
+                    .checked_add(&<$d>::try_parse_str("1e80").expect("failed to parse decimal"))
+                    .is_none());
+            }};
+        }
+
+        assert_checked_arith!(Bitstring32);
+        assert_checked_arith!(Bitstring64);
+        assert_checked_arith!(Bitstring128);
+        assert_checked_arith!(Bitstring256);
+        assert_checked_arith!(Bitstring);
+        #[cfg(feature = "arbitrary-precision")]
+        assert_checked_arith!(BigBitstring);
+
+        // A dynamic decimal keeps the precision of the wider of the two decimals
+        let a = Bitstring::try_parse_str("1").expect("failed to parse decimal");
+        let b = Bitstring::try_parse_str("1.0000000001").expect("failed to parse decimal");
+        let c = Bitstring::try_parse_str("1e-10").expect("failed to parse decimal");
+
+        assert_eq!(4, a.as_le_bytes().len());
+        assert_eq!(8, b.as_le_bytes().len());
+        assert_eq!(
+            "2.0000000001",
+            a.checked_add(&b).expect("failed to add").to_string()
+        );
+        assert!(a.checked_add(&c).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn decimal_num_traits() {
+        use num_traits::{
+            CheckedAdd,
+            CheckedMul,
+            FromPrimitive,
+            One,
+            ToPrimitive,
+            Zero,
+        };
+
+        fn sum<D: Zero + CheckedAdd>(ds: &[D]) -> Option<D> {
+            ds.iter().try_fold(D::zero(), |sum, d| sum.checked_add(d))
+        }
+
+        fn product<D: One + CheckedMul>(ds: &[D]) -> Option<D> {
+            ds.iter()
+                .try_fold(D::one(), |product, d| product.checked_mul(d))
+        }
+
+        let ds = ["1.5", "2.25", "-0.5"]
+            .map(|d| Bitstring64::try_parse_str(d).expect("failed to parse decimal"));
+
+        assert_eq!(
+            "3.25",
+            sum(&ds).expect("failed to add decimals").to_string()
+        );
+        assert_eq!(
+            "-1.6875",
+            product(&ds)
+                .expect("failed to multiply decimals")
+                .to_string()
+        );
+        assert!(sum(&[Bitstring32::MAX, Bitstring32::MAX]).is_none());
+
+        assert!(<Bitstring as Zero>::is_zero(
+            &Bitstring::try_parse_str("-0.00").expect("failed to parse decimal")
+        ));
+        assert_eq!("1", <Bitstring128 as One>::one().to_string());
+        assert_eq!(
+            "6",
+            (Bitstring32::from(2u8) * Bitstring32::from(3u8)).to_string()
+        );
+
+        fn roundtrip<D: FromPrimitive + ToPrimitive>(i: i64) -> Option<i64> {
+            D::from_i64(i)?.to_i64()
+        }
+
+        assert_eq!(Some(-42), roundtrip::<Bitstring32>(-42));
+        assert_eq!(Some(i64::MAX), roundtrip::<Bitstring128>(i64::MAX));
+        assert_eq!(Some(i64::MIN), roundtrip::<Bitstring>(i64::MIN));
+        assert_eq!(None, roundtrip::<Bitstring32>(i64::MAX));
+
+        #[cfg(feature = "float")]
+        assert_eq!(
+            "1.5",
+            <Bitstring64 as FromPrimitive>::from_f64(1.5)
+                .expect("failed to convert")
+                .to_string()
+        );
+        #[cfg(not(feature = "float"))]
+        assert!(<Bitstring64 as FromPrimitive>::from_f64(1.5).is_none());
+        assert!(<Bitstring32 as FromPrimitive>::from_u128(u128::MAX).is_none());
+
+        let d = Bitstring64::try_parse_str("1.5").expect("failed to parse decimal");
+        assert_eq!(None, ToPrimitive::to_i64(&d));
+        assert_eq!(None, ToPrimitive::to_u8(&d));
+        #[cfg(feature = "float")]
+        assert_eq!(Some(1.5), ToPrimitive::to_f64(&d));
+
+        let d = Bitstring64::try_parse_str("-1").expect("failed to parse decimal");
+        assert_eq!(Some(-1), ToPrimitive::to_i8(&d));
+        assert_eq!(None, ToPrimitive::to_u64(&d));
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn decimal_num_traits_zero_one() {
+        use num_traits::{
+            One,
+            Zero,
+        };
+
+        // Call through the traits, since the decimals also have inherent methods with these names
+        assert!(Zero::is_zero(&<Bitstring64 as Zero>::zero()));
+        assert!(!Zero::is_zero(&<Bitstring64 as One>::one()));
+        assert_eq!(Bitstring64::ZERO, <Bitstring64 as Zero>::zero());
+        assert_eq!(Bitstring64::ONE, <Bitstring64 as One>::one());
+
+        // Zeroes in any cohort are zero
+        for f in ["0", "-0", "0.000", "0e10"] {
+            let d = Bitstring64::try_parse_str(f).expect("failed to parse decimal");
+
+            assert!(Zero::is_zero(&d), "{}", f);
+        }
+
+        fn zero_one<D: Zero + One>() -> (D, D) {
+            (D::zero(), D::one())
+        }
+
+        let (zero, one) = zero_one::<Bitstring>();
+        assert_eq!("0", zero.to_string());
+        assert_eq!("1", one.to_string());
+    }
+
+    // The `Add` and `Mul` operators that `num-traits` needs panic for each width
+    // when their result can't be encoded exactly
+    macro_rules! num_traits_ops_panic {
+        ($($add:ident, $mul:ident => $d:ty, $digits:literal,)*) => {
+            $(
+                #[test]
+                #[cfg(feature = "num-traits")]
+                #[should_panic(expected = "the sum can't be encoded exactly")]
+                fn $add() {
+                    let max = <$d>::try_parse_str(&"9".repeat($digits)).expect("failed to parse decimal");
+                    let tenth = <$d>::try_parse_str("0.1").expect("failed to parse decimal");
+
+                    let _ = max + tenth;
+                }
+
+                #[test]
+                #[cfg(feature = "num-traits")]
+                #[should_panic(expected = "the product can't be encoded exactly")]
+                fn $mul() {
+                    let max = || {
+                        <$d>::try_parse_str(&"9".repeat($digits)).expect("failed to parse decimal")
+                    };
+
+                    let _ = max() * max();
+                }
+            )*
+        };
+    }
+
+    num_traits_ops_panic! {
+        err_decimal32_add_inexact, err_decimal32_mul_inexact => Bitstring32, 7,
+        err_decimal64_add_inexact, err_decimal64_mul_inexact => Bitstring64, 16,
+        err_decimal128_add_inexact, err_decimal128_mul_inexact => Bitstring128, 34,
+        err_decimal256_add_inexact, err_decimal256_mul_inexact => Bitstring256, 70,
+        err_decimal_add_inexact, err_decimal_mul_inexact => Bitstring, 70,
+    }
+
+    #[cfg(feature = "arbitrary-precision")]
+    num_traits_ops_panic! {
+        err_bigdecimal_add_inexact, err_bigdecimal_mul_inexact => BigBitstring, 34,
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn decimal_num_traits_ops() {
+        // Results that can be encoded exactly don't panic
+        assert_eq!(
+            "1.5",
+            (Bitstring32::try_parse_str("1").expect("failed to parse decimal")
+                + Bitstring32::try_parse_str("0.5").expect("failed to parse decimal"))
+            .to_string()
+        );
+        assert_eq!(
+            "1e90",
+            (Bitstring32::try_parse_str("1e90").expect("failed to parse decimal")
+                * Bitstring32::ONE)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn decimal_fmt_le_bytes() {
+        for f in ["-123.456", "1e-6100", "inf", "snan(42)"] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            let mut s = String::new();
+            fmt_le_bytes(d.as_le_bytes(), &mut s).expect("failed to format decimal");
+
+            assert_eq!(f, s);
+        }
+
+        let mut s = String::new();
+        fmt_le_bytes(Bitstring32::from(42u8).as_le_bytes(), &mut s)
+            .expect("failed to format decimal");
+
+        assert_eq!("42", s);
+    }
+
+    #[test]
+    fn err_decimal_fmt_le_bytes() {
+        for bytes in [&[][..], &[0; 3], &[0; 17]] {
+            let err = fmt_le_bytes(bytes, String::new()).unwrap_err();
+            assert!(err.is_overflow());
+        }
+
+        struct Fail;
+
+        impl core::fmt::Write for Fail {
+            fn write_str(&mut self, _: &str) -> core::fmt::Result {
+                Err(core::fmt::Error)
+            }
+        }
+
+        let d = Bitstring32::from(42u8);
+        let err = fmt_le_bytes(d.as_le_bytes(), Fail).unwrap_err();
+        assert!(err.is_fmt());
+    }
+
+    #[test]
+    fn decimal_class_of_le_bytes() {
+        for (f, expected) in [
+            ("0", NumberClass::PositiveFinite),
+            ("-0", NumberClass::NegativeFinite),
+            ("-123.456", NumberClass::NegativeFinite),
+            ("1e-6100", NumberClass::PositiveFinite),
+            ("9.999999e96", NumberClass::PositiveFinite),
+            ("inf", NumberClass::PositiveInfinity),
+            ("-inf", NumberClass::NegativeInfinity),
+            ("nan", NumberClass::QuietNan),
+            ("-nan(42)", NumberClass::QuietNan),
+            ("snan", NumberClass::SignalingNan),
+            ("-snan(1)", NumberClass::SignalingNan),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(
+                expected,
+                class_of_le_bytes(d.as_le_bytes()).expect("failed to classify decimal"),
+                "{}",
+                f
+            );
+        }
+
+        assert_eq!(
+            NumberClass::PositiveFinite,
+            class_of_le_bytes(Bitstring128::ONE.as_le_bytes()).expect("failed to classify decimal")
+        );
+
+        for bytes in [&[][..], &[0; 3], &[0; 17]] {
+            let err = class_of_le_bytes(bytes).unwrap_err();
+            assert!(err.is_overflow());
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "arbitrary-precision"))]
+    fn err_decimal_class_of_le_bytes_too_large() {
+        let err = class_of_le_bytes(&[0; 36]).unwrap_err();
+        assert!(err.is_overflow());
+    }
+
+    #[test]
+    #[cfg(not(feature = "arbitrary-precision"))]
+    fn err_decimal_fmt_le_bytes_too_large() {
+        let err = fmt_le_bytes(&[0; 36], String::new()).unwrap_err();
+        assert!(err.is_overflow());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary-precision")]
+    fn bigdecimal_fmt_le_bytes() {
+        let d = BigBitstring::try_parse_str("-1.5e-30000").expect("failed to parse decimal");
+        assert!(d.as_le_bytes().len() > 20);
+
+        let mut s = String::new();
+        fmt_le_bytes(d.as_le_bytes(), &mut s).expect("failed to format decimal");
+
+        assert_eq!("-1.5e-30000", s);
+    }
+
+    #[test]
+    fn decimal_has_canonical_special() {
+        for d in ["1.5", "-0", "inf", "-inf", "nan", "-snan(123)"] {
+            let d = Bitstring32::try_parse_str(d).expect("failed to parse decimal");
+            assert!(d.has_canonical_special(), "{}", d);
+        }
+
+        // An infinity with garbage in its trailing significand
+        assert!(!Bitstring32::from_le_bytes([0x01, 0, 0, 0b0111_1000]).has_canonical_special());
+
+        // An infinity with the signaling bit set
+        assert!(!Bitstring32::from_le_bytes([0, 0, 0, 0b0111_1010]).has_canonical_special());
+
+        // A NaN with bits set in its exponent continuation
+        assert!(!Bitstring32::from_le_bytes([0, 0, 0, 0b0111_1101]).has_canonical_special());
+        assert!(
+            !Bitstring64::from_le_bytes([0, 0, 0, 0, 0, 0, 0b0100_0000, 0b1111_1100])
+                .has_canonical_special()
+        );
+
+        // A NaN with a payload is fine
+        assert!(Bitstring32::from_le_bytes([0x42, 0, 0, 0b0111_1100]).has_canonical_special());
+    }
+
+    #[test]
+    fn decimal_try_from_le_bytes_canonical() {
+        let canonical = *Bitstring32::try_parse_str("999")
+            .expect("failed to parse decimal")
+            .as_le_bytes();
+
+        let d = Bitstring32::try_from_le_bytes_canonical(canonical)
+            .expect("failed to read canonical decimal");
+        assert!(d.is_canonical());
+
+        // The 2 most significant bits of the `999` declet are unused
+        let mut non_canonical = canonical;
+        non_canonical[1] |= 0b0000_0011;
+
+        let d = Bitstring32::from_le_bytes(non_canonical);
+        assert_eq!("999", d.to_string());
+        assert!(d.has_canonical_special());
+        assert!(!d.is_canonical());
+
+        let err = Bitstring32::try_from_le_bytes_canonical(non_canonical).unwrap_err();
+        assert!(err.is_convert());
+        assert!(err.is_non_canonical());
+
+        let err = Bitstring::try_from_le_bytes_canonical(&non_canonical).unwrap_err();
+        assert!(err.is_non_canonical());
+
+        assert!(Bitstring::try_from_le_bytes_canonical(&canonical).is_ok());
+
+        // A NaN payload is checked too
+        let mut nan = *Bitstring32::try_parse_str("nan(999)")
+            .expect("failed to parse decimal")
+            .as_le_bytes();
+        assert!(Bitstring32::from_le_bytes(nan).is_canonical());
+
+        nan[1] |= 0b0000_0011;
+        assert!(!Bitstring32::from_le_bytes(nan).is_canonical());
+    }
+
+    #[test]
+    fn decimal_to_canonical() {
+        let canonical = Bitstring64::try_parse_str("-9.99e-5").expect("failed to parse decimal");
+
+        // The 2 most significant bits of the `999` declet are unused
+        let mut non_canonical = *canonical.as_le_bytes();
+        non_canonical[1] |= 0b0000_0011;
+
+        let d = Bitstring64::from_le_bytes(non_canonical);
+        assert!(!d.is_canonical());
+
+        let d = d.to_canonical();
+        assert!(d.is_canonical());
+        assert_eq!(canonical.as_le_bytes(), d.as_le_bytes());
+
+        for f in ["1.500", "-0", "inf", "nan(123)", "-snan"] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            assert_eq!(d.as_le_bytes(), d.to_canonical().as_le_bytes(), "{}", f);
+        }
+    }
+
+    #[test]
+    fn decimal_from_le_bytes_const() {
+        const RATE: Bitstring = Bitstring::from_le_bytes(
+            Bitstring32::from_coefficient_exponent_const(-1234, -2).to_le_bytes(),
+        );
+        const WIDE: Bitstring = Bitstring::from_le_bytes([0; 20]);
+        const RATE_BYTES: &[u8] = RATE.as_le_bytes();
+
+        let parsed = Bitstring::try_parse_str("-12.34").expect("failed to parse decimal");
+
+        assert_eq!(parsed.as_le_bytes(), RATE_BYTES);
+        assert_eq!(parsed, RATE);
+
+        assert_eq!(20, WIDE.as_le_bytes().len());
+        assert!(WIDE.is_zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn decimal_from_le_bytes_const_invalid_width() {
+        let _ = Bitstring::from_le_bytes([0; 6]);
+    }
+
+    #[test]
+    fn decimal_from_le_bytes_any_width() {
+        for (f, width) in [
+            ("1.5", 4),
+            ("-1234567", 4),
+            ("12345678", 8),
+            ("1e-398", 8),
+            ("12345678901234567", 12),
+            ("1e-6176", 16),
+            ("1234567890123456789012345678901234", 16),
+            ("12345678901234567890123456789012345", 20),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+            assert_eq!(width, d.as_le_bytes().len(), "{}", f);
+
+            let bytes = d.as_le_bytes();
+            let from_bytes = Bitstring::try_from_le_bytes(bytes).expect("failed to read decimal");
+
+            assert_eq!(bytes, from_bytes.as_le_bytes(), "{}", f);
+            assert_eq!(d.to_string(), from_bytes.to_string(), "{}", f);
+        }
+
+        // Bytes from the fixed-width decimals keep their width
+        let d = Bitstring::try_from_le_bytes(&Bitstring32::ONE.to_le_bytes())
+            .expect("failed to read decimal");
+        assert_eq!(4, d.as_le_bytes().len());
+        assert_eq!("1", d.to_string());
+
+        let d = Bitstring::try_from_le_bytes(&Bitstring64::ONE.to_le_bytes())
+            .expect("failed to read decimal");
+        assert_eq!(8, d.as_le_bytes().len());
+        assert_eq!("1", d.to_string());
+
+        let d = Bitstring::try_from_le_bytes(&Bitstring128::ONE.to_le_bytes())
+            .expect("failed to read decimal");
+        assert_eq!(16, d.as_le_bytes().len());
+        assert_eq!("1", d.to_string());
+    }
+
+    #[test]
+    fn decimal_inline_limits() {
+        for f in [
+            "1234567890".repeat(7),
+            "1e-1572932".to_owned(),
+            "1e1572795".to_owned(),
+            format!("1{}e1572795", "0".repeat(69)),
+            format!("{}e1572795", "9".repeat(70)),
+        ] {
+            let d = Bitstring::try_parse_str_inline(&f).expect("failed to parse decimal");
+            assert_eq!(32, d.as_le_bytes().len(), "{}", f);
+        }
+
+        for f in [
+            "1234567890".repeat(7) + "1",
+            "1e-1572933".to_owned(),
+            "1e1572796".to_owned(),
+            "1e1572865".to_owned(),
+        ] {
+            assert!(Bitstring::try_parse_str_inline(&f).is_err(), "{}", f);
+
+            #[cfg(feature = "arbitrary-precision")]
+            {
+                let d = BigBitstring::try_parse_str(&f).expect("failed to parse decimal");
+                assert!(d.as_le_bytes().len() > 32, "{}", f);
+            }
+        }
+
+        // Every fixed-width decimal fits in a `Bitstring`
+        let d = Bitstring256::MAX.to_dynamic();
+        assert_eq!(Bitstring256::MAX.to_string(), d.to_string());
+    }
+
     #[test]
     fn err_decimal_from_invalid_byte_count() {
         let err = Bitstring::try_from_le_bytes(&[]).unwrap_err();
@@ -548,9 +4210,9 @@ mod tests {
         let err = Bitstring::try_from_le_bytes(&[0; 3]).unwrap_err();
         assert_eq!("the value cannot fit into a decimal of `3` bytes; the width needed is `4` bytes; decimals must be a multiple of 32 bits (4 bytes)", &err.to_string());
 
-        let err = Bitstring::try_from_le_bytes(&[0; 32]).unwrap_err();
+        let err = Bitstring::try_from_le_bytes(&[0; 36]).unwrap_err();
         assert_eq!(
-            "the value cannot fit into a decimal of `20` bytes; the width needed is `32` bytes",
+            "the value cannot fit into a decimal of `32` bytes; the width needed is `36` bytes",
             &err.to_string()
         );
     }
@@ -578,4 +4240,43 @@ mod tests {
         assert_eq!(58272, ds.as_le_bytes().len() * 8);
         assert_eq!(58272, dd.as_le_bytes().len() * 8);
     }
+
+    #[test]
+    #[cfg(feature = "arbitrary-precision")]
+    fn bigdecimal_parse_limited() {
+        for (s, max_digits, max_exp_digits) in [
+            ("123.45", 5, 0),
+            ("-1.5e-300", 2, 3),
+            ("0.00", 3, 0),
+            ("inf", 0, 0),
+            ("nan(123)", 3, 0),
+        ] {
+            let d = BigBitstring::try_parse_str_limited(s, max_digits, max_exp_digits)
+                .expect("failed to parse decimal");
+
+            assert_eq!(s, d.to_string());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary-precision")]
+    fn err_bigdecimal_parse_limited() {
+        for (s, max_digits, max_exp_digits) in [
+            ("123.45", 4, 0),
+            ("-1.5e-300", 2, 2),
+            ("1e0000000001", 1, 9),
+            ("nan(1234)", 3, 0),
+        ] {
+            let err = BigBitstring::try_parse_str_limited(s, max_digits, max_exp_digits)
+                .expect_err("expected parsing to fail");
+
+            assert!(err.is_limit_exceeded(), "{}", s);
+            assert!(err.is_parse(), "{}", s);
+        }
+
+        let err = BigBitstring::try_parse_str_limited("1.x", 100, 100)
+            .expect_err("expected parsing to fail");
+
+        assert!(!err.is_limit_exceeded());
+    }
 }
@@ -1,9 +1,12 @@
 use core::{
     fmt,
     ops::Index,
-    str,
 };
 
+#[cfg(feature = "float")]
+use core::str;
+
+#[cfg(feature = "float")]
 use crate::text::{
     ArrayTextBuf,
     FiniteParser,
@@ -86,6 +89,7 @@ impl<'a, T: Integer> fmt::Display for AsDisplay<&'a T> {
 /**
 Generic floating points.
 */
+#[cfg(feature = "float")]
 pub(crate) trait Float {
     /**
     A text writer that can buffer any valid instance of this number.
@@ -108,6 +112,29 @@ pub(crate) trait Float {
     where
         Self: Sized;
 
+    /**
+    The number of binary digits in the significand, including the implicit leading bit.
+    */
+    const MANTISSA_DIGITS: u32;
+
+    /**
+    One more than the minimum exponent of a normal number, in the same form as `f64::MIN_EXP`.
+    */
+    const MIN_EXP: i32;
+
+    /**
+    One more than the maximum exponent of a finite number, in the same form as `f64::MAX_EXP`.
+    */
+    const MAX_EXP: i32;
+
+    /**
+    Get an instance of the finite number `significand * 2^exponent`.
+
+    The significand must fit in `MANTISSA_DIGITS` bits. If it doesn't use all of them then the
+    number is subnormal, and the exponent must be `MIN_EXP - MANTISSA_DIGITS`.
+    */
+    fn from_binary_parts(is_negative: bool, significand: u64, exponent: i32) -> Self;
+
     /**
     Get an instance of an infinity.
     */
@@ -281,24 +308,33 @@ impl_binary_unsigned_integer!(
 );
 
 // 2f32.powi(23 + 1).log10().ceil() + 1f32
+#[cfg(feature = "float")]
 const F32_MAX_MANTISSA_DIGITS: usize = 9;
+#[cfg(feature = "float")]
 const F32_MAX_EXPONENT_DIGITS: usize = 3;
+#[cfg(feature = "float")]
 const F32_BUF_SIZE: usize = F32_MAX_MANTISSA_DIGITS + F32_MAX_EXPONENT_DIGITS + 4;
 
 // The payload for a NaN is the significand bits, except for the most significant,
 // which is used to identify signaling vs quiet NaNs
+#[cfg(feature = "float")]
 const F32_NAN_PAYLOAD_MASK: u32 = 0b0000_0000_0111_1111_1111_1111_1111_1111u32;
 
 // 2f64.powi(52 + 1).log10().ceil() + 1f64
+#[cfg(feature = "float")]
 const F64_MAX_MANTISSA_DIGITS: usize = 17;
+#[cfg(feature = "float")]
 const F64_MAX_EXPONENT_DIGITS: usize = 4;
+#[cfg(feature = "float")]
 const F64_BUF_SIZE: usize = F64_MAX_MANTISSA_DIGITS + F64_MAX_EXPONENT_DIGITS + 4;
 
 // The payload for a NaN is the significand bits, except for the most significant,
 // which is used to identify signaling vs quiet NaNs
+#[cfg(feature = "float")]
 const F64_NAN_PAYLOAD_MASK: u64 =
     0b0000_0000_0000_0111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111u64;
 
+#[cfg(feature = "float")]
 macro_rules! impl_binary_float {
     ($(($f:ty, $i:ty, $u:ty, $text_writer:ty, $nan_mask:ident)),*) => {
         $(
@@ -315,6 +351,37 @@ macro_rules! impl_binary_float {
                     parse_ascii(is_negative, ascii, exponent)
                 }
 
+                const MANTISSA_DIGITS: u32 = <$f>::MANTISSA_DIGITS;
+
+                const MIN_EXP: i32 = <$f>::MIN_EXP;
+
+                const MAX_EXP: i32 = <$f>::MAX_EXP;
+
+                fn from_binary_parts(is_negative: bool, significand: u64, exponent: i32) -> Self {
+                    let trailing_digits = <$f>::MANTISSA_DIGITS - 1;
+
+                    let bits = if significand >> trailing_digits == 0 {
+                        // Subnormal numbers have a biased exponent of zero,
+                        // so their bits are just their significand
+                        significand as $u
+                    } else {
+                        // Normal numbers have an implicit leading bit in their significand
+                        let biased_exponent =
+                            (exponent + trailing_digits as i32 + <$f>::MAX_EXP - 1) as $u;
+
+                        (biased_exponent << trailing_digits)
+                            | (significand as $u & ((1 << trailing_digits) - 1))
+                    };
+
+                    let f = <$f>::from_bits(bits);
+
+                    if is_negative {
+                        -f
+                    } else {
+                        f
+                    }
+                }
+
                 fn infinity(is_negative: bool) -> Self {
                     if is_negative {
                         <$f>::NEG_INFINITY
@@ -379,6 +446,7 @@ macro_rules! impl_binary_float {
     };
 }
 
+#[cfg(feature = "float")]
 impl_binary_float!(
     (
         f32,
@@ -399,6 +467,7 @@ impl_binary_float!(
 /**
 Parse a binary floating point number from text.
 */
+#[cfg(feature = "float")]
 fn parse_ascii<F: Float + str::FromStr>(
     is_negative: bool,
     digits: impl Iterator<Item = u8>,
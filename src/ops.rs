@@ -0,0 +1,33 @@
+/*!
+Operations on binary-encoded decimal numbers that depend on their value rather than their encoding.
+
+Decimals are non-normalized, so the same value may be encoded in different ways. The operations
+in this module account for that, so `1.0` and `1` are treated as equal even though their bits differ.
+*/
+
+mod approx;
+mod arith;
+mod cmp;
+mod cohort;
+mod hash;
+mod nan;
+mod next;
+#[cfg(feature = "alloc")]
+mod ord_key;
+mod pow10;
+mod search;
+
+pub(crate) use self::{
+    approx::*,
+    arith::*,
+    cmp::*,
+    cohort::*,
+    hash::*,
+    nan::*,
+    next::*,
+    pow10::*,
+    search::*,
+};
+
+#[cfg(feature = "alloc")]
+pub(crate) use self::ord_key::*;
@@ -0,0 +1,244 @@
+/*!
+Compare decimals for approximate equality within some tolerance.
+*/
+
+use core::cmp::Ordering;
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_declets_rev,
+        is_infinite,
+        is_nan,
+        is_sign_negative,
+        BinaryBuf,
+        BinaryExponent,
+    },
+    ops::{
+        decimal_cmp,
+        decimal_is_zero,
+    },
+};
+
+/**
+Whether the absolute difference between two decimals is no more than a given tolerance.
+
+The difference is calculated exactly, so there's no rounding involved in deciding whether
+it's within the tolerance. If any of the decimals are NaN, or if the tolerance is negative,
+then they're never approximately equal.
+*/
+pub(crate) fn decimal_approx_eq<D: BinaryBuf>(a: &D, b: &D, tolerance: &D) -> bool {
+    if is_nan(tolerance) || (is_sign_negative(tolerance) && !decimal_is_zero(tolerance)) {
+        return false;
+    }
+
+    // Order the decimals so that `x - y` is always non-negative
+    let (x, y) = match decimal_cmp(a, b) {
+        // NaNs are never approximately equal
+        None => return false,
+        // Equal decimals are always approximately equal, even if they're infinities
+        Some(Ordering::Equal) => return true,
+        Some(Ordering::Greater) => (a, b),
+        Some(Ordering::Less) => (b, a),
+    };
+
+    // An infinite tolerance covers any difference, even an infinite one
+    if is_infinite(tolerance) {
+        return true;
+    }
+
+    // The difference between unequal decimals with an infinity is also infinite,
+    // so it's never within a finite tolerance
+    if is_infinite(x) || is_infinite(y) {
+        return false;
+    }
+
+    // Calculate `x - y - tolerance` one digit at a time, from least to most significant,
+    // just like long subtraction. The difference is within the tolerance if the result is
+    // either negative or zero.
+    //
+    // We only need to look at positions that have a digit in at least one of the decimals.
+    // The positions in any gaps between them are all zero, so the only thing that happens
+    // there is a carry being propagated through them.
+    let mut operands = [
+        operand(x, is_sign_negative(x)),
+        operand(y, !is_sign_negative(y)),
+        operand(tolerance, true),
+    ];
+
+    let mut position: Option<D::Exponent> = None;
+    let mut carry = 0i32;
+    let mut is_non_zero = false;
+
+    while let Some(next) = operands
+        .iter()
+        .filter(|operand| operand.remaining > 0)
+        .map(|operand| &operand.position)
+        .min()
+        .cloned()
+    {
+        // If there's a gap between the last position and this one then propagate the carry
+        // through it. Any non-zero carry leaves non-zero digits in the gap, and a negative
+        // carry continues as `-1` all the way through it, regardless of how long the gap is
+        if matches!(&position, Some(position) if *position < next) && carry != 0 {
+            is_non_zero = true;
+            carry = if carry < 0 { -1 } else { 0 };
+        }
+
+        let mut value = carry;
+
+        for operand in operands.iter_mut() {
+            if operand.remaining > 0 && operand.position == next {
+                value += operand.next_digit();
+            }
+        }
+
+        is_non_zero |= value.rem_euclid(10) != 0;
+        carry = value.div_euclid(10);
+
+        position = Some(next.raise(1));
+    }
+
+    // The final carry determines the sign of the result
+    carry < 0 || (carry == 0 && !is_non_zero)
+}
+
+/**
+The digits of a finite decimal, streamed from least to most significant along with their position.
+*/
+struct Operand<E, I> {
+    is_negative: bool,
+    position: E,
+    remaining: usize,
+    digits: [u8; 3],
+    digit_index: usize,
+    declets: I,
+    most_significant_digit: u8,
+}
+
+fn operand<D: BinaryBuf>(
+    decimal: &D,
+    is_negative: bool,
+) -> Operand<D::Exponent, impl Iterator<Item = [u8; 3]> + '_> {
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    Operand {
+        is_negative,
+        position: exponent,
+        remaining: decimal.precision_digits(),
+        digits: [b'0'; 3],
+        digit_index: 0,
+        declets: decode_significand_trailing_declets_rev(decimal),
+        most_significant_digit: msd.get_ascii(),
+    }
+}
+
+impl<E: BinaryExponent, I: Iterator<Item = [u8; 3]>> Operand<E, I> {
+    /**
+    Get the next digit, negated if the decimal is subtracted.
+    */
+    fn next_digit(&mut self) -> i32 {
+        self.remaining -= 1;
+        self.position = self.position.raise(1);
+
+        let digit = if self.remaining == 0 {
+            self.most_significant_digit
+        } else {
+            if self.digit_index == 0 {
+                self.digits = self.declets.next().unwrap_or([b'0'; 3]);
+                self.digit_index = 3;
+            }
+
+            self.digit_index -= 1;
+            self.digits[self.digit_index]
+        };
+
+        let digit = (digit - b'0') as i32;
+
+        if self.is_negative {
+            -digit
+        } else {
+            digit
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::DynamicBinaryBuf,
+        convert::decimal_from_str,
+    };
+
+    fn parse(s: &str) -> DynamicBinaryBuf<20> {
+        decimal_from_str(s).expect("failed to parse decimal")
+    }
+
+    #[test]
+    fn approx_eq_finite() {
+        for (a, b, tolerance, expected) in [
+            ("1", "1.000", "0", true),
+            ("0", "-0", "0", true),
+            ("1", "1.1", "0.1", true),
+            ("1", "1.1", "0.09", false),
+            ("1.1", "1", "0.1", true),
+            ("-1", "1", "2", true),
+            ("-1", "1", "1.9999", false),
+            ("1", "-1", "2.0000001", true),
+            ("-1.05", "-1.1", "0.05", true),
+            ("-1.05", "-1.1", "0.049", false),
+            ("99.9", "100", "0.1", true),
+            ("100", "99.9", "0.09999", false),
+            ("123456", "123457", "1", true),
+            ("123456", "123457", "0.9999999999", false),
+            ("1e10", "1e10", "0", true),
+            ("1e10", "1.0000000001e10", "1", true),
+            ("1e10", "1.0000000001e10", "0.999", false),
+            // The difference here can't be represented, but it can still be compared
+            ("1e300", "1e-300", "1e300", true),
+            ("1e300", "1e-300", "9.99999e299", false),
+            ("1e-300", "-1e-300", "2e-300", true),
+            ("1e-300", "-1e-300", "1.9e-300", false),
+            ("5e10", "0", "5e10", true),
+            ("5e10", "0", "4.9e10", false),
+        ] {
+            assert_eq!(
+                expected,
+                decimal_approx_eq(&parse(a), &parse(b), &parse(tolerance)),
+                "|{} - {}| <= {}",
+                a,
+                b,
+                tolerance
+            );
+        }
+    }
+
+    #[test]
+    fn approx_eq_special() {
+        for (a, b, tolerance, expected) in [
+            ("inf", "inf", "0", true),
+            ("-inf", "inf", "1e300", false),
+            ("inf", "1", "1e300", false),
+            ("inf", "1", "inf", true),
+            ("-inf", "inf", "inf", true),
+            ("1", "2", "-0", false),
+            ("1", "1", "-0", true),
+            ("1", "1", "-1", false),
+            ("1", "2", "-inf", false),
+            ("nan", "nan", "inf", false),
+            ("nan", "1", "inf", false),
+            ("1", "1", "nan", false),
+        ] {
+            assert_eq!(
+                expected,
+                decimal_approx_eq(&parse(a), &parse(b), &parse(tolerance)),
+                "|{} - {}| <= {}",
+                a,
+                b,
+                tolerance
+            );
+        }
+    }
+}
@@ -0,0 +1,509 @@
+/*!
+Add, subtract, and multiply decimals exactly.
+
+None of the operations in this module round. If the exact result of an operation can't be encoded
+then there's no result, instead of an infinity or a rounded value.
+*/
+
+use core::{
+    cmp,
+    iter,
+};
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_digits_rev,
+        encode_combination_infinity,
+        encode_combination_nan,
+        is_infinite,
+        is_nan,
+        is_sign_negative,
+        BinaryBuf,
+    },
+    convert::encode_finite_fit_buf,
+    num::Integer,
+    ops::{
+        decimal_is_zero,
+        encode_quiet_nan,
+    },
+};
+
+/**
+Add two decimals, returning `None` if the exact sum can't be encoded.
+
+This is IEEE 754's `addition`, except the sum is never rounded. Its exponent is the smaller of
+the two exponents, unless trailing zeroes need to be removed for the sum to fit, so `1.50 + 1`
+is `2.50`. A sum of zero is `0`, unless both decimals are negative, in which case it's `-0`.
+
+If either decimal is a NaN then the first NaN is returned as a quiet NaN. The sum of two infinities
+with different signs is a quiet NaN without a payload.
+
+The sum has the width of the wider of the two decimals.
+*/
+pub(crate) fn decimal_checked_add<D: BinaryBuf>(a: &D, b: &D) -> Option<D> {
+    decimal_checked_add_signed(a, b, is_sign_negative(b))
+}
+
+/**
+Subtract one decimal from another, returning `None` if the exact difference can't be encoded.
+
+This is the same as adding `a` to the negation of `b`, so `1 - 1` is `0`, and `-0 - 0` is `-0`.
+*/
+pub(crate) fn decimal_checked_sub<D: BinaryBuf>(a: &D, b: &D) -> Option<D> {
+    decimal_checked_add_signed(a, b, !is_sign_negative(b))
+}
+
+/**
+Multiply two decimals, returning `None` if the exact product can't be encoded.
+
+This is IEEE 754's `multiplication`, except the product is never rounded. Its exponent is the sum
+of the two exponents, unless trailing zeroes need to be removed or added for the product to fit,
+so `1.5 * 1.0` is `1.50`. The product is negative if exactly one of the decimals is, including
+zeroes.
+
+If either decimal is a NaN then the first NaN is returned as a quiet NaN. The product of an infinity
+and a zero is a quiet NaN without a payload.
+
+The product has the width of the wider of the two decimals.
+*/
+pub(crate) fn decimal_checked_mul<D: BinaryBuf>(a: &D, b: &D) -> Option<D> {
+    let width_bytes = cmp::max(a.bytes().len(), b.bytes().len());
+    let is_negative = is_sign_negative(a) != is_sign_negative(b);
+
+    if let Some(nan) = first_nan(a, b) {
+        return encode_quiet_nan(nan, nan.bytes().len()).ok();
+    }
+
+    if is_infinite(a) || is_infinite(b) {
+        // Multiplying an infinity by zero is invalid
+        return if decimal_is_zero(a) || decimal_is_zero(b) {
+            encode_invalid(width_bytes)
+        } else {
+            encode_infinity(is_negative, width_bytes)
+        };
+    }
+
+    let exponent = exponent(a)? + exponent(b)?;
+
+    let a_digits = significant_digits(a);
+    let b_digits = significant_digits(b);
+
+    if a_digits == 0 || b_digits == 0 {
+        return fit(is_negative, || iter::once(b'0'), exponent, width_bytes);
+    }
+
+    fit(
+        is_negative,
+        || digits_mul(a, a_digits, b, b_digits),
+        exponent,
+        width_bytes,
+    )
+}
+
+/**
+Add `b` to `a`, using `b_is_negative` as the sign of `b` instead of its own.
+*/
+fn decimal_checked_add_signed<D: BinaryBuf>(a: &D, b: &D, b_is_negative: bool) -> Option<D> {
+    let width_bytes = cmp::max(a.bytes().len(), b.bytes().len());
+    let a_is_negative = is_sign_negative(a);
+
+    if let Some(nan) = first_nan(a, b) {
+        return encode_quiet_nan(nan, nan.bytes().len()).ok();
+    }
+
+    match (is_infinite(a), is_infinite(b)) {
+        // Adding infinities with different signs is invalid
+        (true, true) if a_is_negative != b_is_negative => return encode_invalid(width_bytes),
+        (true, _) => return encode_infinity(a_is_negative, width_bytes),
+        (false, true) => return encode_infinity(b_is_negative, width_bytes),
+        (false, false) => (),
+    }
+
+    let precision_digits = D::try_with_exactly_storage_width_bytes(width_bytes)
+        .ok()?
+        .precision_digits() as i64;
+
+    let mut a_exponent = exponent(a)?;
+    let mut b_exponent = exponent(b)?;
+
+    match (decimal_is_zero(a), decimal_is_zero(b)) {
+        // The sum of two zeroes is a zero with the smaller exponent
+        (true, true) => {
+            return fit(
+                a_is_negative && b_is_negative,
+                || iter::once(b'0'),
+                cmp::min(a_exponent, b_exponent),
+                width_bytes,
+            );
+        }
+        // Adding a zero doesn't change the value of the other decimal, so the zero only
+        // needs to be aligned closely enough to still pick the exponent of the sum
+        (true, false) => {
+            a_exponent = a_exponent.clamp(b_exponent - precision_digits, b_exponent);
+        }
+        (false, true) => {
+            b_exponent = b_exponent.clamp(a_exponent - precision_digits, a_exponent);
+        }
+        // If the decimals are further apart than twice the precision then there are more digits
+        // between the most significant digit of one and the least significant digit of the other
+        // than can be encoded
+        (false, false) => {
+            if (a_exponent - b_exponent).abs() > 2 * precision_digits {
+                return None;
+            }
+        }
+    }
+
+    let exponent = cmp::min(a_exponent, b_exponent);
+
+    let a_shift = (a_exponent - exponent) as usize;
+    let b_shift = (b_exponent - exponent) as usize;
+
+    // The extra digit makes room for a carry
+    let len = cmp::max(
+        a_shift + a.precision_digits(),
+        b_shift + b.precision_digits(),
+    ) + 1;
+
+    if a_is_negative == b_is_negative {
+        return fit(
+            a_is_negative,
+            || {
+                digits_add(
+                    aligned_digits(a, a_shift, len),
+                    aligned_digits(b, b_shift, len),
+                )
+            },
+            exponent,
+            width_bytes,
+        );
+    }
+
+    // Subtract the smaller magnitude from the larger one,
+    // so the difference has the sign of the larger one
+    let ((larger, larger_shift, is_negative), (smaller, smaller_shift)) = if magnitude_is_less(
+        aligned_digits(a, a_shift, len),
+        aligned_digits(b, b_shift, len),
+    ) {
+        ((b, b_shift, b_is_negative), (a, a_shift))
+    } else {
+        ((a, a_shift, a_is_negative), (b, b_shift))
+    };
+
+    let difference = || {
+        digits_sub(
+            aligned_digits(larger, larger_shift, len),
+            aligned_digits(smaller, smaller_shift, len),
+        )
+    };
+
+    // A difference of zero is always positive
+    let is_negative = is_negative && difference().any(|digit| digit != b'0');
+
+    fit(is_negative, difference, exponent, width_bytes)
+}
+
+/**
+Encode the digits of an exact result in a buffer of `width_bytes`, adjusting its exponent to fit
+if needed.
+*/
+fn fit<D: BinaryBuf, I: Iterator<Item = u8>>(
+    is_negative: bool,
+    digits: impl Fn() -> I,
+    exponent: i64,
+    width_bytes: usize,
+) -> Option<D> {
+    let buf = D::try_with_exactly_storage_width_bytes(width_bytes).ok()?;
+
+    encode_finite_fit_buf(buf, is_negative, digits, exponent).ok()
+}
+
+fn encode_infinity<D: BinaryBuf>(is_negative: bool, width_bytes: usize) -> Option<D> {
+    let mut buf = D::try_with_exactly_storage_width_bytes(width_bytes).ok()?;
+    encode_combination_infinity(&mut buf, is_negative);
+
+    Some(buf)
+}
+
+fn encode_invalid<D: BinaryBuf>(width_bytes: usize) -> Option<D> {
+    let mut buf = D::try_with_exactly_storage_width_bytes(width_bytes).ok()?;
+    encode_combination_nan(&mut buf, false, false);
+
+    Some(buf)
+}
+
+/**
+Get the first of two decimals that's a NaN, if either of them is.
+*/
+fn first_nan<'a, D: BinaryBuf>(a: &'a D, b: &'a D) -> Option<&'a D> {
+    if is_nan(a) {
+        Some(a)
+    } else if is_nan(b) {
+        Some(b)
+    } else {
+        None
+    }
+}
+
+/**
+Get the exponent of a finite decimal, if it's small enough to work with.
+*/
+fn exponent<D: BinaryBuf>(decimal: &D) -> Option<i64> {
+    let (exponent, _) = decode_combination_finite(decimal);
+
+    Some(exponent.to_i32()? as i64)
+}
+
+/**
+Stream the digits of a finite decimal's significand, from least to most significant.
+*/
+fn digits<D: BinaryBuf>(decimal: &D) -> impl Iterator<Item = u8> + '_ {
+    let (_, msd) = decode_combination_finite(decimal);
+
+    decode_significand_trailing_digits_rev(decimal).chain(iter::once(msd.get_ascii()))
+}
+
+/**
+The number of digits in a finite decimal's significand, up to and including the most significant
+non-zero one.
+*/
+fn significant_digits<D: BinaryBuf>(decimal: &D) -> usize {
+    digits(decimal)
+        .enumerate()
+        .filter(|(_, digit)| *digit != b'0')
+        .last()
+        .map(|(i, _)| i + 1)
+        .unwrap_or(0)
+}
+
+/**
+Stream the digits of a finite decimal's significand after shifting them up by `shift` places,
+padding them with leading zeroes to `len` digits.
+*/
+fn aligned_digits<D: BinaryBuf>(
+    decimal: &D,
+    shift: usize,
+    len: usize,
+) -> impl Iterator<Item = u8> + '_ {
+    iter::repeat_n(b'0', shift)
+        .chain(digits(decimal))
+        .chain(iter::repeat(b'0'))
+        .take(len)
+}
+
+/**
+Add two streams of digits, from least to most significant.
+
+The digits must have enough leading zeroes to hold any carry.
+*/
+fn digits_add(
+    a: impl Iterator<Item = u8>,
+    b: impl Iterator<Item = u8>,
+) -> impl Iterator<Item = u8> {
+    a.zip(b).scan(0, |carry, (a, b)| {
+        let digit = (a - b'0') + (b - b'0') + *carry;
+        *carry = digit / 10;
+
+        Some(b'0' + digit % 10)
+    })
+}
+
+/**
+Subtract a stream of digits from a larger one, from least to most significant.
+*/
+fn digits_sub(
+    a: impl Iterator<Item = u8>,
+    b: impl Iterator<Item = u8>,
+) -> impl Iterator<Item = u8> {
+    a.zip(b).scan(0, |borrow, (a, b)| {
+        let (a, b) = (a - b'0', b - b'0' + *borrow);
+
+        if a < b {
+            *borrow = 1;
+
+            Some(b'0' + a + 10 - b)
+        } else {
+            *borrow = 0;
+
+            Some(b'0' + a - b)
+        }
+    })
+}
+
+/**
+Whether one stream of digits is smaller than another of the same length.
+
+This is the case if subtracting `b` from `a` would leave a borrow.
+*/
+fn magnitude_is_less(a: impl Iterator<Item = u8>, b: impl Iterator<Item = u8>) -> bool {
+    a.zip(b).fold(false, |borrow, (a, b)| a < b + borrow as u8)
+}
+
+/**
+Multiply the significands of two finite decimals, from least to most significant digit.
+*/
+fn digits_mul<'a, D: BinaryBuf>(
+    a: &'a D,
+    a_digits: usize,
+    b: &'a D,
+    b_digits: usize,
+) -> impl Iterator<Item = u8> + 'a {
+    (0..a_digits + b_digits).scan(0usize, move |carry, k| {
+        // Sum the products of every pair of digits whose places add up to `k`
+        let digit = digits(a)
+            .enumerate()
+            .take(a_digits)
+            .skip(k.saturating_sub(b_digits - 1))
+            .take_while(|(i, _)| *i <= k)
+            .map(|(i, a)| {
+                let b = digits(b).nth(k - i).unwrap_or(b'0');
+
+                (a - b'0') as usize * (b - b'0') as usize
+            })
+            .sum::<usize>()
+            + *carry;
+        *carry = digit / 10;
+
+        Some(b'0' + (digit % 10) as u8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::DynamicBinaryBuf,
+        convert::{
+            decimal_from_str,
+            decimal_to_fmt,
+        },
+    };
+
+    fn parse(s: &str) -> DynamicBinaryBuf<20> {
+        decimal_from_str(s).expect("failed to parse decimal")
+    }
+
+    fn fmt(d: &DynamicBinaryBuf<20>) -> String {
+        let mut s = String::new();
+        decimal_to_fmt(d, &mut s).expect("failed to format decimal");
+
+        s
+    }
+
+    #[test]
+    fn checked_add() {
+        for (a, b, expected) in [
+            ("1.5", "1.25", Some("2.75")),
+            ("1.50", "1", Some("2.50")),
+            ("-1.5", "1.25", Some("-0.25")),
+            ("1.25", "-1.5", Some("-0.25")),
+            ("1e5", "-1", Some("99999")),
+            ("123", "0.00", Some("123.00")),
+            ("0e5", "1.5", Some("1.5")),
+            ("0e-90", "1", Some("1.000000")),
+            ("9999999", "1", Some("1.000000e7")),
+            ("1", "1.0000000001", Some("2.0000000001")),
+            ("1", "1e-10", None),
+            ("1", "-1", Some("0")),
+            ("-1", "1", Some("0")),
+            ("0", "-0", Some("0")),
+            ("-0", "-0e-3", Some("-0.000")),
+            ("5e-101", "5e-101", Some("1.0e-100")),
+            ("9999999", "0.1", None),
+            ("1234567", "0.5", None),
+            ("1e20", "1", None),
+            ("9.999999e96", "1e90", None),
+        ] {
+            assert_eq!(
+                expected.map(String::from),
+                decimal_checked_add(&parse(a), &parse(b)).map(|d| fmt(&d)),
+                "{} + {}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn checked_sub() {
+        for (a, b, expected) in [
+            ("1.5", "1.25", Some("0.25")),
+            ("1.25", "1.5", Some("-0.25")),
+            ("-1.5", "1.25", Some("-2.75")),
+            ("1", "1", Some("0")),
+            ("-1", "-1", Some("0")),
+            ("-0", "0", Some("-0")),
+            ("0", "0", Some("0")),
+            ("1e7", "1", Some("9999999")),
+            ("1e20", "1", None),
+        ] {
+            assert_eq!(
+                expected.map(String::from),
+                decimal_checked_sub(&parse(a), &parse(b)).map(|d| fmt(&d)),
+                "{} - {}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn checked_mul() {
+        for (a, b, expected) in [
+            ("1.5", "1.25", Some("1.875")),
+            ("-2", "3", Some("-6")),
+            ("-2", "-3", Some("6")),
+            ("1.0", "1.0", Some("1.00")),
+            ("0", "-5", Some("-0")),
+            ("0e5", "1e3", Some("0e8")),
+            ("99", "99", Some("9801")),
+            ("1000000", "1000000", Some("1.000000e12")),
+            ("5e-101", "2e-1", Some("1e-101")),
+            ("1234567", "1234567", None),
+            ("1e90", "1e90", None),
+            ("1e-90", "1e-90", None),
+        ] {
+            assert_eq!(
+                expected.map(String::from),
+                decimal_checked_mul(&parse(a), &parse(b)).map(|d| fmt(&d)),
+                "{} * {}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn checked_special() {
+        for (a, b, expected_sum, expected_product) in [
+            ("inf", "1", "inf", "inf"),
+            ("inf", "-1", "inf", "-inf"),
+            ("-inf", "-inf", "-inf", "inf"),
+            ("inf", "-inf", "nan", "-inf"),
+            ("inf", "0", "inf", "nan"),
+            ("-0", "-inf", "-inf", "nan"),
+            ("nan(3)", "1", "nan(3)", "nan(3)"),
+            ("1", "snan(4)", "nan(4)", "nan(4)"),
+            ("-snan(1)", "nan(2)", "-nan(1)", "-nan(1)"),
+            ("inf", "nan(5)", "nan(5)", "nan(5)"),
+        ] {
+            assert_eq!(
+                expected_sum,
+                fmt(&decimal_checked_add(&parse(a), &parse(b)).expect("failed to add decimals")),
+                "{} + {}",
+                a,
+                b
+            );
+            assert_eq!(
+                expected_product,
+                fmt(&decimal_checked_mul(&parse(a), &parse(b))
+                    .expect("failed to multiply decimals")),
+                "{} * {}",
+                a,
+                b
+            );
+        }
+    }
+}
@@ -0,0 +1,636 @@
+/*!
+Compare decimals by their numeric value.
+*/
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter,
+};
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_declets,
+        is_finite,
+        is_infinite,
+        is_nan,
+        is_sign_negative,
+        is_signaling_nan,
+        set_nan_signaling,
+        BinaryBuf,
+        BinaryExponent,
+    },
+    num::Integer,
+};
+
+/**
+Compare two decimals by their numeric value.
+
+Different encodings of the same value, like `1.0` and `1`, compare as equal, as do `0` and `-0`.
+If either decimal is a NaN then they're unordered and `None` is returned.
+
+The decimals don't need to be the same width, so a 32-bit `1.5` is equal to a 64-bit `1.50`.
+*/
+pub(crate) fn decimal_cmp<A: BinaryBuf, B: BinaryBuf>(a: &A, b: &B) -> Option<Ordering> {
+    if is_nan(a) || is_nan(b) {
+        return None;
+    }
+
+    let a_is_negative = is_sign_negative(a);
+    let b_is_negative = is_sign_negative(b);
+
+    // Infinities are ordered by their sign before anything else
+    match (is_infinite(a), is_infinite(b)) {
+        (true, true) => return Some(b_is_negative.cmp(&a_is_negative)),
+        (true, false) => {
+            return Some(if a_is_negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            })
+        }
+        (false, true) => {
+            return Some(if b_is_negative {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            })
+        }
+        (false, false) => (),
+    }
+
+    let a_digits = significant_digits(a).count();
+    let b_digits = significant_digits(b).count();
+
+    // Zeroes are equal regardless of their sign or exponent,
+    // so they're ordered only by the sign of the other number
+    match (a_digits, b_digits) {
+        (0, 0) => return Some(Ordering::Equal),
+        (0, _) => {
+            return Some(if b_is_negative {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            })
+        }
+        (_, 0) => {
+            return Some(if a_is_negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            })
+        }
+        _ => (),
+    }
+
+    if a_is_negative != b_is_negative {
+        return Some(b_is_negative.cmp(&a_is_negative));
+    }
+
+    let magnitude = magnitude_cmp(a, a_digits, b, b_digits);
+
+    // If both numbers are negative then the one with the larger magnitude is the smaller number
+    if a_is_negative {
+        Some(magnitude.reverse())
+    } else {
+        Some(magnitude)
+    }
+}
+
+/**
+Compare two decimals using the IEEE 754 `totalOrder` predicate.
+
+Unlike [`decimal_cmp`], every decimal is ordered, including NaNs and different encodings of the
+same value. From least to greatest, the order is:
+
+- negative quiet NaNs, ordered by descending payload.
+- negative signaling NaNs, ordered by descending payload.
+- negative infinity.
+- negative finite numbers, with equal values ordered by descending exponent, so `-1.0` comes before `-1.00`.
+- positive finite numbers, with equal values ordered by ascending exponent, so `1.00` comes before `1.0`.
+- positive infinity.
+- positive signaling NaNs, ordered by ascending payload.
+- positive quiet NaNs, ordered by ascending payload.
+
+Zeroes are ordered like any other finite number, so `-0` comes before `0`.
+Decimals are only equal if they're encoded with the same sign, exponent, and significand.
+*/
+pub(crate) fn decimal_total_cmp<D: BinaryBuf>(a: &D, b: &D) -> Ordering {
+    let a_is_negative = is_sign_negative(a);
+    let b_is_negative = is_sign_negative(b);
+
+    if a_is_negative != b_is_negative {
+        return b_is_negative.cmp(&a_is_negative);
+    }
+
+    let magnitude = total_magnitude_cmp(a, b);
+
+    // If both numbers are negative then the one with the larger magnitude is the smaller number
+    if a_is_negative {
+        magnitude.reverse()
+    } else {
+        magnitude
+    }
+}
+
+/**
+Pick the smaller of two decimals, like IEEE 754's `minimumNumber`.
+
+If only one of the decimals is a NaN then the other is picked. If both are NaNs then the first is
+picked as a quiet NaN. Otherwise, the decimals are compared using `totalOrder`, so `-0` is smaller
+than `0`, and when two decimals are equal the one with the smaller exponent is picked if they're
+positive, or the one with the larger exponent if they're negative.
+*/
+pub(crate) fn decimal_min<D: BinaryBuf + Clone>(a: &D, b: &D) -> D {
+    decimal_select(a, b, Ordering::Greater)
+}
+
+/**
+Pick the larger of two decimals, like IEEE 754's `maximumNumber`.
+
+NaNs are handled the same way as in [`decimal_min`], and the decimals are compared using `totalOrder`.
+*/
+pub(crate) fn decimal_max<D: BinaryBuf + Clone>(a: &D, b: &D) -> D {
+    decimal_select(a, b, Ordering::Less)
+}
+
+/**
+Bound a decimal to the range `[min, max]`, comparing decimals by their numeric value.
+
+If the decimal is less than `min` then `min` is returned, and if it's greater than `max` then `max`
+is returned. Otherwise the decimal is returned unchanged, so a decimal that's equal to one of the
+bounds keeps its own exponent. NaNs are returned unchanged. A bound that's a NaN doesn't compare
+with anything, so it's ignored.
+*/
+pub(crate) fn decimal_clamp<D: BinaryBuf + Clone>(decimal: &D, min: &D, max: &D) -> D {
+    debug_assert!(
+        matches!(
+            decimal_cmp(min, max),
+            Some(Ordering::Less | Ordering::Equal)
+        ),
+        "the minimum of the range must be less than or equal to the maximum"
+    );
+
+    match (decimal_cmp(decimal, min), decimal_cmp(decimal, max)) {
+        (Some(Ordering::Less), _) => min.clone(),
+        (_, Some(Ordering::Greater)) => max.clone(),
+        _ => decimal.clone(),
+    }
+}
+
+/**
+Pick `b` if it's a number and `a` is a NaN, or if `a` compares to `b` with `pick_b`. Otherwise pick `a`.
+*/
+fn decimal_select<D: BinaryBuf + Clone>(a: &D, b: &D, pick_b: Ordering) -> D {
+    let mut picked = match (is_nan(a), is_nan(b)) {
+        (true, false) => b.clone(),
+        (false, true) | (true, true) => a.clone(),
+        (false, false) if decimal_total_cmp(a, b) == pick_b => b.clone(),
+        (false, false) => a.clone(),
+    };
+
+    // If both decimals are NaNs then the result is quiet
+    set_nan_signaling(&mut picked, false);
+
+    picked
+}
+
+/**
+Compare the absolute values of two decimals using the IEEE 754 `totalOrder` predicate.
+*/
+fn total_magnitude_cmp<D: BinaryBuf>(a: &D, b: &D) -> Ordering {
+    // Finite numbers come before infinities, which come before signaling and then quiet NaNs
+    fn rank<D: BinaryBuf>(decimal: &D) -> u8 {
+        if is_nan(decimal) {
+            if is_signaling_nan(decimal) {
+                2
+            } else {
+                3
+            }
+        } else if is_infinite(decimal) {
+            1
+        } else {
+            0
+        }
+    }
+
+    match (rank(a), rank(b)) {
+        // Finite numbers are ordered by their value, then by their exponent
+        (0, 0) => {
+            let a_digits = significant_digits(a).count();
+            let b_digits = significant_digits(b).count();
+
+            let magnitude = match (a_digits, b_digits) {
+                (0, 0) => Ordering::Equal,
+                (0, _) => Ordering::Less,
+                (_, 0) => Ordering::Greater,
+                _ => magnitude_cmp(a, a_digits, b, b_digits),
+            };
+
+            magnitude.then_with(|| {
+                let (a_exponent, _) = decode_combination_finite(a);
+                let (b_exponent, _) = decode_combination_finite(b);
+
+                a_exponent.cmp(&b_exponent)
+            })
+        }
+        // Infinities with the same sign are equal
+        (1, 1) => Ordering::Equal,
+        // NaNs of the same kind are ordered by their payloads
+        (a_rank, b_rank) if a_rank == b_rank => nan_payload(a)
+            .count()
+            .cmp(&nan_payload(b).count())
+            .then_with(|| nan_payload(a).cmp(nan_payload(b))),
+        (a_rank, b_rank) => a_rank.cmp(&b_rank),
+    }
+}
+
+/**
+Whether the decimal is a zero, regardless of its sign or exponent.
+*/
+pub(crate) fn decimal_is_zero<D: BinaryBuf>(decimal: &D) -> bool {
+    is_finite(decimal) && significant_digits(decimal).next().is_none()
+}
+
+/**
+Compare the absolute values of two non-zero finite decimals.
+*/
+fn magnitude_cmp<A: BinaryBuf, B: BinaryBuf>(
+    a: &A,
+    a_digits: usize,
+    b: &B,
+    b_digits: usize,
+) -> Ordering {
+    let (a_exponent, _) = decode_combination_finite(a);
+    let (b_exponent, _) = decode_combination_finite(b);
+
+    // Raise each exponent by the number of significant digits so that the
+    // exponents refer to the position of the most significant digit.
+    // For example, `123e-1` and `1230e-2` both get an exponent of `2`
+    match exponent_cmp(&a_exponent.raise(a_digits), &b_exponent.raise(b_digits)) {
+        Ordering::Equal => (),
+        ordering => return ordering,
+    }
+
+    // If the most significant digits are in the same position then compare
+    // the digits themselves. The shorter number is padded with trailing zeroes
+    let pad = iter::repeat(b'0');
+
+    significant_digits(a)
+        .chain(pad.clone())
+        .zip(significant_digits(b).chain(pad))
+        .take(a_digits.max(b_digits))
+        .map(|(a, b)| a.cmp(&b))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/**
+Compare two exponents, which may be of different types.
+*/
+fn exponent_cmp<A: Integer, B: Integer>(a: &A, b: &B) -> Ordering {
+    match (a.to_i32(), b.to_i32()) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        // An exponent that doesn't fit in an `i32` is further from zero than one that does
+        (None, Some(_)) => {
+            if a.is_negative() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }
+        (Some(_), None) => {
+            if b.is_negative() {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        }
+        // Exponents this large only come from arbitrary precision decimals. They're
+        // compared by their digits so no allocations are needed to convert between them
+        (None, None) => {
+            let (a_is_negative, b_is_negative) = (a.is_negative(), b.is_negative());
+
+            if a_is_negative != b_is_negative {
+                return b_is_negative.cmp(&a_is_negative);
+            }
+
+            let a_len = integer_digit(a, usize::MAX).0;
+            let b_len = integer_digit(b, usize::MAX).0;
+
+            let magnitude = a_len.cmp(&b_len).then_with(|| {
+                (0..a_len)
+                    .map(|i| integer_digit(a, i).1.cmp(&integer_digit(b, i).1))
+                    .find(|ordering| *ordering != Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            });
+
+            if a_is_negative {
+                magnitude.reverse()
+            } else {
+                magnitude
+            }
+        }
+    }
+}
+
+/**
+Get the number of digits in an integer, along with its digit at the given index, if there is one.
+
+The sign of the integer isn't counted as a digit.
+*/
+fn integer_digit<I: Integer>(integer: &I, index: usize) -> (usize, Option<u8>) {
+    struct Digits {
+        index: usize,
+        len: usize,
+        digit: Option<u8>,
+    }
+
+    impl fmt::Write for Digits {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            for b in s.bytes().filter(u8::is_ascii_digit) {
+                if self.len == self.index {
+                    self.digit = Some(b);
+                }
+
+                self.len += 1;
+            }
+
+            Ok(())
+        }
+    }
+
+    let mut digits = Digits {
+        index,
+        len: 0,
+        digit: None,
+    };
+
+    let _ = integer.to_fmt(&mut digits);
+
+    (digits.len, digits.digit)
+}
+
+/**
+Stream the digits of the significand of a finite decimal, skipping any leading zeroes.
+*/
+fn significant_digits<D: BinaryBuf>(decimal: &D) -> impl Iterator<Item = u8> + '_ {
+    let (_, msd) = decode_combination_finite(decimal);
+
+    iter::once(msd.get_ascii())
+        .chain(decode_significand_trailing_declets(decimal).flatten())
+        .skip_while(|digit| *digit == b'0')
+}
+
+/**
+Stream the digits of the payload of a NaN, skipping any leading zeroes.
+*/
+fn nan_payload<D: BinaryBuf>(decimal: &D) -> impl Iterator<Item = u8> + '_ {
+    decode_significand_trailing_declets(decimal)
+        .flatten()
+        .skip_while(|digit| *digit == b'0')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::DynamicBinaryBuf,
+        convert::decimal_from_str,
+    };
+
+    fn parse(s: &str) -> DynamicBinaryBuf<20> {
+        decimal_from_str(s).expect("failed to parse decimal")
+    }
+
+    #[test]
+    fn min_max() {
+        for (a, b, expected_min, expected_max) in [
+            ("1", "2", "1", "2"),
+            ("-1", "1", "-1", "1"),
+            ("1.5", "1.50", "1.50", "1.5"),
+            ("-1.5", "-1.50", "-1.5", "-1.50"),
+            ("1e2", "99.9", "99.9", "1e2"),
+            ("-0", "0", "-0", "0"),
+            ("0", "-0", "-0", "0"),
+            ("0e5", "0.00", "0.00", "0e5"),
+            ("-inf", "1", "-inf", "1"),
+            ("inf", "-inf", "-inf", "inf"),
+            ("nan", "1", "1", "1"),
+            ("1", "-snan(3)", "1", "1"),
+            ("nan(1)", "nan(2)", "nan(1)", "nan(1)"),
+            ("snan(1)", "nan(2)", "nan(1)", "nan(1)"),
+        ] {
+            let (a_dec, b_dec) = (parse(a), parse(b));
+
+            assert_eq!(
+                parse(expected_min).bytes(),
+                decimal_min(&a_dec, &b_dec).bytes(),
+                "{} min {}",
+                a,
+                b
+            );
+            assert_eq!(
+                parse(expected_max).bytes(),
+                decimal_max(&a_dec, &b_dec).bytes(),
+                "{} max {}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn clamp() {
+        for (d, min, max, expected) in [
+            ("1.5", "1", "2", "1.5"),
+            ("1.50", "1.0", "2.00", "1.50"),
+            ("0.5", "1.0", "2.00", "1.0"),
+            ("3", "1.0", "2.00", "2.00"),
+            ("1", "1.00", "2", "1"),
+            ("2.000", "1", "2", "2.000"),
+            ("-0", "0", "1", "-0"),
+            ("-inf", "-1e5", "1e5", "-1e5"),
+            ("inf", "-1e5", "1e5", "1e5"),
+            ("1", "1", "1", "1"),
+            ("nan(3)", "1", "2", "nan(3)"),
+            ("-snan", "1", "2", "-snan"),
+        ] {
+            assert_eq!(
+                parse(expected).bytes(),
+                decimal_clamp(&parse(d), &parse(min), &parse(max)).bytes(),
+                "{} clamp [{}, {}]",
+                d,
+                min,
+                max
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn clamp_invalid_range() {
+        decimal_clamp(&parse("1"), &parse("2"), &parse("1"));
+    }
+
+    #[test]
+    fn cmp_finite() {
+        for (a, b, expected) in [
+            ("0", "0", Ordering::Equal),
+            ("0", "-0", Ordering::Equal),
+            ("0e5", "0.000", Ordering::Equal),
+            ("1", "1.000", Ordering::Equal),
+            ("123e-1", "12.3", Ordering::Equal),
+            ("1", "2", Ordering::Less),
+            ("-1", "-2", Ordering::Greater),
+            ("-1", "1", Ordering::Less),
+            ("-0", "0.1", Ordering::Less),
+            ("0", "-0.1", Ordering::Greater),
+            ("9", "10", Ordering::Less),
+            ("99.9", "1e2", Ordering::Less),
+            ("1.23", "1.2300001", Ordering::Less),
+            ("-1.23", "-1.2300001", Ordering::Greater),
+            ("1e-101", "1e90", Ordering::Less),
+            ("9999999999999999e369", "1e-398", Ordering::Greater),
+        ] {
+            assert_eq!(
+                Some(expected),
+                decimal_cmp(&parse(a), &parse(b)),
+                "{} cmp {}",
+                a,
+                b
+            );
+            assert_eq!(
+                Some(expected.reverse()),
+                decimal_cmp(&parse(b), &parse(a)),
+                "{} cmp {}",
+                b,
+                a
+            );
+        }
+    }
+
+    #[test]
+    fn cmp_mixed_width() {
+        let a: DynamicBinaryBuf<4> = decimal_from_str("1.5").expect("failed to parse decimal");
+        let b: DynamicBinaryBuf<16> = decimal_from_str("1.500").expect("failed to parse decimal");
+
+        assert_eq!(Some(Ordering::Equal), decimal_cmp(&a, &b));
+        assert_eq!(Some(Ordering::Equal), decimal_cmp(&b, &a));
+
+        let b: DynamicBinaryBuf<16> =
+            decimal_from_str("1.5e-6000").expect("failed to parse decimal");
+
+        assert_eq!(Some(Ordering::Greater), decimal_cmp(&a, &b));
+        assert_eq!(Some(Ordering::Less), decimal_cmp(&b, &a));
+    }
+
+    #[test]
+    fn cmp_exponent() {
+        for (a, b, expected) in [
+            (1i64, 1i64, Ordering::Equal),
+            (-1, 1, Ordering::Less),
+            (i64::MAX, 1, Ordering::Greater),
+            (i64::MIN, 1, Ordering::Less),
+            (i64::MIN, i64::MAX, Ordering::Less),
+            (i64::MAX, i64::MAX - 1, Ordering::Greater),
+            (i64::MIN, i64::MIN + 1, Ordering::Less),
+            (10_000_000_000, 9_999_999_999, Ordering::Greater),
+            (-10_000_000_000, -9_999_999_999, Ordering::Less),
+        ] {
+            assert_eq!(expected, exponent_cmp(&a, &b), "{} cmp {}", a, b);
+            assert_eq!(expected.reverse(), exponent_cmp(&b, &a), "{} cmp {}", b, a);
+        }
+
+        assert_eq!(Ordering::Less, exponent_cmp(&5i32, &i64::MAX));
+        assert_eq!(Ordering::Greater, exponent_cmp(&5i32, &i64::MIN));
+    }
+
+    #[test]
+    fn cmp_infinity() {
+        for (a, b, expected) in [
+            ("inf", "inf", Ordering::Equal),
+            ("-inf", "-inf", Ordering::Equal),
+            ("-inf", "inf", Ordering::Less),
+            ("inf", "9999999999999999e369", Ordering::Greater),
+            ("-inf", "-9999999999999999e369", Ordering::Less),
+            ("inf", "-0", Ordering::Greater),
+        ] {
+            assert_eq!(
+                Some(expected),
+                decimal_cmp(&parse(a), &parse(b)),
+                "{} cmp {}",
+                a,
+                b
+            );
+            assert_eq!(
+                Some(expected.reverse()),
+                decimal_cmp(&parse(b), &parse(a)),
+                "{} cmp {}",
+                b,
+                a
+            );
+        }
+    }
+
+    #[test]
+    fn cmp_nan() {
+        for (a, b) in [
+            ("nan", "nan"),
+            ("nan", "1"),
+            ("snan", "inf"),
+            ("-nan(12)", "0"),
+        ] {
+            assert_eq!(None, decimal_cmp(&parse(a), &parse(b)), "{} cmp {}", a, b);
+            assert_eq!(None, decimal_cmp(&parse(b), &parse(a)), "{} cmp {}", b, a);
+        }
+    }
+
+    #[test]
+    fn total_cmp() {
+        let ordered = [
+            "-nan(12)",
+            "-nan(3)",
+            "-nan",
+            "-snan(5)",
+            "-snan",
+            "-inf",
+            "-9999999999999999e369",
+            "-1.0",
+            "-1.00",
+            "-0.5",
+            "-0e5",
+            "-0",
+            "-0.00",
+            "0.00",
+            "0",
+            "0e5",
+            "0.5",
+            "1.00",
+            "1.0",
+            "1",
+            "9999999999999999e369",
+            "inf",
+            "snan",
+            "snan(5)",
+            "nan",
+            "nan(3)",
+            "nan(12)",
+        ];
+
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(
+                    i.cmp(&j),
+                    decimal_total_cmp(&parse(a), &parse(b)),
+                    "{} total_cmp {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,775 @@
+/*!
+Inspect and change the cohort of decimals.
+
+A cohort is the set of encodings that a single value can have. `1.200`, `1.20`, and `1.2` are all
+in the same cohort, and differ only in the number of trailing zeroes in their coefficient.
+*/
+
+use core::{
+    cmp,
+    iter,
+};
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_digits_rev,
+        encode_combination_finite,
+        encode_combination_nan,
+        encode_significand_trailing_digits_rev,
+        is_finite,
+        is_infinite,
+        is_nan,
+        is_sign_negative,
+        BinaryBuf,
+        BinaryExponent,
+        CoefficientDigits,
+        MostSignificantDigit,
+    },
+    num::Integer,
+    ops::decimal_is_zero,
+    ConvertError,
+    OverflowError,
+};
+
+/**
+Decompose a finite decimal into its sign, coefficient digits, and exponent.
+
+If the decimal isn't finite, or its exponent doesn't fit in an `i32`, then `None` is returned.
+*/
+pub(crate) fn decimal_coefficient_and_exponent<D: BinaryBuf>(
+    decimal: &D,
+) -> Option<(bool, CoefficientDigits<'_>, i32)> {
+    if !is_finite(decimal) {
+        return None;
+    }
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    Some((
+        is_sign_negative(decimal),
+        CoefficientDigits::new(decimal, msd),
+        exponent.to_i32()?,
+    ))
+}
+
+/**
+Get the exponent of the most significant digit of a finite decimal.
+
+This is the exponent the decimal would be written with in scientific notation with a single digit
+before the decimal point, so it's the same for every member of a cohort. A zero coefficient counts
+as a single digit, so the adjusted exponent of a zero is its exponent.
+
+If the decimal isn't finite, or its adjusted exponent doesn't fit in an `i32`, then `None` is
+returned.
+*/
+pub(crate) fn decimal_adjusted_exponent<D: BinaryBuf>(decimal: &D) -> Option<i32> {
+    if !is_finite(decimal) {
+        return None;
+    }
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+    let significant_digits = cmp::max(coefficient_significant_digits(decimal, msd), 1);
+
+    exponent.raise(significant_digits - 1).to_i32()
+}
+
+/**
+Count the trailing zeroes in the coefficient of a decimal.
+
+If the decimal isn't finite, or its coefficient is zero, then `0` is returned.
+*/
+pub(crate) fn decimal_coefficient_trailing_zeros<D: BinaryBuf>(decimal: &D) -> u32 {
+    if !is_finite(decimal) {
+        return 0;
+    }
+
+    let (_, msd) = decode_combination_finite(decimal);
+
+    let digits = decode_significand_trailing_digits_rev(decimal).chain(iter::once(msd.get_ascii()));
+
+    // If every digit is zero then the coefficient itself is zero
+    digits
+        .enumerate()
+        .find(|(_, digit)| *digit != b'0')
+        .map(|(trailing_zeros, _)| trailing_zeros as u32)
+        .unwrap_or(0)
+}
+
+/**
+Whether a decimal is finite and has no fractional part.
+
+A decimal with a negative exponent can still be whole if its coefficient has enough trailing
+zeroes to cover it, like `1.0` or `1200e-2`. Zeroes are always whole.
+*/
+pub(crate) fn decimal_is_integer<D: BinaryBuf>(decimal: &D) -> bool {
+    if !is_finite(decimal) {
+        return false;
+    }
+
+    let (exponent, _) = decode_combination_finite(decimal);
+
+    // A zero coefficient doesn't have any trailing zeroes, but it's still whole
+    if !exponent.is_negative() || decimal_is_zero(decimal) {
+        return true;
+    }
+
+    let trailing_zeros = decimal_coefficient_trailing_zeros(decimal);
+
+    !exponent.raise(trailing_zeros as usize).is_negative()
+}
+
+/**
+Whether two decimals have the same quantum.
+
+This is the `sameQuantum` predicate from IEEE 754. Finite decimals have the same quantum if their
+exponents are equal, regardless of their coefficients. Infinities have the same quantum as other
+infinities, and NaNs have the same quantum as other NaNs.
+*/
+pub(crate) fn decimal_same_quantum<D: BinaryBuf>(a: &D, b: &D) -> bool {
+    if is_nan(a) || is_nan(b) {
+        return is_nan(a) && is_nan(b);
+    }
+
+    if is_infinite(a) || is_infinite(b) {
+        return is_infinite(a) && is_infinite(b);
+    }
+
+    decode_combination_finite(a).0 == decode_combination_finite(b).0
+}
+
+/**
+Get the smallest and largest exponents the value of a decimal could be encoded with.
+
+Lowering the exponent pads the coefficient with trailing zeroes, up to the precision of the
+decimal. Raising it removes trailing zeroes. Both are also limited by the exponent range of the
+decimal. Zeroes can be encoded with any exponent in that range.
+
+If the decimal isn't finite, or any of the exponents don't fit in an `i32`, then `None` is returned.
+*/
+pub(crate) fn decimal_cohort_exponent_range<D: BinaryBuf>(decimal: &D) -> Option<(i32, i32)> {
+    if !is_finite(decimal) {
+        return None;
+    }
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+    let exponent = exponent.to_i32()? as i64;
+
+    let precision_digits = decimal.precision_digits();
+
+    let min_exponent = D::Exponent::emin(decimal)
+        .lower(precision_digits - 1)
+        .to_i32()? as i64;
+    let max_exponent = D::Exponent::emax(decimal)
+        .lower(precision_digits - 1)
+        .to_i32()? as i64;
+
+    let significant_digits = coefficient_significant_digits(decimal, msd);
+
+    if significant_digits == 0 {
+        return Some((min_exponent as i32, max_exponent as i32));
+    }
+
+    let trailing_zeros = decimal_coefficient_trailing_zeros(decimal) as i64;
+
+    let lowest = cmp::max(
+        exponent - (precision_digits - significant_digits) as i64,
+        min_exponent,
+    );
+    let highest = cmp::min(exponent + trailing_zeros, max_exponent);
+
+    Some((lowest as i32, highest as i32))
+}
+
+/**
+Encode the value of a decimal with the given exponent, without rounding.
+
+The result has the same width as the decimal. The exponent must be within the decimal's
+[cohort exponent range](decimal_cohort_exponent_range), so only trailing zeroes are padded
+or removed from its coefficient.
+
+If the decimal isn't finite, or its value can't be encoded with the exponent, then an error
+is returned.
+*/
+pub(crate) fn decimal_rescale<D: BinaryBuf>(
+    decimal: &D,
+    exponent: i32,
+) -> Result<D, OverflowError> {
+    let width_bytes = decimal.bytes().len();
+
+    let (lowest_exponent, highest_exponent) =
+        decimal_cohort_exponent_range(decimal).ok_or_else(|| {
+            OverflowError::exponent_out_of_range(
+                width_bytes,
+                "only finite decimals with an exponent that fits in an `i32` can be rescaled",
+            )
+        })?;
+
+    if exponent < lowest_exponent || exponent > highest_exponent {
+        return Err(OverflowError::exponent_out_of_range(
+            width_bytes,
+            "the decimal can't be rescaled to the exponent without rounding",
+        ));
+    }
+
+    let mut buf = D::try_with_exactly_storage_width_bytes(width_bytes)?;
+    encode_rescaled(&mut buf, decimal, exponent);
+
+    Ok(buf)
+}
+
+/**
+Encode the value of a decimal with the given exponent, without rounding, widening it if needed.
+
+This is like [`decimal_rescale`], except if padding the coefficient with trailing zeroes needs
+more digits than the decimal can store, or the exponent is outside its range, then the result
+uses the smallest width that does fit. Decimals with a fixed width always keep it.
+*/
+pub(crate) fn decimal_rescale_fit<D: BinaryBuf>(
+    decimal: &D,
+    exponent: i32,
+) -> Result<D, OverflowError> {
+    let err = match decimal_rescale(decimal, exponent) {
+        Ok(buf) => return Ok(buf),
+        Err(err) => err,
+    };
+
+    if !is_finite(decimal) {
+        return Err(err);
+    }
+
+    let (current_exponent, msd) = decode_combination_finite(decimal);
+    let Some(current_exponent) = current_exponent.to_i32() else {
+        return Err(err);
+    };
+
+    let significant_digits = coefficient_significant_digits(decimal, msd);
+
+    // Widening the decimal can't avoid rounding away digits
+    let removed_zeroes = cmp::max(exponent as i64 - current_exponent as i64, 0) as usize;
+    let padded_zeroes = cmp::max(current_exponent as i64 - exponent as i64, 0) as usize;
+
+    if significant_digits > 0
+        && removed_zeroes > decimal_coefficient_trailing_zeros(decimal) as usize
+    {
+        return Err(err);
+    }
+
+    let mut buf = D::try_with_at_least_precision(
+        cmp::max(
+            (significant_digits + padded_zeroes).saturating_sub(removed_zeroes),
+            1,
+        ),
+        Some(&D::Exponent::from_i32(exponent)),
+    )?;
+    encode_rescaled(&mut buf, decimal, exponent);
+
+    Ok(buf)
+}
+
+/**
+Encode the digits of a finite decimal into a buffer with the given exponent.
+
+The buffer must be able to fit the digits with the exponent, without rounding.
+*/
+fn encode_rescaled<D: BinaryBuf>(buf: &mut D, decimal: &D, exponent: i32) {
+    let (current_exponent, msd) = decode_combination_finite(decimal);
+    let current_exponent = current_exponent
+        .to_i32()
+        .expect("the exponent fits in an `i32`") as i64;
+
+    // Raising the exponent removes trailing zeroes, and lowering it pads them
+    let removed_zeroes = cmp::max(exponent as i64 - current_exponent, 0) as usize;
+    let padded_zeroes = cmp::max(current_exponent - exponent as i64, 0) as usize;
+
+    let msd = encode_significand_trailing_digits_rev(
+        buf,
+        iter::repeat_n(b'0', padded_zeroes).chain(
+            decode_significand_trailing_digits_rev(decimal)
+                .chain(iter::once(msd.get_ascii()))
+                .skip(removed_zeroes),
+        ),
+    );
+    encode_combination_finite(
+        buf,
+        is_sign_negative(decimal),
+        D::Exponent::from_i32(exponent),
+        msd,
+    );
+}
+
+/**
+Encode the value of a decimal with as few coefficient digits as possible.
+
+This is the `reduce` operation from IEEE 754 and the General Decimal Arithmetic specification.
+Trailing zeroes are removed from the coefficient while raising the exponent, including the trailing
+zeroes of integers, so `1.2300` reduces to `1.23` and `100` reduces to `1e2`. The exponent can't be
+raised beyond the range of the decimal, so some trailing zeroes may be kept.
+
+Zeroes reduce to a zero with an exponent of `0`, keeping their sign. Infinities and NaNs, and
+arbitrary precision decimals with exponents that don't fit in an `i32`, are returned unchanged.
+*/
+pub(crate) fn decimal_reduce<D: BinaryBuf>(decimal: &D) -> D {
+    let width_bytes = decimal.bytes().len();
+
+    let exponent = if decimal_is_zero(decimal) {
+        Some(0)
+    } else {
+        decimal_cohort_exponent_range(decimal).map(|(_, highest_exponent)| highest_exponent)
+    };
+
+    let mut buf = D::try_with_exactly_storage_width_bytes(width_bytes)
+        .expect("the decimal's width is always supported");
+
+    match exponent {
+        Some(exponent) => encode_rescaled(&mut buf, decimal, exponent),
+        None => buf.bytes_mut().copy_from_slice(decimal.bytes()),
+    }
+
+    buf
+}
+
+/**
+Count the digits in the coefficient of a finite decimal, excluding leading zeroes.
+*/
+fn coefficient_significant_digits<D: BinaryBuf>(decimal: &D, msd: MostSignificantDigit) -> usize {
+    decode_significand_trailing_digits_rev(decimal)
+        .chain(iter::once(msd.get_ascii()))
+        .enumerate()
+        .filter(|(_, digit)| *digit != b'0')
+        .last()
+        .map(|(i, _)| i + 1)
+        .unwrap_or(0)
+}
+
+/**
+Encode the value of a decimal with the same exponent as a reference decimal, without rounding.
+
+This is the two-operand `quantize` operation from IEEE 754 and the General Decimal Arithmetic
+specification, except that it returns an error instead of rounding:
+
+- If either decimal is a NaN then the result is a quiet NaN with its sign and payload, preferring
+  the decimal over the reference.
+- If both decimals are infinite then the result is the decimal.
+- If only one of the decimals is infinite then the result is a quiet NaN.
+- Otherwise the decimal is quantized to the exponent of the reference, just like [`decimal_quantize`].
+
+The result has the same width as the decimal.
+*/
+pub(crate) fn decimal_quantize_like<D: BinaryBuf>(
+    decimal: &D,
+    reference: &D,
+) -> Result<D, ConvertError> {
+    let width_bytes = decimal.bytes().len();
+
+    // ±NaN123
+    if is_nan(decimal) || is_nan(reference) {
+        let nan = if is_nan(decimal) { decimal } else { reference };
+
+        return encode_quiet_nan(nan, width_bytes).map_err(quantize_overflow);
+    }
+
+    // ±Infinity
+    if is_infinite(decimal) || is_infinite(reference) {
+        let mut buf =
+            D::try_with_exactly_storage_width_bytes(width_bytes).map_err(quantize_overflow)?;
+
+        if is_infinite(decimal) && is_infinite(reference) {
+            buf.bytes_mut().copy_from_slice(decimal.bytes());
+        } else {
+            encode_combination_nan(&mut buf, false, false);
+        }
+
+        return Ok(buf);
+    }
+
+    let (exponent, _) = decode_combination_finite(reference);
+
+    // A reference with an exponent that doesn't fit in an `i32` is outside the range of
+    // any exponent the decimal could be quantized to
+    let exponent = exponent
+        .to_i32()
+        .ok_or_else(|| ConvertError::would_overflow("decimal"))?;
+
+    decimal_quantize_finite(decimal, exponent)
+}
+
+/**
+Encode the value of a decimal with the given exponent, without rounding.
+
+This is like [`decimal_quantize_like`] with a finite reference that has the given exponent:
+
+- If the decimal is a NaN then the result is a quiet NaN with its sign and payload.
+- If the decimal is infinite then the result is a quiet NaN.
+- If the decimal has non-zero digits below the exponent then an inexact error is returned.
+- Otherwise the decimal is [rescaled](decimal_rescale) to the exponent, which may overflow.
+
+The result has the same width as the decimal.
+*/
+pub(crate) fn decimal_quantize<D: BinaryBuf>(
+    decimal: &D,
+    exponent: i32,
+) -> Result<D, ConvertError> {
+    let width_bytes = decimal.bytes().len();
+
+    // ±NaN123
+    if is_nan(decimal) {
+        return encode_quiet_nan(decimal, width_bytes).map_err(quantize_overflow);
+    }
+
+    // ±Infinity
+    if is_infinite(decimal) {
+        let mut buf =
+            D::try_with_exactly_storage_width_bytes(width_bytes).map_err(quantize_overflow)?;
+        encode_combination_nan(&mut buf, false, false);
+
+        return Ok(buf);
+    }
+
+    decimal_quantize_finite(decimal, exponent)
+}
+
+fn decimal_quantize_finite<D: BinaryBuf>(decimal: &D, exponent: i32) -> Result<D, ConvertError> {
+    // Raising the exponent beyond the trailing zeroes would round away non-zero digits
+    if !decimal_is_zero(decimal) {
+        let (current_exponent, _) = decode_combination_finite(decimal);
+
+        if let Some(current_exponent) = current_exponent.to_i32() {
+            let trailing_zeros = decimal_coefficient_trailing_zeros(decimal) as i64;
+
+            if exponent as i64 > current_exponent as i64 + trailing_zeros {
+                return Err(ConvertError::inexact("decimal", exponent));
+            }
+        }
+    }
+
+    decimal_rescale(decimal, exponent).map_err(quantize_overflow)
+}
+
+/**
+Quantizing a decimal can only fail to encode it if the exponent needs more digits or a wider
+exponent range than the decimal has.
+*/
+fn quantize_overflow(_: OverflowError) -> ConvertError {
+    ConvertError::would_overflow("decimal")
+}
+
+/**
+Encode a quiet NaN with the sign and payload of another NaN.
+
+If the payload doesn't fit then its most significant digits are dropped.
+*/
+pub(crate) fn encode_quiet_nan<D: BinaryBuf>(
+    nan: &D,
+    width_bytes: usize,
+) -> Result<D, OverflowError> {
+    let mut buf = D::try_with_exactly_storage_width_bytes(width_bytes)?;
+
+    let payload_digits = buf.trailing_significand_digits();
+    encode_significand_trailing_digits_rev(
+        &mut buf,
+        decode_significand_trailing_digits_rev(nan).take(payload_digits),
+    );
+    encode_combination_nan(&mut buf, is_sign_negative(nan), false);
+
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::{
+            DynamicBinaryBuf,
+            FixedBinaryBuf,
+        },
+        convert::decimal_from_str,
+    };
+
+    fn parse(s: &str) -> DynamicBinaryBuf<20> {
+        decimal_from_str(s).expect("failed to parse decimal")
+    }
+
+    #[test]
+    fn coefficient_trailing_zeros() {
+        for (d, expected) in [
+            ("1", 0),
+            ("1.200", 2),
+            ("120", 1),
+            ("-1000", 3),
+            ("1e3", 0),
+            ("10e3", 1),
+            ("1000000", 6),
+            ("1000000000000000000000", 21),
+            ("0", 0),
+            ("0.000", 0),
+            ("-0e5", 0),
+            ("inf", 0),
+            ("nan(100)", 0),
+        ] {
+            assert_eq!(
+                expected,
+                decimal_coefficient_trailing_zeros(&parse(d)),
+                "{}",
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn adjusted_exponent() {
+        for (d, expected) in [
+            ("123.456", Some(2)),
+            ("0.00123", Some(-3)),
+            ("-1.230", Some(0)),
+            ("1e5", Some(5)),
+            ("1000", Some(3)),
+            ("0", Some(0)),
+            ("0.00", Some(-2)),
+            ("inf", None),
+            ("nan", None),
+        ] {
+            assert_eq!(expected, decimal_adjusted_exponent(&parse(d)), "{}", d);
+        }
+    }
+
+    #[test]
+    fn same_quantum() {
+        for (a, b, expected) in [
+            ("1.23", "4.56", true),
+            ("1.23", "-0.00", true),
+            ("1.2", "1.20", false),
+            ("1e3", "1000", false),
+            ("inf", "-inf", true),
+            ("nan", "snan(5)", true),
+            ("inf", "nan", false),
+            ("1", "inf", false),
+            ("nan", "1", false),
+        ] {
+            assert_eq!(
+                expected,
+                decimal_same_quantum(&parse(a), &parse(b)),
+                "{} {}",
+                a,
+                b
+            );
+            assert_eq!(
+                expected,
+                decimal_same_quantum(&parse(b), &parse(a)),
+                "{} {}",
+                b,
+                a
+            );
+        }
+    }
+
+    #[test]
+    fn cohort_exponent_range() {
+        for (d, expected) in [
+            ("1", Some((-15, 0))),
+            ("1.200", Some((-15, -1))),
+            ("-1000", Some((-12, 3))),
+            ("1234567890123456", Some((0, 0))),
+            ("1e-398", Some((-398, -398))),
+            ("1000e-398", Some((-398, -395))),
+            ("1e369", Some((354, 369))),
+            ("1000000000000000e369", Some((369, 369))),
+            ("0", Some((-398, 369))),
+            ("-0.000", Some((-398, 369))),
+            ("inf", None),
+            ("nan(100)", None),
+        ] {
+            let decimal: FixedBinaryBuf<8, i32> =
+                decimal_from_str(d).expect("failed to parse decimal");
+
+            assert_eq!(expected, decimal_cohort_exponent_range(&decimal), "{}", d);
+        }
+    }
+
+    #[test]
+    fn reduce() {
+        for (d, expected) in [
+            ("1.2300", "1.23"),
+            ("1.00", "1"),
+            ("100", "1e2"),
+            ("-1200e-5", "-0.012"),
+            ("123", "123"),
+            ("0.000", "0"),
+            ("-0e5", "-0"),
+            ("inf", "inf"),
+            ("-nan(123)", "-nan(123)"),
+        ] {
+            let decimal: FixedBinaryBuf<8, i32> =
+                decimal_from_str(d).expect("failed to parse decimal");
+            let expected: FixedBinaryBuf<8, i32> =
+                decimal_from_str(expected).expect("failed to parse decimal");
+
+            assert_eq!(expected.bytes(), decimal_reduce(&decimal).bytes(), "{}", d);
+        }
+
+        // The exponent can't be raised beyond the maximum
+        let decimal: FixedBinaryBuf<8, i32> =
+            decimal_from_str("1000e367").expect("failed to parse decimal");
+        let expected: FixedBinaryBuf<8, i32> =
+            decimal_from_str("10e369").expect("failed to parse decimal");
+
+        assert_eq!(expected.bytes(), decimal_reduce(&decimal).bytes());
+    }
+
+    #[test]
+    fn quantize() {
+        for (d, exponent, expected) in [
+            ("1.5", -2, "1.50"),
+            ("1.500", -1, "1.5"),
+            ("-120", 1, "-12e1"),
+            ("0.000", 2, "0e2"),
+            ("inf", -2, "nan"),
+            ("-snan(7)", 0, "-nan(7)"),
+        ] {
+            let decimal: FixedBinaryBuf<8, i32> =
+                decimal_from_str(d).expect("failed to parse decimal");
+            let expected: FixedBinaryBuf<8, i32> =
+                decimal_from_str(expected).expect("failed to parse decimal");
+
+            assert_eq!(
+                expected.bytes(),
+                decimal_quantize(&decimal, exponent)
+                    .expect("failed to quantize decimal")
+                    .bytes(),
+                "{}",
+                d
+            );
+        }
+
+        for (d, exponent, inexact) in [
+            ("1.234", -2, true),
+            ("125", 1, true),
+            ("1", -16, false),
+            ("0", 370, false),
+        ] {
+            let decimal: FixedBinaryBuf<8, i32> =
+                decimal_from_str(d).expect("failed to parse decimal");
+
+            let err = decimal_quantize(&decimal, exponent).unwrap_err();
+
+            assert_eq!(inexact, err.is_inexact(), "{}", d);
+            assert_eq!(!inexact, err.is_overflow(), "{}", d);
+        }
+    }
+
+    #[test]
+    fn rescale_fit() {
+        for (d, exponent, expected) in [
+            ("1.5", -2, Some("1.50")),
+            ("1.5", -10, Some("1.5000000000")),
+            ("123456.5", -4, Some("123456.5000")),
+            ("0", 100, Some("0e100")),
+            ("1.25", -1, None),
+            ("1e10", 0, Some("10000000000")),
+        ] {
+            let decimal: DynamicBinaryBuf<20> =
+                decimal_from_str(d).expect("failed to parse decimal");
+
+            let expected = expected.map(parse);
+
+            assert_eq!(
+                expected.as_ref().map(|expected| expected.bytes()),
+                decimal_rescale_fit(&decimal, exponent)
+                    .ok()
+                    .as_ref()
+                    .map(|rescaled| rescaled.bytes()),
+                "{} {}",
+                d,
+                exponent
+            );
+        }
+
+        // Fixed width decimals can't be widened
+        let decimal: FixedBinaryBuf<4, i32> =
+            decimal_from_str("123456.5").expect("failed to parse decimal");
+        assert!(decimal_rescale_fit(&decimal, -4).is_err());
+    }
+
+    #[test]
+    fn quantize_like() {
+        for (d, r, expected) in [
+            ("1.2", "0.001", Some("1.200")),
+            ("1.200", "5.0", Some("1.2")),
+            ("-3", "1e-2", Some("-3.00")),
+            ("120", "1e1", Some("12e1")),
+            ("1.25", "0.1", None),
+            ("1", "1e-16", None),
+            ("0", "1e-398", Some("0e-398")),
+            ("1.5", "inf", Some("nan")),
+            ("inf", "1.5", Some("nan")),
+            ("-inf", "inf", Some("-inf")),
+            ("1.5", "-nan(12)", Some("-nan(12)")),
+            ("snan(3)", "nan(4)", Some("nan(3)")),
+            ("inf", "snan", Some("nan")),
+        ] {
+            let decimal: FixedBinaryBuf<8, i32> =
+                decimal_from_str(d).expect("failed to parse decimal");
+            let reference: FixedBinaryBuf<8, i32> =
+                decimal_from_str(r).expect("failed to parse decimal");
+
+            let expected = expected.map(|expected| {
+                let expected: FixedBinaryBuf<8, i32> =
+                    decimal_from_str(expected).expect("failed to parse decimal");
+
+                expected
+            });
+
+            assert_eq!(
+                expected.as_ref().map(|expected| expected.bytes()),
+                decimal_quantize_like(&decimal, &reference)
+                    .ok()
+                    .as_ref()
+                    .map(|quantized| quantized.bytes()),
+                "{} {}",
+                d,
+                r
+            );
+        }
+    }
+
+    #[test]
+    fn rescale() {
+        for (d, exponent, expected) in [
+            ("1.2", -3, Some("1.200")),
+            ("1.200", -1, Some("1.2")),
+            ("-1.2", -1, Some("-1.2")),
+            ("120", 1, Some("1.2e2")),
+            ("1", -15, Some("1.000000000000000")),
+            ("1", -16, None),
+            ("1.25", -1, None),
+            ("1e-398", -397, None),
+            ("0", -398, Some("0e-398")),
+            ("-0.00", 5, Some("-0e5")),
+            ("0", 370, None),
+            ("inf", 0, None),
+            ("nan", 0, None),
+        ] {
+            let decimal: FixedBinaryBuf<8, i32> =
+                decimal_from_str(d).expect("failed to parse decimal");
+
+            let expected = expected.map(|expected| {
+                let expected: FixedBinaryBuf<8, i32> =
+                    decimal_from_str(expected).expect("failed to parse decimal");
+
+                expected
+            });
+
+            assert_eq!(
+                expected.as_ref().map(|expected| expected.bytes()),
+                decimal_rescale(&decimal, exponent)
+                    .ok()
+                    .as_ref()
+                    .map(|rescaled| rescaled.bytes()),
+                "{} {}",
+                d,
+                exponent
+            );
+        }
+    }
+}
@@ -0,0 +1,189 @@
+/*!
+Hash decimals consistently with their numeric equality.
+*/
+
+use core::{
+    fmt,
+    hash::{
+        Hash,
+        Hasher,
+    },
+    iter,
+};
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_declets,
+        is_infinite,
+        is_nan,
+        is_sign_negative,
+        is_signaling_nan,
+        BinaryBuf,
+        BinaryExponent,
+    },
+    num::Integer,
+    ops::decimal_coefficient_trailing_zeros,
+};
+
+/**
+Hash a decimal so that decimals that are equal by [`decimal_cmp`] have equal hashes.
+
+Decimals are hashed in a normalized form that doesn't depend on their cohort or width:
+
+- All zeroes hash the same, regardless of their sign or exponent.
+- Infinities hash their sign.
+- Other finite numbers hash their sign, their adjusted exponent, which is the position of their most
+  significant digit, and their significant digits with trailing zeroes removed.
+
+NaNs aren't equal to anything, so they're free to hash however they like. They hash their sign,
+whether they're signaling, and their payload.
+
+[`decimal_cmp`]: crate::ops::decimal_cmp
+*/
+pub(crate) fn decimal_hash<D: BinaryBuf, H: Hasher>(decimal: &D, state: &mut H) {
+    if is_nan(decimal) {
+        state.write_u8(0x03);
+        is_sign_negative(decimal).hash(state);
+        is_signaling_nan(decimal).hash(state);
+
+        for digit in decode_significand_trailing_declets(decimal)
+            .flatten()
+            .skip_while(|digit| *digit == b'0')
+        {
+            state.write_u8(digit);
+        }
+
+        return;
+    }
+
+    if is_infinite(decimal) {
+        state.write_u8(0x02);
+        is_sign_negative(decimal).hash(state);
+
+        return;
+    }
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    let digits = || {
+        iter::once(msd.get_ascii())
+            .chain(decode_significand_trailing_declets(decimal).flatten())
+            .skip_while(|digit| *digit == b'0')
+    };
+
+    let significant_digits = digits().count();
+
+    // `0`, `-0`, and `0e5` are all equal
+    if significant_digits == 0 {
+        state.write_u8(0x00);
+
+        return;
+    }
+
+    let trailing_zeros = decimal_coefficient_trailing_zeros(decimal) as usize;
+
+    state.write_u8(0x01);
+    is_sign_negative(decimal).hash(state);
+
+    // The adjusted exponent of a decimal is the same across its cohort, since adding
+    // a trailing zero to the coefficient also lowers its exponent by one
+    let adjusted_exponent = exponent.raise(significant_digits - 1);
+
+    match adjusted_exponent.to_i32() {
+        Some(adjusted_exponent) => adjusted_exponent.hash(state),
+        // Exponents that don't fit in an `i32` are only possible in arbitrary precision decimals
+        // and are hashed through their text form so they're consistent across widths
+        None => {
+            let _ = adjusted_exponent.to_fmt(HashWriter(state));
+        }
+    }
+
+    for digit in digits().take(significant_digits - trailing_zeros) {
+        state.write_u8(digit);
+    }
+
+    // Terminate the digits so they can't run into any subsequent hashed values
+    state.write_u8(0xff);
+}
+
+struct HashWriter<'a, H>(&'a mut H);
+
+impl<'a, H: Hasher> fmt::Write for HashWriter<'a, H> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::hash_map::DefaultHasher;
+
+    use crate::{
+        binary::{
+            DynamicBinaryBuf,
+            FixedBinaryBuf,
+        },
+        convert::decimal_from_str,
+        ops::decimal_cmp,
+    };
+
+    fn hash<D: BinaryBuf>(d: &D) -> u64 {
+        let mut state = DefaultHasher::new();
+        decimal_hash(d, &mut state);
+
+        state.finish()
+    }
+
+    #[test]
+    fn hash_equal_values() {
+        for (a, b) in [
+            ("1.0", "1.00"),
+            ("1", "1.000000"),
+            ("1200", "1.2e3"),
+            ("-1.5", "-15e-1"),
+            ("0", "-0"),
+            ("0", "0e5"),
+            ("-0.000", "0e-90"),
+            ("inf", "inf"),
+            ("-inf", "-inf"),
+            ("9999999999999999e369", "9999999999999999e369"),
+        ] {
+            let a: DynamicBinaryBuf<20> = decimal_from_str(a).expect("failed to parse decimal");
+            let b: DynamicBinaryBuf<20> = decimal_from_str(b).expect("failed to parse decimal");
+
+            assert_eq!(Some(core::cmp::Ordering::Equal), decimal_cmp(&a, &b));
+            assert_eq!(hash(&a), hash(&b), "{:?} {:?}", a.bytes(), b.bytes());
+        }
+    }
+
+    #[test]
+    fn hash_across_widths() {
+        let a: FixedBinaryBuf<4, i32> = decimal_from_str("1.50").expect("failed to parse decimal");
+        let b: FixedBinaryBuf<16, i32> =
+            decimal_from_str("1.5000").expect("failed to parse decimal");
+
+        assert_eq!(hash(&a), hash(&b));
+    }
+
+    #[test]
+    fn hash_different_values() {
+        for (a, b) in [
+            ("1", "-1"),
+            ("1", "10"),
+            ("1", "11"),
+            ("0", "1e-398"),
+            ("inf", "-inf"),
+            ("inf", "9999999999999999e369"),
+        ] {
+            let a: DynamicBinaryBuf<20> = decimal_from_str(a).expect("failed to parse decimal");
+            let b: DynamicBinaryBuf<20> = decimal_from_str(b).expect("failed to parse decimal");
+
+            assert_ne!(hash(&a), hash(&b), "{:?} {:?}", a.bytes(), b.bytes());
+        }
+    }
+}
@@ -0,0 +1,113 @@
+/*!
+Work with the payloads of NaNs.
+*/
+
+use crate::{
+    binary::{
+        decode_significand_trailing_declets,
+        encode_combination_nan,
+        encode_significand_trailing_digits,
+        encode_significand_trailing_digits_repeat,
+        is_nan,
+        is_sign_negative,
+        is_signaling_nan,
+        BinaryBuf,
+    },
+    num::Integer,
+};
+
+/**
+Transform the payload of a NaN, retaining its sign and whether it's signaling.
+
+If the decimal isn't a NaN, or its payload is too large to fit in a `u128`, then it's left unchanged.
+If the new payload has more digits than the decimal can fit then it's clamped to the largest payload
+that does fit, which is all `9`s.
+*/
+pub(crate) fn decimal_map_nan_payload<D: BinaryBuf>(decimal: &mut D, f: impl FnOnce(u128) -> u128) {
+    if !is_nan(decimal) {
+        return;
+    }
+
+    let payload = match u128::try_from_ascii(
+        false,
+        decode_significand_trailing_declets(decimal).flatten(),
+    ) {
+        Some(payload) => payload,
+        None => return,
+    };
+
+    let payload = f(payload);
+
+    let mut buf = D::try_with_exactly_storage_width_bytes(decimal.bytes().len())
+        .expect("a decimal can always fit a buffer of the same width");
+
+    let mut payload_buf = itoa::Buffer::new();
+    let payload = payload_buf.format(payload).as_bytes();
+
+    if payload.len() <= buf.trailing_significand_digits() {
+        encode_significand_trailing_digits(&mut buf, [payload]);
+    } else {
+        encode_significand_trailing_digits_repeat(&mut buf, b'9');
+    }
+
+    encode_combination_nan(
+        &mut buf,
+        is_sign_negative(decimal),
+        is_signaling_nan(decimal),
+    );
+
+    decimal.bytes_mut().copy_from_slice(buf.bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::DynamicBinaryBuf,
+        convert::{
+            decimal_from_str,
+            decimal_to_fmt,
+        },
+    };
+
+    fn map(s: &str, f: impl FnOnce(u128) -> u128) -> String {
+        let mut d: DynamicBinaryBuf<20> = decimal_from_str(s).expect("failed to parse decimal");
+
+        decimal_map_nan_payload(&mut d, f);
+
+        let mut s = String::new();
+        decimal_to_fmt(&d, &mut s).expect("failed to format decimal");
+
+        s
+    }
+
+    #[test]
+    fn map_nan_payload() {
+        for (d, expected) in [
+            ("nan", "nan(1)"),
+            ("nan(41)", "nan(42)"),
+            ("-nan(41)", "-nan(42)"),
+            ("snan(41)", "snan(42)"),
+            ("-snan(41)", "-snan(42)"),
+            ("nan(999999)", "nan(999999)"),
+        ] {
+            assert_eq!(expected, map(d, |payload| payload + 1), "{}", d);
+        }
+
+        assert_eq!("nan", map("nan(42)", |_| 0));
+    }
+
+    #[test]
+    fn map_nan_payload_clamped() {
+        assert_eq!("nan(999999)", map("nan(1)", |_| 1000000));
+        assert_eq!("-snan(999999)", map("-snan(1)", |_| u128::MAX));
+    }
+
+    #[test]
+    fn map_nan_payload_not_nan() {
+        for d in ["0", "-123.456", "inf", "-inf"] {
+            assert_eq!(d, map(d, |_| panic!("the decimal isn't a NaN")), "{}", d);
+        }
+    }
+}
@@ -0,0 +1,268 @@
+/*!
+Step decimals to their nearest representable neighbors.
+*/
+
+use core::iter;
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_digits_rev,
+        encode_combination_finite,
+        encode_combination_infinity,
+        encode_min,
+        encode_significand_trailing_digits_rev,
+        is_finite,
+        is_nan,
+        is_sign_negative,
+        BinaryBuf,
+        BinaryExponent,
+    },
+    ops::{
+        decimal_is_zero,
+        encode_quiet_nan,
+    },
+};
+
+/**
+Get the next representable decimal toward positive infinity.
+
+This matches IEEE 754's `nextUp`. The result uses the smallest exponent it can, so its
+significand has as many digits as possible. Zeroes step to the smallest positive subnormal,
+the largest finite decimal steps to positive infinity, and negative infinity steps to the
+largest negative finite decimal. NaNs return a quiet NaN with the same payload.
+*/
+pub(crate) fn decimal_next_up<D: BinaryBuf>(decimal: &D) -> D {
+    decimal_next(decimal, false)
+}
+
+/**
+Get the next representable decimal toward negative infinity.
+
+This matches IEEE 754's `nextDown`, and is the same as negating the `nextUp` of the negated decimal.
+*/
+pub(crate) fn decimal_next_down<D: BinaryBuf>(decimal: &D) -> D {
+    decimal_next(decimal, true)
+}
+
+fn decimal_next<D: BinaryBuf>(decimal: &D, toward_negative: bool) -> D {
+    let width_bytes = decimal.bytes().len();
+
+    if is_nan(decimal) {
+        return encode_quiet_nan(decimal, width_bytes)
+            .expect("the decimal's width is always supported");
+    }
+
+    let mut buf = D::try_with_exactly_storage_width_bytes(width_bytes)
+        .expect("the decimal's width is always supported");
+
+    let precision_digits = buf.precision_digits();
+    let etiny = D::Exponent::emin(&buf).raise(1).lower(precision_digits);
+    let is_negative = is_sign_negative(decimal);
+
+    if decimal_is_zero(decimal) {
+        encode_min(&mut buf, toward_negative);
+
+        return buf;
+    }
+
+    if !is_finite(decimal) {
+        if is_negative == toward_negative {
+            // Stepping further away from zero stays at infinity
+            encode_combination_infinity(&mut buf, is_negative);
+        } else {
+            // Stepping back from infinity gives the largest finite decimal
+            let msd = encode_significand_trailing_digits_rev(
+                &mut buf,
+                iter::repeat_n(b'9', precision_digits),
+            );
+            let exponent = D::Exponent::emax(&buf).lower(precision_digits - 1);
+
+            encode_combination_finite(&mut buf, is_negative, exponent, msd);
+        }
+
+        return buf;
+    }
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    let digits =
+        || decode_significand_trailing_digits_rev(decimal).chain(iter::once(msd.get_ascii()));
+
+    // Shift the significand up to the full precision, as far as the smallest exponent allows,
+    // so that a step in its last digit is as small as possible
+    let significant_digits = digits()
+        .enumerate()
+        .filter(|(_, digit)| *digit != b'0')
+        .last()
+        .map(|(i, _)| i + 1)
+        .unwrap_or(0);
+    let leading_zeros = precision_digits - significant_digits;
+    let shift = (0..=leading_zeros)
+        .rev()
+        .find(|shift| exponent.lower(*shift) >= etiny)
+        .unwrap_or(0);
+    let exponent = exponent.lower(shift);
+
+    let shifted = || iter::repeat_n(b'0', shift).chain(digits().take(precision_digits - shift));
+
+    if is_negative == toward_negative {
+        // Increase the magnitude, carrying through any trailing nines
+        let nines = shifted().take_while(|digit| *digit == b'9').count();
+
+        if nines == precision_digits {
+            // The significand overflows into another digit, so the exponent is raised
+            let exponent = exponent.raise(1);
+
+            if exponent > D::Exponent::emax(&buf).lower(precision_digits - 1) {
+                encode_combination_infinity(&mut buf, is_negative);
+            } else {
+                let msd = encode_significand_trailing_digits_rev(
+                    &mut buf,
+                    iter::repeat_n(b'0', precision_digits - 1).chain(iter::once(b'1')),
+                );
+                encode_combination_finite(&mut buf, is_negative, exponent, msd);
+            }
+        } else {
+            let msd = encode_significand_trailing_digits_rev(
+                &mut buf,
+                iter::repeat_n(b'0', nines)
+                    .chain(shifted().skip(nines).take(1).map(|digit| digit + 1))
+                    .chain(shifted().skip(nines + 1)),
+            );
+            encode_combination_finite(&mut buf, is_negative, exponent, msd);
+        }
+    } else {
+        // Decrease the magnitude, borrowing through any trailing zeroes
+        let zeroes = shifted().take_while(|digit| *digit == b'0').count();
+
+        if zeroes == precision_digits - 1 && exponent > etiny && shifted().last() == Some(b'1') {
+            // The significand loses a digit, so the exponent is lowered to keep the full precision
+            let msd = encode_significand_trailing_digits_rev(
+                &mut buf,
+                iter::repeat_n(b'9', precision_digits),
+            );
+            encode_combination_finite(&mut buf, is_negative, exponent.lower(1), msd);
+        } else {
+            let msd = encode_significand_trailing_digits_rev(
+                &mut buf,
+                iter::repeat_n(b'9', zeroes)
+                    .chain(shifted().skip(zeroes).take(1).map(|digit| digit - 1))
+                    .chain(shifted().skip(zeroes + 1)),
+            );
+            encode_combination_finite(&mut buf, is_negative, exponent, msd);
+        }
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::cmp::Ordering;
+
+    use crate::{
+        binary::FixedBinaryBuf,
+        convert::{
+            decimal_from_str,
+            decimal_to_fmt,
+        },
+        ops::decimal_cmp,
+    };
+
+    fn parse(s: &str) -> FixedBinaryBuf<4, i32> {
+        decimal_from_str(s).expect("failed to parse decimal")
+    }
+
+    fn fmt(d: &FixedBinaryBuf<4, i32>) -> String {
+        let mut s = String::new();
+        decimal_to_fmt(d, &mut s).expect("failed to format decimal");
+
+        s
+    }
+
+    #[test]
+    fn next_up_finite() {
+        for (d, expected) in [
+            ("1", "1.000001"),
+            ("1.5", "1.500001"),
+            ("-1", "-0.9999999"),
+            ("9.999999", "10.00000"),
+            ("-10.00000", "-9.999999"),
+            ("123e5", "1.230001e7"),
+            ("0", "1e-101"),
+            ("-0", "1e-101"),
+            ("-1e-101", "-0e-101"),
+            ("1e-101", "2e-101"),
+            ("1e-95", "1.000001e-95"),
+            ("-1e-95", "-9.99999e-96"),
+            ("9.999999e96", "inf"),
+            ("-9.999999e96", "-9.999998e96"),
+        ] {
+            assert_eq!(expected, fmt(&decimal_next_up(&parse(d))), "{}", d);
+        }
+    }
+
+    #[test]
+    fn next_down_finite() {
+        for (d, expected) in [
+            ("1", "0.9999999"),
+            ("-1", "-1.000001"),
+            ("10", "9.999999"),
+            ("0", "-1e-101"),
+            ("1e-101", "0e-101"),
+            ("2e-101", "1e-101"),
+            ("1e-95", "9.99999e-96"),
+            ("-9.999999e96", "-inf"),
+            ("9.999999e96", "9.999998e96"),
+        ] {
+            assert_eq!(expected, fmt(&decimal_next_down(&parse(d))), "{}", d);
+        }
+    }
+
+    #[test]
+    fn next_special() {
+        for (d, expected_up, expected_down) in [
+            ("inf", "inf", "9.999999e96"),
+            ("-inf", "-9.999999e96", "-inf"),
+            ("nan(42)", "nan(42)", "nan(42)"),
+            ("-snan(7)", "-nan(7)", "-nan(7)"),
+        ] {
+            assert_eq!(expected_up, fmt(&decimal_next_up(&parse(d))), "{}", d);
+            assert_eq!(expected_down, fmt(&decimal_next_down(&parse(d))), "{}", d);
+        }
+    }
+
+    #[test]
+    fn next_roundtrip() {
+        for d in [
+            "1",
+            "-1",
+            "1.5",
+            "9.999999e96",
+            "-9.999999e96",
+            "1e-101",
+            "1e-95",
+        ] {
+            let d = parse(d);
+
+            let up_down = decimal_next_down(&decimal_next_up(&d));
+            let down_up = decimal_next_up(&decimal_next_down(&d));
+
+            assert_eq!(
+                Some(Ordering::Equal),
+                decimal_cmp(&d, &up_down),
+                "{}",
+                fmt(&d)
+            );
+            assert_eq!(
+                Some(Ordering::Equal),
+                decimal_cmp(&d, &down_up),
+                "{}",
+                fmt(&d)
+            );
+        }
+    }
+}
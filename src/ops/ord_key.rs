@@ -0,0 +1,225 @@
+/*!
+Encode decimals into byte strings that sort in the same order as the decimals themselves.
+*/
+
+use core::iter;
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_declets,
+        is_infinite,
+        is_nan,
+        is_sign_negative,
+        is_signaling_nan,
+        BinaryBuf,
+    },
+    num::Integer,
+    ops::decimal_coefficient_trailing_zeros,
+};
+
+/**
+Encode a decimal into a key whose lexicographic order matches [`decimal_total_cmp`].
+
+The key is a sign byte, `0x00` for negative and `0x01` for positive, followed by an encoding of the
+absolute value of the decimal. For negative decimals, every byte of that encoding is inverted so
+larger magnitudes sort first. The encoding of the absolute value starts with a class byte:
+
+- `0x00` for zero, followed by its exponent.
+- `0x01` for a non-zero finite number, followed by its adjusted exponent, which is the position of
+  its most significant digit, then its significant digits with trailing zeroes removed, then
+  its exponent.
+- `0x02` for infinity.
+- `0x03` for a signaling NaN, followed by the number of digits in its payload, then its digits.
+- `0x04` for a quiet NaN, followed by the number of digits in its payload, then its digits.
+
+Exponents are written as 4 big-endian bytes with their sign bit flipped, and counts as 4 big-endian
+bytes. Digits are packed two to a byte, with each digit `d` written as the nibble `d + 1`, and are
+terminated by a zero nibble, which is padded to a whole byte. Since no encoding is a prefix of
+another, inverting the bytes of negative decimals reverses their order.
+
+The decimal's exponent must fit in an `i32`.
+
+[`decimal_total_cmp`]: crate::ops::decimal_total_cmp
+*/
+pub(crate) fn decimal_ord_key<D: BinaryBuf>(decimal: &D, mut push: impl FnMut(u8)) {
+    let is_negative = is_sign_negative(decimal);
+
+    push(if is_negative { 0x00 } else { 0x01 });
+
+    let mut push = |b: u8| push(if is_negative { !b } else { b });
+
+    if is_nan(decimal) {
+        push(if is_signaling_nan(decimal) {
+            0x03
+        } else {
+            0x04
+        });
+
+        let payload = || {
+            decode_significand_trailing_declets(decimal)
+                .flatten()
+                .skip_while(|digit| *digit == b'0')
+        };
+
+        push_u32(&mut push, payload().count() as u32);
+        push_digits(&mut push, payload());
+
+        return;
+    }
+
+    if is_infinite(decimal) {
+        push(0x02);
+
+        return;
+    }
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    let exponent = exponent
+        .to_i32()
+        .expect("the exponent doesn't fit in an `i32`");
+
+    let digits = || {
+        iter::once(msd.get_ascii())
+            .chain(decode_significand_trailing_declets(decimal).flatten())
+            .skip_while(|digit| *digit == b'0')
+    };
+
+    let significant_digits = digits().count();
+
+    if significant_digits == 0 {
+        push(0x00);
+        push_i32(&mut push, exponent);
+
+        return;
+    }
+
+    let trailing_zeros = decimal_coefficient_trailing_zeros(decimal) as usize;
+
+    push(0x01);
+    push_i32(
+        &mut push,
+        exponent.saturating_add(significant_digits as i32 - 1),
+    );
+    push_digits(
+        &mut push,
+        digits().take(significant_digits - trailing_zeros),
+    );
+    push_i32(&mut push, exponent);
+}
+
+fn push_i32(mut push: impl FnMut(u8), n: i32) {
+    // Flipping the sign bit makes negative numbers sort before positive ones
+    push_u32(&mut push, (n as u32) ^ (1 << 31));
+}
+
+fn push_u32(mut push: impl FnMut(u8), n: u32) {
+    for b in n.to_be_bytes() {
+        push(b);
+    }
+}
+
+fn push_digits(mut push: impl FnMut(u8), digits: impl Iterator<Item = u8>) {
+    let mut high = None;
+
+    // The terminator is a zero nibble, so shorter runs of digits sort first
+    for nibble in digits.map(|digit| digit - b'0' + 1).chain(iter::once(0)) {
+        match high.take() {
+            Some(high) => push((high << 4) | nibble),
+            None => high = Some(nibble),
+        }
+    }
+
+    if let Some(high) = high {
+        push(high << 4);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::cmp::Ordering;
+
+    use crate::{
+        binary::DynamicBinaryBuf,
+        convert::decimal_from_str,
+        ops::decimal_total_cmp,
+    };
+
+    fn parse(s: &str) -> DynamicBinaryBuf<20> {
+        decimal_from_str(s).expect("failed to parse decimal")
+    }
+
+    fn key(d: &DynamicBinaryBuf<20>) -> Vec<u8> {
+        let mut key = Vec::new();
+        decimal_ord_key(d, |b| key.push(b));
+
+        key
+    }
+
+    #[test]
+    fn ord_key_matches_total_cmp() {
+        let ds = [
+            "-nan(12)",
+            "-nan(3)",
+            "-nan",
+            "-snan(5)",
+            "-snan",
+            "-inf",
+            "-9999999999999999e369",
+            "-1e10",
+            "-12",
+            "-1.2",
+            "-1.0",
+            "-1.00",
+            "-0.5",
+            "-1e-398",
+            "-0e5",
+            "-0",
+            "-0.00",
+            "0.00",
+            "0",
+            "0e5",
+            "1e-398",
+            "0.5",
+            "0.55",
+            "1.00",
+            "1.0",
+            "1",
+            "1.2",
+            "12",
+            "120",
+            "1.2e2",
+            "1e10",
+            "9999999999999999e369",
+            "inf",
+            "snan",
+            "snan(5)",
+            "nan",
+            "nan(3)",
+            "nan(12)",
+        ];
+
+        for a in ds {
+            for b in ds {
+                let (a, b) = (parse(a), parse(b));
+
+                assert_eq!(
+                    decimal_total_cmp(&a, &b),
+                    key(&a).cmp(&key(&b)),
+                    "{:?} cmp {:?}",
+                    a.bytes(),
+                    b.bytes()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ord_key_equal() {
+        assert_eq!(Ordering::Equal, key(&parse("1.5")).cmp(&key(&parse("1.5"))));
+        assert_ne!(key(&parse("1.5")), key(&parse("1.50")));
+    }
+}
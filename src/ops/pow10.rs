@@ -0,0 +1,315 @@
+/*!
+Scale decimals by powers of ten.
+
+Since decimals are base-10, scaling them by a power of ten only moves their digits around.
+None of the operations in this module need to round.
+*/
+
+use core::iter;
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        decode_significand_trailing_digits_rev,
+        encode_combination_finite,
+        encode_combination_infinity,
+        encode_combination_nan,
+        encode_min,
+        encode_significand_trailing_digits,
+        encode_significand_trailing_digits_rev,
+        is_finite,
+        is_nan,
+        is_sign_negative,
+        BinaryBuf,
+        BinaryExponent,
+    },
+    convert::encode_finite_fit,
+    num::Integer,
+    ops::decimal_is_zero,
+    OverflowError,
+};
+
+/**
+Split a decimal into a quotient and remainder by `10^n`.
+
+The quotient is the integer part of `decimal / 10^n`, and the remainder is what's left over, so
+`decimal = quotient * 10^n + remainder`. Both have the same sign as the decimal. The remainder keeps
+the exponent of the decimal, so it's just its lowest digits.
+
+If the decimal isn't finite, or the quotient can't be encoded, then `None` is returned.
+*/
+pub(crate) fn decimal_divmod_pow10<D: BinaryBuf>(decimal: &D, n: i32) -> Option<(D, D)> {
+    if !is_finite(decimal) {
+        return None;
+    }
+
+    let is_negative = is_sign_negative(decimal);
+    let precision_digits = decimal.precision_digits();
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    let n_exponent = D::Exponent::from_i32(n);
+
+    // Figure out how many of the lowest digits to move into the remainder,
+    // along with the exponent of the quotient
+    let (remainder_digits, quotient_exponent) = if exponent >= n_exponent {
+        // If the exponent is at least `n` then no digits are removed, so
+        // `decimal / 10^n` is just the decimal with its exponent lowered by `n`.
+        // If that overflows the exponent then the quotient can't be encoded
+        let quotient_exponent = if n >= 0 {
+            exponent.checked_lower(n as usize)?
+        } else {
+            exponent.checked_raise(n.unsigned_abs() as usize)?
+        };
+
+        (0, quotient_exponent)
+    } else if exponent.raise(precision_digits) <= n_exponent {
+        // If all the digits are below `10^n` then the quotient is zero
+        (precision_digits, D::Exponent::zero())
+    } else {
+        // The exponent is within `precision_digits` of `n`, so the difference between them fits
+        let remainder_digits = (n as i64 - exponent.to_i32()? as i64) as usize;
+
+        (remainder_digits, D::Exponent::zero())
+    };
+
+    let digits =
+        || decode_significand_trailing_digits_rev(decimal).chain(iter::once(msd.get_ascii()));
+
+    let mut quotient =
+        D::try_with_at_least_precision(precision_digits, Some(&quotient_exponent)).ok()?;
+    let quotient_msd =
+        encode_significand_trailing_digits_rev(&mut quotient, digits().skip(remainder_digits));
+    encode_combination_finite(&mut quotient, is_negative, quotient_exponent, quotient_msd);
+
+    let mut remainder = D::try_with_at_least_precision(precision_digits, Some(&exponent)).ok()?;
+    let remainder_msd =
+        encode_significand_trailing_digits_rev(&mut remainder, digits().take(remainder_digits));
+    encode_combination_finite(&mut remainder, is_negative, exponent, remainder_msd);
+
+    Some((quotient, remainder))
+}
+
+/**
+Multiply a decimal by `10^n`.
+
+This only changes the exponent of the decimal, unless it would fall outside the range the decimal
+can encode. In that case, trailing zeroes are removed from its significand, or added to it, to try
+make it fit. If the scaled decimal can't be encoded exactly then an error is returned.
+Infinities and NaNs are returned unchanged.
+*/
+pub(crate) fn decimal_scaleb<D: BinaryBuf>(decimal: &D, n: i32) -> Result<D, OverflowError> {
+    let width_bytes = decimal.bytes().len();
+
+    if !is_finite(decimal) {
+        let mut buf = D::try_with_exactly_storage_width_bytes(width_bytes)?;
+        buf.bytes_mut().copy_from_slice(decimal.bytes());
+
+        return Ok(buf);
+    }
+
+    let (exponent, msd) = decode_combination_finite(decimal);
+
+    let exponent = exponent.to_i32().ok_or_else(|| {
+        OverflowError::exponent_out_of_range(
+            width_bytes,
+            "the decimal's exponent is too large to scale",
+        )
+    })? as i64;
+
+    encode_finite_fit(
+        is_sign_negative(decimal),
+        || decode_significand_trailing_digits_rev(decimal).chain(iter::once(msd.get_ascii())),
+        exponent + n as i64,
+        width_bytes,
+    )
+}
+
+/**
+Get the unit in the last place of a decimal, which is `10^exponent`.
+
+The result has the same width as the decimal, and is always positive. A decimal's exponent is
+the quantum of its cohort, so `1.23` has a unit in the last place of `0.01`, while `1.230` has
+`0.001`. Zeroes don't have a last place, so they use the smallest positive decimal instead.
+
+Infinities have an infinite unit in the last place, and NaNs have a quiet NaN without a payload.
+*/
+pub(crate) fn decimal_ulp<D: BinaryBuf>(decimal: &D) -> D {
+    let mut buf = D::try_with_exactly_storage_width_bytes(decimal.bytes().len())
+        .expect("the decimal's width is always supported");
+
+    if is_nan(decimal) {
+        encode_combination_nan(&mut buf, false, false);
+    } else if !is_finite(decimal) {
+        encode_combination_infinity(&mut buf, false);
+    } else if decimal_is_zero(decimal) {
+        encode_min(&mut buf, false);
+    } else {
+        let (exponent, _) = decode_combination_finite(decimal);
+
+        let msd = encode_significand_trailing_digits(&mut buf, [b"1"]);
+        encode_combination_finite(&mut buf, false, exponent, msd);
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::DynamicBinaryBuf,
+        convert::{
+            decimal_from_str,
+            decimal_to_fmt,
+        },
+    };
+
+    fn parse(s: &str) -> DynamicBinaryBuf<20> {
+        decimal_from_str(s).expect("failed to parse decimal")
+    }
+
+    fn fmt(d: &DynamicBinaryBuf<20>) -> String {
+        let mut s = String::new();
+        decimal_to_fmt(d, &mut s).expect("failed to format decimal");
+
+        s
+    }
+
+    #[test]
+    fn divmod_pow10_finite() {
+        for (d, n, expected_quotient, expected_remainder) in [
+            ("12345", 2, "123", "45"),
+            ("12345", 0, "12345", "0"),
+            ("12345", 5, "0", "12345"),
+            ("12345", 10, "0", "12345"),
+            ("12345.678", 0, "12345", "0.678"),
+            ("12345.678", 1, "1234", "5.678"),
+            ("12345.678", -1, "123456", "0.078"),
+            ("-12345.678", 2, "-123", "-45.678"),
+            ("-0.5", 0, "-0", "-0.5"),
+            ("5e3", 1, "5e2", "0e3"),
+            ("1.20", -2, "120", "0.00"),
+            ("1.5e-90", 0, "0", "1.5e-90"),
+        ] {
+            let (quotient, remainder) =
+                decimal_divmod_pow10(&parse(d), n).expect("failed to split decimal");
+
+            assert_eq!(expected_quotient, fmt(&quotient), "{} divmod 10^{}", d, n);
+            assert_eq!(expected_remainder, fmt(&remainder), "{} divmod 10^{}", d, n);
+        }
+    }
+
+    #[test]
+    fn divmod_pow10_extreme() {
+        for (d, n) in [
+            ("1e90", i32::MIN),
+            ("1e90", i32::MIN + 5),
+            ("-1.5e-6000", i32::MIN),
+            ("1e6000", i32::MIN + 1),
+        ] {
+            assert!(
+                decimal_divmod_pow10(&parse(d), n).is_none(),
+                "{} divmod 10^{}",
+                d,
+                n
+            );
+        }
+
+        for (d, n, expected_quotient, expected_remainder) in [
+            ("1e90", i32::MAX, "0", "1e90"),
+            ("1e90", i32::MAX - 1, "0", "1e90"),
+            ("-1.5e-6000", i32::MAX, "-0", "-1.5e-6000"),
+        ] {
+            let (quotient, remainder) =
+                decimal_divmod_pow10(&parse(d), n).expect("failed to split decimal");
+
+            assert_eq!(expected_quotient, fmt(&quotient), "{} divmod 10^{}", d, n);
+            assert_eq!(expected_remainder, fmt(&remainder), "{} divmod 10^{}", d, n);
+        }
+    }
+
+    #[test]
+    fn scaleb_finite() {
+        for (d, n, expected) in [
+            ("123", 2, "1.23e4"),
+            ("123", -2, "1.23"),
+            ("-1.5", 0, "-1.5"),
+            ("0", 5, "0e5"),
+            ("1.000", 3, "1000"),
+            ("1e6100", 100, "1e6200"),
+        ] {
+            assert_eq!(
+                expected,
+                fmt(&decimal_scaleb(&parse(d), n).expect("failed to scale decimal")),
+                "{} scaleb {}",
+                d,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn scaleb_special() {
+        for d in ["inf", "-inf", "nan(42)", "-snan"] {
+            assert_eq!(
+                d,
+                fmt(&decimal_scaleb(&parse(d), 5).expect("failed to scale decimal")),
+                "{}",
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn err_scaleb() {
+        for (d, n) in [
+            ("1", i32::MAX),
+            ("1", i32::MIN),
+            ("1e6144", 30000),
+            ("1.5", -30000),
+        ] {
+            assert!(decimal_scaleb(&parse(d), n).is_err(), "{} scaleb {}", d, n);
+        }
+    }
+
+    #[test]
+    fn ulp_finite() {
+        for (d, expected) in [
+            ("1.23", "0.01"),
+            ("1.230", "0.001"),
+            ("-1.23", "0.01"),
+            ("123", "1"),
+            ("1.2e5", "1e4"),
+            ("12e4", "1e4"),
+            ("120e3", "1e3"),
+            ("1e-6176", "1e-6176"),
+            // Zeroes use the smallest positive decimal with the same width
+            ("0", "1e-101"),
+            ("-0.00", "1e-101"),
+            ("0e-6000", "1e-6176"),
+        ] {
+            assert_eq!(expected, fmt(&decimal_ulp(&parse(d))), "ulp {}", d);
+        }
+    }
+
+    #[test]
+    fn ulp_special() {
+        for (d, expected) in [
+            ("inf", "inf"),
+            ("-inf", "inf"),
+            ("nan", "nan"),
+            ("-snan(42)", "nan"),
+        ] {
+            assert_eq!(expected, fmt(&decimal_ulp(&parse(d))), "ulp {}", d);
+        }
+    }
+
+    #[test]
+    fn divmod_pow10_special() {
+        for d in ["inf", "-inf", "nan", "snan"] {
+            assert!(decimal_divmod_pow10(&parse(d), 0).is_none(), "{}", d);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+/*!
+Search sorted arrays of encoded decimals.
+*/
+
+use core::cmp::Ordering;
+
+use crate::{
+    binary::BinaryBuf,
+    ops::decimal_total_cmp,
+};
+
+/**
+Binary search a buffer of little-endian decimals sorted by [`decimal_total_cmp`] for a needle.
+
+Only the decimals probed by the search are decoded. The result follows the same convention as
+`slice::binary_search`: if the needle is found then `Ok` is returned with its index, otherwise
+`Err` is returned with the index where it could be inserted while keeping the buffer sorted.
+
+Panics if `width_bytes` isn't a width the decimal can be decoded from, or if the buffer's length
+isn't a multiple of it.
+*/
+pub(crate) fn decimal_search_sorted_le<D: BinaryBuf>(
+    sorted: &[u8],
+    width_bytes: usize,
+    needle: &D,
+) -> Result<usize, usize> {
+    let mut probe = D::try_with_exactly_storage_width_bytes(width_bytes)
+        .expect("the width isn't supported by the decimal");
+
+    assert_eq!(
+        0,
+        sorted.len() % width_bytes,
+        "the buffer length isn't a multiple of the width"
+    );
+
+    let mut lo = 0;
+    let mut hi = sorted.len() / width_bytes;
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        probe
+            .bytes_mut()
+            .copy_from_slice(&sorted[mid * width_bytes..(mid + 1) * width_bytes]);
+
+        match decimal_total_cmp(&probe, needle) {
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+
+    Err(lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        binary::{
+            DynamicBinaryBuf,
+            FixedBinaryBuf,
+        },
+        convert::decimal_from_str,
+    };
+
+    fn sorted(ds: &[&str]) -> Vec<u8> {
+        ds.iter()
+            .flat_map(|d| {
+                let d: FixedBinaryBuf<8, i32> =
+                    decimal_from_str(d).expect("failed to parse decimal");
+
+                d.bytes().to_vec()
+            })
+            .collect()
+    }
+
+    fn search(sorted: &[u8], needle: &str) -> Result<usize, usize> {
+        let needle: DynamicBinaryBuf<20> =
+            decimal_from_str(needle).expect("failed to parse decimal");
+
+        decimal_search_sorted_le(sorted, 8, &needle)
+    }
+
+    #[test]
+    fn search_sorted() {
+        let buf = sorted(&["-inf", "-1.5", "-0", "0", "1.00", "1.0", "42", "inf", "nan"]);
+
+        for (needle, expected) in [
+            ("-inf", Ok(0)),
+            ("-1.5", Ok(1)),
+            ("-0", Ok(2)),
+            ("0", Ok(3)),
+            ("1.00", Ok(4)),
+            ("1.0", Ok(5)),
+            ("42", Ok(6)),
+            ("inf", Ok(7)),
+            ("nan", Ok(8)),
+            ("-nan", Err(0)),
+            ("-2", Err(1)),
+            ("-0.5", Err(2)),
+            ("1.000", Err(4)),
+            ("1", Err(6)),
+            ("1e300", Err(7)),
+            ("snan", Err(8)),
+            ("nan(1)", Err(9)),
+        ] {
+            assert_eq!(expected, search(&buf, needle), "{}", needle);
+        }
+    }
+
+    #[test]
+    fn search_sorted_empty() {
+        assert_eq!(Err(0), search(&[], "1"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn search_sorted_invalid_len() {
+        let mut buf = sorted(&["1", "2"]);
+        buf.pop();
+
+        let _ = search(&buf, "1");
+    }
+}
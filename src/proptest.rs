@@ -0,0 +1,172 @@
+/*!
+Generating random decimals for property tests with [`proptest`](https://docs.rs/proptest).
+
+When the `proptest` feature is enabled, decimals implement [`Arbitrary`](https://docs.rs/proptest/1/proptest/arbitrary/trait.Arbitrary.html),
+so they can be used as inputs to property tests. Decimals are generated by encoding a random
+coefficient and exponent, so they cover the full range of exponents the decimal can encode, including
+subnormals. Zeroes with either sign, infinities, and NaNs with random payloads are also generated,
+but less often than other finite decimals.
+
+Generated decimals always use the canonical encoding of their value. Variable-width decimals use a
+random width, up to 32 bytes for both [`Bitstring`](crate::Bitstring) and `BigBitstring`.
+
+```
+use decstr::Bitstring64;
+use proptest::prelude::*;
+
+proptest!(|(decimal: Bitstring64)| {
+    let parsed = Bitstring64::try_parse_str(&decimal.to_string()).expect("failed to parse decimal");
+
+    prop_assert_eq!(decimal.as_le_bytes(), parsed.as_le_bytes());
+});
+```
+*/
+
+use core::fmt;
+
+use ::proptest::{
+    collection::vec,
+    prelude::*,
+};
+
+use crate::{
+    binary::{
+        encode_combination_finite,
+        encode_combination_infinity,
+        encode_combination_nan,
+        encode_significand_trailing_digits_rev,
+        BinaryBuf,
+        BinaryExponent,
+    },
+    num::Integer,
+};
+
+/**
+Generate a decimal with the given width.
+
+Most decimals are finite, with a random coefficient and exponent. The rest are evenly split between
+zeroes, infinities, and NaNs.
+*/
+pub(crate) fn arbitrary_decimal<D: BinaryBuf + fmt::Debug + 'static>(
+    width_bytes: usize,
+) -> BoxedStrategy<D> {
+    let buf = D::try_with_exactly_storage_width_bytes(width_bytes)
+        .expect("the decimal's width is always supported");
+
+    let precision_digits = buf.precision_digits();
+    let payload_digits = buf.trailing_significand_digits();
+
+    let min_exponent = D::Exponent::emin(&buf)
+        .raise(1)
+        .lower(precision_digits)
+        .to_i32()
+        .expect("the decimal's exponent fits in an i32");
+    let max_exponent = D::Exponent::emax(&buf)
+        .lower(precision_digits - 1)
+        .to_i32()
+        .expect("the decimal's exponent fits in an i32");
+
+    let empty = move || {
+        D::try_with_exactly_storage_width_bytes(width_bytes)
+            .expect("the decimal's width is always supported")
+    };
+
+    let finite = (
+        any::<bool>(),
+        vec(b'0'..=b'9', 1..=precision_digits),
+        min_exponent..=max_exponent,
+    )
+        .prop_map(move |(is_negative, digits, exponent)| {
+            let mut buf = empty();
+
+            let msd = encode_significand_trailing_digits_rev(&mut buf, digits.into_iter());
+            encode_combination_finite(&mut buf, is_negative, D::Exponent::from_i32(exponent), msd);
+
+            buf
+        });
+
+    let zero =
+        (any::<bool>(), min_exponent..=max_exponent).prop_map(move |(is_negative, exponent)| {
+            let mut buf = empty();
+
+            let msd = encode_significand_trailing_digits_rev(&mut buf, core::iter::empty());
+            encode_combination_finite(&mut buf, is_negative, D::Exponent::from_i32(exponent), msd);
+
+            buf
+        });
+
+    let infinity = any::<bool>().prop_map(move |is_negative| {
+        let mut buf = empty();
+
+        encode_combination_infinity(&mut buf, is_negative);
+
+        buf
+    });
+
+    let nan = (
+        any::<bool>(),
+        any::<bool>(),
+        vec(b'0'..=b'9', 0..=payload_digits),
+    )
+        .prop_map(move |(is_negative, is_signaling, payload)| {
+            let mut buf = empty();
+
+            encode_significand_trailing_digits_rev(&mut buf, payload.into_iter());
+            encode_combination_nan(&mut buf, is_negative, is_signaling);
+
+            buf
+        });
+
+    prop_oneof![
+        85 => finite,
+        5 => zero,
+        5 => infinity,
+        5 => nan,
+    ]
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use ::proptest::proptest;
+
+    #[cfg(feature = "arbitrary-precision")]
+    use crate::BigBitstring;
+    use crate::{
+        Bitstring,
+        Bitstring128,
+        Bitstring256,
+        Bitstring32,
+        Bitstring64,
+    };
+
+    macro_rules! roundtrip {
+        ($($name:ident: $d:ident,)*) => {
+            proptest! {
+                $(
+                    #[test]
+                    fn $name(decimal: $d) {
+                        let parsed = $d::try_parse_str(&decimal.to_string())
+                            .expect("failed to parse decimal");
+
+                        assert_eq!(decimal.to_string(), parsed.to_string());
+                        assert!(decimal.is_canonical());
+                    }
+                )*
+            }
+        };
+    }
+
+    roundtrip! {
+        parse_display_roundtrip_32: Bitstring32,
+        parse_display_roundtrip_64: Bitstring64,
+        parse_display_roundtrip_128: Bitstring128,
+        parse_display_roundtrip_256: Bitstring256,
+        parse_display_roundtrip_dynamic: Bitstring,
+    }
+
+    #[cfg(feature = "arbitrary-precision")]
+    roundtrip! {
+        parse_display_roundtrip_arbitrary: BigBitstring,
+    }
+}
@@ -0,0 +1,543 @@
+/*!
+Low-level access to the decimal bitstring encoding.
+
+This module is for building encoders and decoders on top of this library that need to work
+with the parts of a decimal directly, rather than through text or Rust's primitive numbers.
+Nothing in this module validates that the values it's given represent a meaningful number.
+It only checks that they fit into the decimal being encoded.
+
+A finite decimal is made up of:
+
+- A sign.
+- A most significant digit, between `0` and `9`.
+- Some number of trailing declets. Each declet is 3 decimal digits compressed into 10 bits
+  using densely-packed-decimal encoding.
+- An unbiased exponent.
+
+The value of the decimal is the digits, as an integer, multiplied by `10` raised to the exponent.
+
+Any decimal can be classified by examining its most significant byte, which is the _last_ byte
+in its little-endian buffer. The constants in this module are masks over that byte. For example,
+a decimal is a NaN if `bytes[bytes.len() - 1] & NAN_COMBINATION == NAN`.
+*/
+
+use core::fmt;
+
+use crate::{
+    binary::{
+        self,
+        bias,
+        decode_combination_finite,
+        decode_significand_trailing_digits_rev,
+        emax,
+        encode_combination_finite,
+        encode_combination_infinity,
+        encode_combination_nan,
+        encode_significand_trailing_digits_rev,
+        encode_significand_trailing_dpd_declets,
+        is_infinite,
+        is_nan,
+        is_sign_negative,
+        is_signaling_nan,
+        BinaryBuf,
+        DynamicBinaryBuf,
+        MostSignificantDigit,
+        SliceBinaryBuf,
+    },
+    convert::{
+        decimal_from_parsed,
+        decimal_to_fmt,
+    },
+    num::Integer,
+    text::{
+        ParsedDecimal,
+        TextBuf,
+    },
+    Error,
+    OverflowError,
+};
+
+/**
+The sign bit in the most significant byte of a decimal.
+
+The bit is set for negative decimals, including negative infinities and NaNs.
+*/
+pub const SIGN_NEGATIVE: u8 = binary::SIGN_NEGATIVE;
+
+/**
+The bits in the most significant byte of a decimal that identify it as an infinity.
+
+The value of these bits should be checked using the `INFINITY_COMBINATION` mask.
+*/
+pub const INFINITY: u8 = binary::INFINITY;
+
+/**
+A mask over the most significant byte of a decimal for all bits needed to determine whether it's an infinity.
+
+A decimal is an infinity if `msb & INFINITY_COMBINATION == INFINITY`.
+*/
+pub const INFINITY_COMBINATION: u8 = binary::INFINITY_COMBINATION;
+
+/**
+The bit in the most significant byte of a NaN that identifies it as signaling.
+*/
+pub const SIGNALING: u8 = binary::SIGNALING;
+
+/**
+The bits in the most significant byte of a decimal that identify it as a quiet NaN.
+
+A decimal is any kind of NaN if `msb & NAN == NAN`.
+*/
+pub const NAN: u8 = binary::NAN;
+
+/**
+A mask over the most significant byte of a decimal for all bits needed to determine whether it's a NaN,
+and whether that NaN is quiet or signaling.
+
+A decimal is a quiet NaN if `msb & NAN_COMBINATION == NAN`, and a signaling NaN if
+`msb & NAN_COMBINATION == NAN_COMBINATION`.
+*/
+pub const NAN_COMBINATION: u8 = binary::NAN_COMBINATION;
+
+/**
+A mask over the most significant byte of a decimal for all bits needed to determine whether it's finite.
+
+A decimal is finite if `msb & FINITE_COMBINATION != FINITE_COMBINATION`.
+*/
+pub const FINITE_COMBINATION: u8 = binary::FINITE_COMBINATION;
+
+/**
+Encode a finite decimal into a little-endian buffer.
+
+The buffer must be a multiple of 32 bits (4 bytes) wide. Any existing contents of the buffer are overwritten.
+
+The `trailing_declets` are given most significant first, and each must fit in 10 bits. If there
+are fewer declets than the buffer can fit then the missing most significant declets are zero.
+
+The `exponent` is the unbiased exponent, which must be within the range of the buffer's width.
+For a 32 bit decimal that's `-101..=90`, and for a 64 bit decimal it's `-398..=369`.
+
+This function will fail if any of the parts don't fit in a decimal of the buffer's width, or if the
+buffer is wider than 896 bits (112 bytes).
+*/
+pub fn encode_finite(
+    buf: &mut [u8],
+    is_negative: bool,
+    most_significant_digit: u8,
+    trailing_declets: &[u16],
+    exponent: i64,
+) -> Result<(), Error> {
+    let mut decimal = SliceBinaryBuf::try_from_le_bytes(buf)?;
+
+    let width_bytes = decimal.bytes().len();
+
+    if most_significant_digit > 9 {
+        Err(OverflowError::significand_out_of_range(
+            width_bytes,
+            "the most significant digit must be between 0 and 9",
+        ))?;
+    }
+
+    if trailing_declets.len() * 3 > decimal.trailing_significand_digits() {
+        Err(OverflowError::significand_out_of_range(
+            width_bytes,
+            "there are too many trailing declets",
+        ))?;
+    }
+
+    if trailing_declets.iter().any(|dpd| *dpd >= 1 << 10) {
+        Err(OverflowError::significand_out_of_range(
+            width_bytes,
+            "trailing declets must fit in 10 bits",
+        ))?;
+    }
+
+    // The exponent range is based on the range of the biased exponent.
+    //
+    // The smallest biased exponent is `0`, and the largest is `2 * emax - 1`.
+    let bias = bias::<i64>(decimal.storage_width_bits(), decimal.precision_digits());
+    let emax = emax::<i64>(decimal.storage_width_bits());
+
+    if exponent < -bias || exponent > 2 * emax - 1 - bias {
+        Err(OverflowError::exponent_out_of_range(
+            width_bytes,
+            "the exponent is outside the range of the decimal",
+        ))?;
+    }
+
+    decimal.bytes_mut().fill(0);
+
+    encode_significand_trailing_dpd_declets(&mut decimal, trailing_declets);
+
+    encode_combination_finite(
+        &mut decimal,
+        is_negative,
+        exponent,
+        MostSignificantDigit::from_bcd(most_significant_digit),
+    );
+
+    Ok(())
+}
+
+/**
+Encode a parsed decimal into a little-endian buffer.
+
+This is for encoding decimals into memory that's managed elsewhere, like a field of a `#[repr(C)]`
+struct, without going through one of the decimal types. The buffer is a plain byte slice, so it
+doesn't need any particular alignment. It must be a multiple of 32 bits (4 bytes) wide, and no
+wider than 896 bits (112 bytes). The decimal is encoded with exactly the width of the buffer, and
+any existing contents of the buffer are overwritten.
+
+The decimal is encoded with the same digits and exponent it was parsed with. Its value needs to fit
+in a [`Bitstring`](crate::Bitstring), which is up to 70 digits with the exponent range of a
+decimal256, and it needs to fit in a decimal of the buffer's width, or this function will fail.
+If it fails, the buffer is left unchanged.
+*/
+pub fn encode_parsed<B: TextBuf>(parsed: ParsedDecimal<B>, buf: &mut [u8]) -> Result<(), Error> {
+    let mut decimal = SliceBinaryBuf::try_from_le_bytes(buf)?;
+
+    // Parse into the narrowest decimal that fits, then widen it to fill the buffer
+    let parsed: DynamicBinaryBuf<32> = decimal_from_parsed(parsed)?;
+
+    let width_bytes = decimal.bytes().len();
+    let required_width_bytes = parsed.bytes().len();
+
+    if required_width_bytes > width_bytes {
+        Err(OverflowError::would_overflow(
+            width_bytes,
+            required_width_bytes,
+        ))?;
+    }
+
+    decimal.bytes_mut().fill(0);
+
+    let is_negative = is_sign_negative(&parsed);
+
+    if is_infinite(&parsed) {
+        encode_combination_infinity(&mut decimal, is_negative);
+    } else if is_nan(&parsed) {
+        encode_significand_trailing_digits_rev(
+            &mut decimal,
+            decode_significand_trailing_digits_rev(&parsed),
+        );
+        encode_combination_nan(&mut decimal, is_negative, is_signaling_nan(&parsed));
+    } else {
+        let (exponent, msd) = decode_combination_finite(&parsed);
+        let exponent = exponent
+            .to_i32()
+            .expect("the exponent of a `Bitstring` fits in an `i32`");
+
+        // The digits are padded with leading zeroes in the wider buffer
+        encode_significand_trailing_digits_rev(
+            &mut decimal,
+            decode_significand_trailing_digits_rev(&parsed)
+                .chain(core::iter::once(msd.get_ascii())),
+        );
+
+        // If the buffer is wider then the most significant digit was moved into
+        // the trailing significand, so the one in the combination field is a leading zero
+        let msd = if width_bytes > required_width_bytes {
+            MostSignificantDigit::zero()
+        } else {
+            msd
+        };
+
+        encode_combination_finite(&mut decimal, is_negative, exponent as i64, msd);
+    }
+
+    Ok(())
+}
+
+/**
+Format a decimal encoded in a little-endian buffer as text.
+
+This is for formatting decimals in memory that's managed elsewhere, without copying them into one of
+the decimal types first. The buffer must be a multiple of 32 bits (4 bytes) wide, and no wider than
+896 bits (112 bytes). Its width determines the width of the decimal, so an 8 byte buffer is read as
+a decimal64. The text is the same as the `Display` implementation of the decimal types produces.
+*/
+pub fn decode_fmt(buf: &[u8], out: impl fmt::Write) -> Result<(), Error> {
+    // The decimal is copied onto the stack so it can be read through a mutable buffer
+    let mut copy = [0; SliceBinaryBuf::MAX_STORAGE_WIDTH_BYTES];
+    let copy = copy
+        .get_mut(..buf.len())
+        .ok_or_else(|| SliceBinaryBuf::too_wide(buf.len()))?;
+    copy.copy_from_slice(buf);
+
+    let decimal = SliceBinaryBuf::try_from_le_bytes(copy)?;
+
+    Ok(decimal_to_fmt(&decimal, out)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        text::DecimalParser,
+        Bitstring,
+        Bitstring64,
+    };
+
+    #[test]
+    fn encode_finite_matches_parsed() {
+        // The declets here are `123`, `456`, `234`, and `567`.
+        // Each digit is small, so their densely-packed-decimal encoding is `abcdef0ghi`
+        for (f, width, is_negative, msd, declets, exponent) in [
+            ("0", 4, false, 0, &[][..], 0),
+            ("-0", 4, true, 0, &[][..], 0),
+            ("123", 4, false, 0, &[0b00_1010_0011][..], 0),
+            (
+                "-123.456",
+                4,
+                true,
+                0,
+                &[0b00_1010_0011, 0b10_0101_0110][..],
+                -3,
+            ),
+            (
+                "1234567",
+                4,
+                false,
+                1,
+                &[0b01_0011_0100, 0b10_1110_0111][..],
+                0,
+            ),
+            ("1e90", 4, false, 0, &[1][..], 90),
+            ("1e-101", 4, false, 0, &[0, 1][..], -101),
+            ("9e369", 8, false, 0, &[9][..], 369),
+        ] {
+            let expected = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            let mut buf = [0xff; 8];
+            let buf = &mut buf[..width];
+
+            encode_finite(buf, is_negative, msd, declets, exponent)
+                .expect("failed to encode decimal");
+
+            assert_eq!(expected.as_le_bytes(), buf, "{}", f);
+        }
+    }
+
+    #[test]
+    fn encode_finite_max_width() {
+        let mut buf = [0; 112];
+
+        for (exponent, expected) in [
+            (1729382256910270215, "1e1729382256910270215"),
+            (-1729382256910270712, "1e-1729382256910270712"),
+        ] {
+            encode_finite(&mut buf, false, 0, &[1], exponent).expect("failed to encode decimal");
+
+            let mut s = String::new();
+            decode_fmt(&buf, &mut s).expect("failed to format decimal");
+
+            assert_eq!(expected, s);
+        }
+
+        assert!(encode_finite(&mut buf, false, 0, &[1], 1729382256910270216).is_err());
+        assert!(encode_finite(&mut buf, false, 0, &[1], -1729382256910270713).is_err());
+
+        // Wider buffers can't have their exponent represented as an `i64`
+        assert!(encode_finite(&mut [0; 116], false, 0, &[1], 0).is_err());
+        assert!(encode_finite(&mut [0; 116], false, 0, &[1], i64::MAX).is_err());
+    }
+
+    #[test]
+    fn encode_parsed_decode_fmt_roundtrip() {
+        for (f, width) in [
+            ("0", 4),
+            ("-123.456", 4),
+            ("-123.456", 16),
+            ("1.5e300", 16),
+            ("9.999999e96", 4),
+            ("1e-101", 12),
+            ("inf", 8),
+            ("-snan(42)", 112),
+            ("-1.5e-300", 112),
+            ("1.5e300", 112),
+            ("123456789012345678901234567890", 112),
+            ("9999999999999999", 8),
+            ("9999999999999999", 16),
+            ("-9.999999e96", 8),
+        ] {
+            let mut buf = [0xff; 112];
+            let buf = &mut buf[..width];
+
+            encode_parsed(
+                DecimalParser::parse_str(f).expect("failed to parse decimal"),
+                buf,
+            )
+            .expect("failed to encode decimal");
+
+            let mut s = String::new();
+            decode_fmt(buf, &mut s).expect("failed to format decimal");
+
+            assert_eq!(f, s, "{}", f);
+        }
+
+        // Decimals with the same width as a `Bitstring` have the same bytes
+        let mut buf = [0; 8];
+        encode_parsed(
+            DecimalParser::parse_str("-1.5e300").expect("failed to parse decimal"),
+            &mut buf,
+        )
+        .expect("failed to encode decimal");
+
+        assert_eq!(
+            Bitstring64::try_parse_str("-1.5e300")
+                .expect("failed to parse decimal")
+                .as_le_bytes(),
+            &buf
+        );
+    }
+
+    #[test]
+    fn err_encode_parsed_too_narrow() {
+        let mut buf = [0xff; 4];
+
+        let err = encode_parsed(
+            DecimalParser::parse_str("12345678").expect("failed to parse decimal"),
+            &mut buf,
+        )
+        .unwrap_err();
+
+        assert!(err.is_overflow());
+        assert_eq!([0xff; 4], buf);
+
+        assert!(decode_fmt(&[0; 6], String::new()).is_err());
+    }
+
+    #[test]
+    fn err_encode_parsed_too_many_digits() {
+        let mut buf = [0xff; 112];
+
+        let max = "9".repeat(70);
+
+        encode_parsed(
+            DecimalParser::parse_str(&max).expect("failed to parse decimal"),
+            &mut buf,
+        )
+        .expect("failed to encode decimal");
+
+        let mut s = String::new();
+        decode_fmt(&buf, &mut s).expect("failed to format decimal");
+
+        assert_eq!(max, s);
+
+        let err = encode_parsed(
+            DecimalParser::parse_str(&"9".repeat(71)).expect("failed to parse decimal"),
+            &mut buf,
+        )
+        .unwrap_err();
+
+        assert!(err.is_overflow());
+    }
+
+    #[test]
+    fn err_raw_too_wide() {
+        let err = decode_fmt(&[0; 120], String::new()).unwrap_err();
+        assert_eq!(
+            "the value cannot fit into a decimal of `112` bytes; the width needed is `120` bytes",
+            &err.to_string()
+        );
+
+        let err = encode_finite(&mut [0; 116], false, 0, &[1], 0).unwrap_err();
+        assert_eq!(
+            "the value cannot fit into a decimal of `112` bytes; the width needed is `116` bytes",
+            &err.to_string()
+        );
+    }
+
+    #[test]
+    fn iter_declets_roundtrip() {
+        for (f, msd, declets, exponent) in [
+            ("0", 0, &[0, 0][..], 0),
+            ("-123.456", 0, &[0b00_1010_0011, 0b10_0101_0110][..], -3),
+            ("1234567", 1, &[0b01_0011_0100, 0b10_1110_0111][..], 0),
+            ("999", 0, &[0, 0b00_1111_1111][..], 0),
+            ("1e-101", 0, &[0, 1][..], -101),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            let actual = d.iter_declets().collect::<Vec<_>>();
+            assert_eq!(declets, &actual[..], "{}", f);
+
+            let mut buf = [0; 4];
+            encode_finite(&mut buf, d.is_sign_negative(), msd, &actual, exponent)
+                .expect("failed to encode decimal");
+
+            assert_eq!(d.as_le_bytes(), &buf, "{}", f);
+        }
+
+        let d = Bitstring64::try_parse_str("1").expect("failed to parse decimal");
+        assert_eq!(
+            &[0, 0, 0, 0, 1][..],
+            &d.iter_declets().collect::<Vec<_>>()[..]
+        );
+    }
+
+    #[test]
+    fn classify_msb() {
+        for (f, is_negative, is_finite, is_infinite, is_nan, is_signaling) in [
+            ("0", false, true, false, false, false),
+            ("-123.456", true, true, false, false, false),
+            ("9.999999e96", false, true, false, false, false),
+            ("inf", false, false, true, false, false),
+            ("-inf", true, false, true, false, false),
+            ("nan", false, false, false, true, false),
+            ("-nan(42)", true, false, false, true, false),
+            ("snan", false, false, false, true, true),
+            ("-snan", true, false, false, true, true),
+        ] {
+            let d = Bitstring::try_parse_str(f).expect("failed to parse decimal");
+
+            let bytes = d.as_le_bytes();
+            let msb = bytes[bytes.len() - 1];
+
+            assert_eq!(is_negative, msb & SIGN_NEGATIVE != 0, "{}", f);
+            assert_eq!(
+                is_finite,
+                msb & FINITE_COMBINATION != FINITE_COMBINATION,
+                "{}",
+                f
+            );
+            assert_eq!(is_infinite, msb & INFINITY_COMBINATION == INFINITY, "{}", f);
+            assert_eq!(is_nan, msb & NAN == NAN, "{}", f);
+            assert_eq!(
+                is_nan && !is_signaling,
+                msb & NAN_COMBINATION == NAN,
+                "{}",
+                f
+            );
+            assert_eq!(
+                is_signaling,
+                msb & NAN_COMBINATION == NAN_COMBINATION,
+                "{}",
+                f
+            );
+            assert_eq!(is_signaling, is_nan && msb & SIGNALING != 0, "{}", f);
+        }
+    }
+
+    #[test]
+    fn err_encode_finite_out_of_range() {
+        let mut buf = [0; 4];
+
+        // Invalid width
+        assert!(encode_finite(&mut buf[..3], false, 1, &[], 0).is_err());
+        assert!(encode_finite(&mut [0; 120], false, 1, &[], 0).is_err());
+
+        // Invalid significand
+        assert!(encode_finite(&mut buf, false, 10, &[], 0).is_err());
+        assert!(encode_finite(&mut buf, false, 1, &[1 << 10], 0).is_err());
+        assert!(encode_finite(&mut buf, false, 1, &[0, 0, 0], 0).is_err());
+
+        // Invalid exponent
+        assert!(encode_finite(&mut buf, false, 1, &[], 91).is_err());
+        assert!(encode_finite(&mut buf, false, 1, &[], -102).is_err());
+
+        assert!(encode_finite(&mut buf, false, 1, &[], 90).is_ok());
+        assert!(encode_finite(&mut buf, false, 1, &[], -101).is_ok());
+    }
+}
@@ -0,0 +1,74 @@
+/*!
+Serialization and deserialization with [`serde`](https://docs.rs/serde).
+
+Decimals are serialized as text in human-readable formats like JSON, using the same format as
+their `Display` implementation. That means trailing zeroes are preserved, so `1.50` is serialized
+as the string `"1.50"`, and deserializes back to `1.50`, rather than `1.5`. In binary formats,
+decimals are serialized as their little-endian bytes.
+
+Decimals are serialized as strings rather than numbers in human-readable formats by default, because
+most parsers of formats like JSON will treat numbers as binary floating point, losing any trailing
+zeroes, and possibly rounding. If you'd rather serialize decimals as numbers where it's safe to do so
+then you can use [`serialize_as_number`] with `#[serde(serialize_with)]`. Deserialization accepts
+decimals as either strings or integer numbers. Fractional JSON numbers aren't accepted, since they
+may already have been rounded by the time they're deserialized.
+*/
+
+use core::fmt;
+
+use ::serde::Serializer;
+
+use crate::{
+    binary::{
+        decode_combination_finite,
+        is_finite,
+        is_sign_negative,
+        BinaryBuf,
+    },
+    convert::decimal_to_int,
+    num::Integer,
+    ops::decimal_is_zero,
+    Decimal,
+};
+
+/**
+Serialize a decimal as a number in human-readable formats when it won't lose any information.
+
+A decimal is only serialized as a number if it's an integer with an exponent of zero, like `150`,
+that fits in an `i64` or `u64`. These decimals don't have any trailing fractional zeroes to lose.
+Anything else, like `1.50`, `15e1`, `-0`, infinities, and NaNs, is serialized as a string instead,
+just like the default `Serialize` implementation. In binary formats, decimals are always serialized
+as their little-endian bytes.
+
+This function can be used with `#[serde(serialize_with = "decstr::serde::serialize_as_number")]`.
+*/
+pub fn serialize_as_number<D: Decimal, S: Serializer>(
+    decimal: &D,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    decimal.serialize_as_number(serializer)
+}
+
+pub(crate) fn serialize_decimal_as_number<D: BinaryBuf, S: Serializer>(
+    decimal: &D,
+    serializer: S,
+    display: impl fmt::Display,
+) -> Result<S::Ok, S::Error> {
+    // A decimal can be serialized as a number if it doesn't have a fractional part, or trailing
+    // zeroes that are recorded in its exponent
+    let is_number = is_finite(decimal)
+        && decode_combination_finite(decimal).0.to_i32() == Some(0)
+        && !(is_sign_negative(decimal) && decimal_is_zero(decimal));
+
+    if is_number {
+        if let Ok(i) = decimal_to_int::<D, i64>(decimal) {
+            return serializer.serialize_i64(i);
+        }
+
+        if let Ok(u) = decimal_to_int::<D, u64>(decimal) {
+            return serializer.serialize_u64(u);
+        }
+    }
+
+    serializer.collect_str(&display)
+}
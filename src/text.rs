@@ -10,6 +10,38 @@ The output from the text parser is a `ParsedDecimal` that classifies the number
 to any digits or other features within it. These offsets can be used to convert the number into
 different representations.
 
+A [`DecimalParser`] can be fed a number incrementally, so it can be parsed as it arrives in chunks,
+like from a network stream, without needing to collect it into a string first. The parser implements
+`fmt::Write`, so chunks can be written into it with `write!`. Once all of the input has been written,
+call [`DecimalParser::end`] to get a [`ParsedDecimal`], which can then be converted into any of the
+decimal types, like [`Bitstring64::try_from_parsed`](crate::Bitstring64::try_from_parsed):
+
+```
+use std::fmt::Write;
+
+use decstr::{
+    text::{
+        ArrayTextBuf,
+        DecimalParser,
+    },
+    Bitstring64,
+};
+
+let mut parser = DecimalParser::begin(ArrayTextBuf::<64>::default());
+
+for chunk in ["-12", "3.4", "5e", "-6"] {
+    write!(parser, "{}", chunk).expect("failed to parse chunk");
+}
+
+let parsed = parser.end().expect("failed to parse decimal");
+let decimal = Bitstring64::try_from_parsed(parsed).expect("failed to encode decimal");
+
+assert_eq!("-0.00012345", decimal.to_string());
+```
+
+The digits of the number are buffered into a `TextBuf`. An [`ArrayTextBuf`] can buffer as many
+characters as its size, and will fail to parse numbers that are longer than that.
+
 This module is organized around _kinds_ of numbers.
 */
 
@@ -18,8 +50,9 @@ mod finite;
 mod infinity;
 mod nan;
 
-pub use self::{
-    buf::*,
+pub use self::buf::*;
+
+pub(crate) use self::{
     finite::*,
     infinity::*,
     nan::*,
@@ -30,6 +63,7 @@ use core::{
         self,
         Write,
     },
+    mem,
     ops::Range,
     str,
 };
@@ -63,9 +97,9 @@ The buffer may be the original string parsed, or it could be digits buffered fro
  */
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ParsedFinite<B> {
-    pub finite_buf: B,
-    pub finite_significand: ParsedSignificand,
-    pub finite_exponent: Option<ParsedExponent>,
+    pub(crate) finite_buf: B,
+    pub(crate) finite_significand: ParsedSignificand,
+    pub(crate) finite_exponent: Option<ParsedExponent>,
 }
 
 /**
@@ -75,9 +109,9 @@ The NaN may contain a payload, which is an integer value.
 */
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ParsedNan<B> {
-    pub nan_buf: B,
-    pub nan_header: ParsedNanHeader,
-    pub nan_payload: Option<ParsedSignificand>,
+    pub(crate) nan_buf: B,
+    pub(crate) nan_header: ParsedNanHeader,
+    pub(crate) nan_payload: Option<ParsedSignificand>,
 }
 
 /**
@@ -85,7 +119,7 @@ An infinite value parsed form a formatted string.
 */
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ParsedInfinity {
-    pub is_infinity_negative: bool,
+    pub(crate) is_infinity_negative: bool,
 }
 
 impl Default for ParsedInfinity {
@@ -101,9 +135,9 @@ Offsets and metadata about the significand parsed from a formatted decimal
  */
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ParsedSignificand {
-    pub significand_is_negative: bool,
-    pub significand_range: Range<usize>,
-    pub decimal_point: Option<ParsedDecimalPoint>,
+    pub(crate) significand_is_negative: bool,
+    pub(crate) significand_range: Range<usize>,
+    pub(crate) decimal_point: Option<ParsedDecimalPoint>,
 }
 
 impl Default for ParsedSignificand {
@@ -121,7 +155,7 @@ Offsets and metadata about the significand decimal point parsed from a formatted
  */
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ParsedDecimalPoint {
-    pub decimal_point_range: Range<usize>,
+    pub(crate) decimal_point_range: Range<usize>,
 }
 
 impl Default for ParsedDecimalPoint {
@@ -137,8 +171,8 @@ Offsets and metadata about the exponent parsed from a formatted decimal.
  */
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ParsedExponent {
-    pub exponent_is_negative: bool,
-    pub exponent_range: Range<usize>,
+    pub(crate) exponent_is_negative: bool,
+    pub(crate) exponent_range: Range<usize>,
 }
 
 impl Default for ParsedExponent {
@@ -157,8 +191,8 @@ The header contains information about the NaN outside of its payload.
 */
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ParsedNanHeader {
-    pub is_nan_signaling: bool,
-    pub is_nan_negative: bool,
+    pub(crate) is_nan_signaling: bool,
+    pub(crate) is_nan_negative: bool,
 }
 
 impl Default for ParsedNanHeader {
@@ -182,6 +216,7 @@ enum DecimalParserInner<B> {
         buf: Option<B>,
         is_negative: Option<bool>,
         error: Option<ParseError>,
+        digit_separators: bool,
     },
     Finite(FiniteParser<B>),
     Infinity(InfinityParser<B>),
@@ -189,6 +224,11 @@ enum DecimalParserInner<B> {
 }
 
 impl<'a> DecimalParser<StrTextBuf<'a>> {
+    /**
+    Parse a decimal from a string.
+
+    The string is used as the buffer, so its digits don't need to be copied.
+    */
     pub fn parse_str(input: &'a str) -> Result<ParsedDecimal<StrTextBuf<'a>>, ParseError> {
         let mut parser = DecimalParser::begin(StrTextBuf::new(input));
 
@@ -199,18 +239,74 @@ impl<'a> DecimalParser<StrTextBuf<'a>> {
 }
 
 impl<B: TextWriter> DecimalParser<B> {
+    /**
+    Begin parsing a decimal, buffering its digits into the given buffer.
+
+    Input can be fed to the parser using [`parse_ascii`](#method.parse_ascii), [`parse_fmt`](#method.parse_fmt),
+    or by writing it with `write!`. Once all of the input has been given, call [`end`](#method.end)
+    to get the parsed decimal.
+    */
     pub fn begin(buf: B) -> Self {
         DecimalParser(DecimalParserInner::AtStart {
             buf: Some(buf),
             error: None,
             is_negative: None,
+            digit_separators: false,
         })
     }
 
+    /**
+    Allow `_` to be used as a separator between digits in finite numbers, like `1_000.000_1`.
+
+    This must be called before any input is parsed.
+    */
+    pub fn allow_digit_separators(&mut self) {
+        if let DecimalParserInner::AtStart {
+            ref mut digit_separators,
+            ..
+        } = self.0
+        {
+            *digit_separators = true;
+        }
+    }
+
+    /**
+    Reset the parser back to its initial state so it can parse another number.
+
+    Any partially parsed input, along with its sign and any stashed error, is discarded.
+    When parsing a sequence of numbers, one parser can be reused by resetting it with a new
+    buffer, rather than beginning a new parser each time. Fixed-size buffers like `ArrayTextBuf`
+    are cheap to create, so a parser can be reset with `Default::default()` between numbers.
+    Whether digit separators are allowed is also reset.
+
+    To finish parsing a number and reset the parser in one step, use
+    [`end_and_reset`](#method.end_and_reset).
+    */
+    pub fn reset(&mut self, buf: B) {
+        self.0 = DecimalParserInner::AtStart {
+            buf: Some(buf),
+            error: None,
+            is_negative: None,
+            digit_separators: false,
+        };
+    }
+
+    /**
+    Parse the next chunk of a decimal from some formattable value.
+
+    This method can avoid needing to buffer the chunk as a string upfront.
+    */
     pub fn parse_fmt(&mut self, f: impl fmt::Display) -> Result<(), ParseError> {
         write!(self, "{}", f).map_err(|err| self.unwrap_context(err))
     }
 
+    /**
+    Parse the next chunk of a decimal from some ASCII text.
+
+    The decimal can be split into chunks anywhere, even in the middle of its exponent or the
+    word `inf`. If the chunk isn't a valid continuation of the decimal parsed so far, or there's
+    no room left in the buffer for its digits, then this method will fail.
+    */
     pub fn parse_ascii(&mut self, mut ascii: &[u8]) -> Result<(), ParseError> {
         while !ascii.is_empty() {
             match self.0 {
@@ -221,12 +317,17 @@ impl<B: TextWriter> DecimalParser<B> {
                 DecimalParserInner::AtStart {
                     ref mut is_negative,
                     ref mut buf,
+                    digit_separators,
                     ..
                 } => match ascii[0] {
                     // Finite
                     b'0'..=b'9' => {
                         let mut finite = FiniteParser::begin(buf.take().expect("missing buffer"));
 
+                        if digit_separators {
+                            finite.allow_digit_separators();
+                        }
+
                         match is_negative {
                             Some(false) => finite.significand_is_positive(),
                             Some(true) => finite.significand_is_negative(),
@@ -270,6 +371,20 @@ impl<B: TextWriter> DecimalParser<B> {
 
                         self.0 = DecimalParserInner::Nan(nan);
                     }
+                    // Uncommon: Quiet NaN with an explicit `q`, like `qnan`
+                    b'q' | b'Q' => {
+                        let mut nan = NanParser::begin(buf.take().expect("missing buffer"));
+
+                        match is_negative {
+                            Some(false) => nan.nan_is_positive(b'+'),
+                            Some(true) => nan.nan_is_negative(b'-'),
+                            _ => (),
+                        }
+
+                        nan.nan_is_explicitly_quiet(ascii[0]);
+
+                        self.0 = DecimalParserInner::Nan(nan);
+                    }
                     // Infinity
                     b'i' | b'I' => {
                         let mut inf = InfinityParser::begin(buf.take().expect("missing buffer"));
@@ -308,6 +423,12 @@ impl<B: TextWriter> DecimalParser<B> {
         Ok(())
     }
 
+    /**
+    Finish parsing the decimal.
+
+    If the input parsed so far isn't a complete decimal, like `1.2e` or `-`, then this method
+    will fail.
+    */
     pub fn end(self) -> Result<ParsedDecimal<B>, ParseError> {
         match self.0 {
             DecimalParserInner::Finite(finite) => Ok(ParsedDecimal::Finite(finite.end()?)),
@@ -319,7 +440,46 @@ impl<B: TextWriter> DecimalParser<B> {
         }
     }
 
-    pub fn context(&mut self, err: ParseError) -> fmt::Error {
+    /**
+    Finish parsing the decimal, and reset the parser so it can parse another number.
+
+    This is like [`end`](#method.end), except the parser isn't consumed. It's reset with the
+    given buffer the same way as [`reset`](#method.reset), even if the input parsed so far isn't
+    a complete decimal. That makes it possible to parse a long sequence of numbers with a single
+    parser:
+
+    ```
+    use decstr::{
+        text::{
+            ArrayTextBuf,
+            DecimalParser,
+        },
+        Bitstring64,
+    };
+
+    let mut parser = DecimalParser::begin(ArrayTextBuf::<64>::default());
+
+    let mut decimals = Vec::new();
+    for number in ["1.5", "-42", "inf"] {
+        parser
+            .parse_ascii(number.as_bytes())
+            .expect("failed to parse number");
+
+        let parsed = parser
+            .end_and_reset(ArrayTextBuf::default())
+            .expect("failed to parse decimal");
+
+        decimals.push(Bitstring64::try_from_parsed(parsed).expect("failed to encode decimal"));
+    }
+
+    assert_eq!("-42", decimals[1].to_string());
+    ```
+    */
+    pub fn end_and_reset(&mut self, buf: B) -> Result<ParsedDecimal<B>, ParseError> {
+        mem::replace(self, DecimalParser::begin(buf)).end()
+    }
+
+    pub(crate) fn context(&mut self, err: ParseError) -> fmt::Error {
         match self.0 {
             DecimalParserInner::AtStart { ref mut error, .. } => {
                 *error = Some(err);
@@ -332,7 +492,7 @@ impl<B: TextWriter> DecimalParser<B> {
         }
     }
 
-    pub fn unwrap_context(&mut self, err: fmt::Error) -> ParseError {
+    pub(crate) fn unwrap_context(&mut self, err: fmt::Error) -> ParseError {
         match self.0 {
             DecimalParserInner::AtStart { ref mut error, .. } => {
                 error.take().unwrap_or_else(ParseError::source)
@@ -879,6 +1039,30 @@ mod tests {
         assert_eq!(expected_err, &err.to_string());
     }
 
+    #[test]
+    fn parse_finite_drops_trailing_zeroes_on_buffer_too_small() {
+        let mut buf = FiniteParser::begin(ArrayTextBuf::<4>::default());
+        buf.parse_ascii(b"1.20").expect("failed to parse");
+        buf.parse_ascii(b"0000").expect("failed to parse");
+        let parsed = buf.end().expect("failed to parse");
+        assert_eq!(b"1.20", parsed.finite_buf.get_ascii());
+
+        // Enough space is kept free after the zeroes for the exponent
+        let mut buf = FiniteParser::begin(ArrayTextBuf::<16>::default());
+        buf.parse_ascii(b"1.00000000000000000000e-5")
+            .expect("failed to parse");
+        let parsed = buf.end().expect("failed to parse");
+        assert_eq!(b"1.00000e-5", parsed.finite_buf.get_ascii());
+
+        let mut buf = FiniteParser::begin(ArrayTextBuf::<4>::default());
+        let err = buf.parse_ascii(b"1.20001").unwrap_err();
+        assert_eq!("the buffer is too small", &err.to_string());
+
+        let mut buf = FiniteParser::begin(ArrayTextBuf::<4>::default());
+        let err = buf.parse_ascii(b"12000").unwrap_err();
+        assert_eq!("the buffer is too small", &err.to_string());
+    }
+
     #[test]
     fn parse_finite_invalid() {
         let (input, expected_err) = &("", "unexpected end of input, expected a sign or digit");
@@ -980,6 +1164,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_decimal_reset() {
+        let mut parser = DecimalParser::begin(ArrayTextBuf::<32>::default());
+
+        // Reset a parser that failed part-way through a number
+        parser.parse_ascii(b"-12").unwrap();
+        parser.parse_ascii(b"x").unwrap_err();
+        parser.reset(ArrayTextBuf::default());
+
+        for input in ["-1.5e3", "inf", "snan(12)", "42"] {
+            parser.parse_ascii(input.as_bytes()).unwrap();
+
+            let parsed = parser.end_and_reset(ArrayTextBuf::default()).unwrap();
+
+            let mut fresh = DecimalParser::begin(ArrayTextBuf::<32>::default());
+            fresh.parse_ascii(input.as_bytes()).unwrap();
+
+            assert_eq!(fresh.end().unwrap(), parsed, "{}", input);
+
+            // Resetting a parser with a partial sign discards it
+            parser.parse_ascii(b"-").unwrap();
+            parser.reset(ArrayTextBuf::default());
+        }
+
+        // Ending an incomplete number still resets the parser
+        parser.parse_ascii(b"1.5e").unwrap();
+        parser.end_and_reset(ArrayTextBuf::default()).unwrap_err();
+
+        let mut fresh = DecimalParser::begin(ArrayTextBuf::<32>::default());
+        fresh.parse_ascii(b"7").unwrap();
+
+        parser.parse_ascii(b"7").unwrap();
+        assert_eq!(fresh.end().unwrap(), parser.end().unwrap());
+    }
+
     #[test]
     fn parse_fmt_no_content() {
         struct Empty;
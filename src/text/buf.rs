@@ -1,8 +1,3 @@
-use crate::text::{
-    ParsedExponent,
-    ParsedSignificand,
-};
-
 mod array;
 mod str;
 
@@ -35,20 +30,33 @@ A writer for parsed decimal numbers that can produce text buffers containing dig
 track offsets.
 
 A `TextWriter` is probably also going to be a `TextBuf`, which stashes the written bytes
-to be accessed later.
+to be accessed later. This trait is sealed, so it can't be implemented outside of this library.
+Use [`ArrayTextBuf`] to buffer digits in a fixed-size array, or `VecTextBuf` to buffer them in a
+growable vector, which is available with the `arbitrary-precision` feature.
 */
-pub trait TextWriter {
-    fn remaining_capacity(&self) -> Option<usize>;
-
-    fn begin_significand(&mut self) -> ParsedSignificand;
-    fn advance_significand(&mut self, b: u8);
-    fn push_significand_digit(&mut self, significand: &mut ParsedSignificand, digit: u8);
-    fn push_significand_decimal_point(&mut self, significand: &mut ParsedSignificand);
-    fn significand_is_negative(&mut self, significand: &mut ParsedSignificand);
-    fn significand_is_positive(&mut self, significand: &mut ParsedSignificand);
-
-    fn begin_exponent(&mut self) -> ParsedExponent;
-    fn push_exponent_digit(&mut self, exponent: &mut ParsedExponent, digit: u8);
-    fn exponent_is_negative(&mut self, exponent: &mut ParsedExponent);
-    fn exponent_is_positive(&mut self, exponent: &mut ParsedExponent);
+pub trait TextWriter: private::Writer {}
+
+impl<T: private::Writer> TextWriter for T {}
+
+pub(crate) mod private {
+    use crate::text::{
+        ParsedExponent,
+        ParsedSignificand,
+    };
+
+    pub trait Writer {
+        fn remaining_capacity(&self) -> Option<usize>;
+
+        fn begin_significand(&mut self) -> ParsedSignificand;
+        fn advance_significand(&mut self, b: u8);
+        fn push_significand_digit(&mut self, significand: &mut ParsedSignificand, digit: u8);
+        fn push_significand_decimal_point(&mut self, significand: &mut ParsedSignificand);
+        fn significand_is_negative(&mut self, significand: &mut ParsedSignificand);
+        fn significand_is_positive(&mut self, significand: &mut ParsedSignificand);
+
+        fn begin_exponent(&mut self) -> ParsedExponent;
+        fn push_exponent_digit(&mut self, exponent: &mut ParsedExponent, digit: u8);
+        fn exponent_is_negative(&mut self, exponent: &mut ParsedExponent);
+        fn exponent_is_positive(&mut self, exponent: &mut ParsedExponent);
+    }
 }
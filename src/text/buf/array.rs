@@ -3,11 +3,15 @@ use crate::text::{
     ParsedExponent,
     ParsedSignificand,
     TextBuf,
-    TextWriter,
 };
 
+use super::private::Writer;
+
 /**
-A buffer that splits between the significand and exponent so they can use different buffers.
+A buffer for parsed decimals that stores their text in a fixed-size array.
+
+The buffer can fit up to `N` characters of a decimal, including its sign, decimal point, and
+exponent. Decimals that are longer than that will fail to parse.
  */
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ArrayTextBuf<const N: usize> {
@@ -30,7 +34,7 @@ impl<const N: usize> TextBuf for ArrayTextBuf<N> {
     }
 }
 
-impl<const N: usize> TextWriter for ArrayTextBuf<N> {
+impl<const N: usize> Writer for ArrayTextBuf<N> {
     fn remaining_capacity(&self) -> Option<usize> {
         Some(N - self.len)
     }
@@ -3,9 +3,10 @@ use crate::text::{
     ParsedExponent,
     ParsedSignificand,
     TextBuf,
-    TextWriter,
 };
 
+use super::private::Writer;
+
 /**
 A buffer that already contains an ASCII-coded decimal.
 */
@@ -16,14 +17,14 @@ pub struct StrTextBuf<'a> {
 }
 
 impl<'a> StrTextBuf<'a> {
-    pub fn new(buf: &'a str) -> Self {
+    pub(crate) fn new(buf: &'a str) -> Self {
         let ascii = buf.as_bytes();
 
         StrTextBuf { ascii, index: 0 }
     }
 
     #[cfg(test)]
-    pub fn at_end(buf: &'a str) -> Self {
+    pub(crate) fn at_end(buf: &'a str) -> Self {
         let ascii = buf.as_bytes();
         let index = ascii.len();
 
@@ -37,7 +38,7 @@ impl<'a> TextBuf for StrTextBuf<'a> {
     }
 }
 
-impl<'a> TextWriter for StrTextBuf<'a> {
+impl<'a> Writer for StrTextBuf<'a> {
     fn remaining_capacity(&self) -> Option<usize> {
         None
     }
@@ -3,11 +3,12 @@ use crate::text::{
     ParsedExponent,
     ParsedSignificand,
     TextBuf,
-    TextWriter,
 };
 
+use super::private::Writer;
+
 /**
-A buffer that splits between the significand and exponent so they can use different buffers.
+A buffer for parsed decimals that stores their text in a growable vector.
  */
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VecTextBuf {
@@ -26,7 +27,7 @@ impl TextBuf for VecTextBuf {
     }
 }
 
-impl TextWriter for VecTextBuf {
+impl Writer for VecTextBuf {
     fn remaining_capacity(&self) -> Option<usize> {
         None
     }
@@ -8,26 +8,41 @@ use crate::{
     },
     ParseError,
 };
-use core::fmt::{
-    self,
-    Write,
+use core::{
+    cmp,
+    fmt::{
+        self,
+        Write,
+    },
 };
 
+/**
+The capacity to keep free for the exponent when dropping trailing zeroes.
+
+This is enough for a sign and the digits of the exponent of any fixed-size decimal.
+*/
+const EXPONENT_CAPACITY: usize = 9;
+
 /**
 A parser for a formatted decimal number.
 */
 #[derive(Debug)]
-pub struct FiniteParser<B> {
+pub(crate) struct FiniteParser<B> {
     buf: B,
     error: Option<ParseError>,
     significand: ParsedSignificand,
     exponent: Option<ParsedExponent>,
+    pending_zeroes: usize,
     has_sign: bool,
     has_decimal: bool,
     has_digits: bool,
+    digit_separators: bool,
+    last_was_digit: bool,
+    after_separator: bool,
 }
 
 impl<'a> FiniteParser<StrTextBuf<'a>> {
+    #[cfg(any(feature = "float", test))]
     pub fn parse_str(input: &'a str) -> Result<ParsedFinite<StrTextBuf<'a>>, ParseError> {
         let mut parser = FiniteParser::begin(StrTextBuf::new(input));
 
@@ -42,14 +57,29 @@ impl<B: TextWriter> FiniteParser<B> {
         FiniteParser {
             significand: buf.begin_significand(),
             exponent: None,
+            pending_zeroes: 0,
             buf,
             error: None,
             has_sign: false,
             has_decimal: false,
             has_digits: false,
+            digit_separators: false,
+            last_was_digit: false,
+            after_separator: false,
         }
     }
 
+    /**
+    Allow `_` to be used as a separator between digits in the significand and exponent.
+
+    A separator must appear between two digits, so `1_000` is accepted, but `1_`, `1__0`,
+    and `1_.0` aren't. Separators are skipped, so they're never written to the buffer.
+    */
+    pub fn allow_digit_separators(&mut self) {
+        self.digit_separators = true;
+    }
+
+    #[cfg(feature = "float")]
     pub fn checked_push_significand_digit(&mut self, digit: u8) -> Result<(), ParseError> {
         if self.buf.remaining_capacity() == Some(0) {
             Err(ParseError::buffer_too_small())
@@ -61,11 +91,13 @@ impl<B: TextWriter> FiniteParser<B> {
 
     pub(in crate::text) fn push_significand_digit(&mut self, digit: u8) {
         self.has_digits = true;
+        self.last_was_digit = true;
 
         self.buf
             .push_significand_digit(&mut self.significand, digit)
     }
 
+    #[cfg(feature = "float")]
     pub fn checked_significand_is_negative(&mut self) -> Result<(), ParseError> {
         if self.buf.remaining_capacity() == Some(0) {
             Err(ParseError::buffer_too_small())
@@ -95,6 +127,7 @@ impl<B: TextWriter> FiniteParser<B> {
             .push_significand_decimal_point(&mut self.significand)
     }
 
+    #[cfg(feature = "float")]
     pub fn checked_begin_exponent(&mut self) -> Result<(), ParseError> {
         if self.buf.remaining_capacity() == Some(0) {
             Err(ParseError::buffer_too_small())
@@ -111,6 +144,7 @@ impl<B: TextWriter> FiniteParser<B> {
         self.exponent = Some(self.buf.begin_exponent());
     }
 
+    #[cfg(feature = "float")]
     pub fn parse(buf: B, input: impl fmt::Display) -> Result<ParsedFinite<B>, ParseError> {
         let mut parser = FiniteParser::begin(buf);
 
@@ -120,21 +154,45 @@ impl<B: TextWriter> FiniteParser<B> {
         }
     }
 
+    #[cfg(feature = "float")]
     pub fn parse_fmt(&mut self, f: impl fmt::Display) -> Result<(), ParseError> {
         write!(self, "{}", f).map_err(|err| self.unwrap_context(err))
     }
 
     pub fn parse_ascii(&mut self, mut ascii: &[u8]) -> Result<(), ParseError> {
-        if let Some(remaining_capacity) = self.buf.remaining_capacity() {
-            if remaining_capacity < ascii.len() {
-                return Err(ParseError::buffer_too_small());
-            }
-        }
-
         // If there's no exponent then parse the significand
         // The number may be split across multiple calls to `write_str`
         if self.exponent.is_none() {
             while !ascii.is_empty() {
+                if self.skip_digit_separator(ascii[0])? {
+                    ascii = &ascii[1..];
+                    continue;
+                }
+
+                // If the buffer has a fixed size then zeroes in the fractional part of the
+                // significand aren't written straight away. If they're followed by any other
+                // digit then they're written along with it. If they turn out to be trailing
+                // zeroes then any that don't fit can be dropped instead, since that doesn't
+                // change the value of the number.
+                match ascii[0] {
+                    b'0' if self.has_decimal && self.buf.remaining_capacity().is_some() => {
+                        self.has_digits = true;
+                        self.last_was_digit = true;
+                        self.pending_zeroes += 1;
+
+                        ascii = &ascii[1..];
+                        continue;
+                    }
+                    b'1'..=b'9' => self.push_pending_zeroes()?,
+                    // Keep enough space free after the zeroes to fit the exponent
+                    b'e' | b'E' => self.push_trailing_zeroes(EXPONENT_CAPACITY),
+                    _ => (),
+                }
+
+                if self.buf.remaining_capacity() == Some(0) {
+                    return Err(ParseError::buffer_too_small());
+                }
+
                 match ascii[0] {
                     // Push a digit to the significand
                     b'0'..=b'9' => {
@@ -172,8 +230,19 @@ impl<B: TextWriter> FiniteParser<B> {
         // If there's an exponent then parse its digits
         // The format for the exponent is simpler than the significand
         // It's really just a simple integer
-        if let Some(ref mut exponent) = self.exponent {
+        if self.exponent.is_some() {
             while !ascii.is_empty() {
+                if self.skip_digit_separator(ascii[0])? {
+                    ascii = &ascii[1..];
+                    continue;
+                }
+
+                let exponent = self.exponent.as_mut().expect("missing exponent");
+
+                if self.buf.remaining_capacity() == Some(0) {
+                    return Err(ParseError::buffer_too_small());
+                }
+
                 match ascii[0] {
                     // Push a digit to the exponent
                     b'0'..=b'9' => {
@@ -203,12 +272,18 @@ impl<B: TextWriter> FiniteParser<B> {
         Ok(())
     }
 
-    pub fn end(self) -> Result<ParsedFinite<B>, ParseError> {
+    pub fn end(mut self) -> Result<ParsedFinite<B>, ParseError> {
         debug_assert!(
             self.error.is_none(),
             "attempt to complete a parser with an error context"
         );
 
+        self.push_trailing_zeroes(0);
+
+        if self.after_separator {
+            return Err(ParseError::unexpected_end("any digit"));
+        }
+
         if !self.has_digits {
             return Err(ParseError::unexpected_end(if !self.has_sign {
                 "a sign or digit"
@@ -224,6 +299,83 @@ impl<B: TextWriter> FiniteParser<B> {
         })
     }
 
+    /**
+    Write any zeroes that have been held back, because a non-zero digit follows them.
+
+    The zeroes are significant, so if they don't all fit then this method will fail.
+    */
+    fn push_pending_zeroes(&mut self) -> Result<(), ParseError> {
+        if let Some(remaining_capacity) = self.buf.remaining_capacity() {
+            if remaining_capacity < self.pending_zeroes {
+                return Err(ParseError::buffer_too_small());
+            }
+        }
+
+        while self.pending_zeroes > 0 {
+            self.push_significand_digit(b'0');
+            self.pending_zeroes -= 1;
+        }
+
+        Ok(())
+    }
+
+    /**
+    Write as many zeroes that have been held back as will fit, because they end the significand.
+
+    The zeroes aren't significant, so any that don't fit, leaving `reserved_capacity` free,
+    are dropped.
+    */
+    fn push_trailing_zeroes(&mut self, reserved_capacity: usize) {
+        let fit = match self.buf.remaining_capacity() {
+            Some(remaining_capacity) => cmp::min(
+                self.pending_zeroes,
+                remaining_capacity.saturating_sub(reserved_capacity),
+            ),
+            None => self.pending_zeroes,
+        };
+
+        for _ in 0..fit {
+            self.push_significand_digit(b'0');
+        }
+
+        self.pending_zeroes = 0;
+    }
+
+    /**
+    Check the next character against any digit separators, returning `true` if it should be skipped.
+
+    Separators are only allowed between two digits, so the character before one must be a digit,
+    and so must the character after it.
+    */
+    fn skip_digit_separator(&mut self, c: u8) -> Result<bool, ParseError> {
+        if !self.digit_separators {
+            return Ok(false);
+        }
+
+        match c {
+            b'_' if self.last_was_digit => {
+                self.last_was_digit = false;
+                self.after_separator = true;
+
+                Ok(true)
+            }
+            b'0'..=b'9' => {
+                self.last_was_digit = true;
+                self.after_separator = false;
+
+                Ok(false)
+            }
+            c if c == b'_' || self.after_separator => {
+                Err(ParseError::unexpected_char(c, "any digit"))
+            }
+            _ => {
+                self.last_was_digit = false;
+
+                Ok(false)
+            }
+        }
+    }
+
     pub fn context(&mut self, err: ParseError) -> fmt::Error {
         self.error = Some(err);
         fmt::Error
@@ -14,7 +14,7 @@ use core::{
 };
 
 #[derive(Debug)]
-pub struct InfinityParser<B> {
+pub(crate) struct InfinityParser<B> {
     expecting: &'static [u8],
     infinity: ParsedInfinity,
     buf: B,
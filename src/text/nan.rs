@@ -17,7 +17,7 @@ use core::{
 };
 
 #[derive(Debug)]
-pub struct NanParser<B> {
+pub(crate) struct NanParser<B> {
     buf: NanBuf<B>,
     error: Option<ParseError>,
     header: ParsedNanHeader,
@@ -61,6 +61,10 @@ impl<B: TextWriter> NanParser<B> {
         self.buf.nan_is_signaling(&mut self.header, b)
     }
 
+    pub(in crate::text) fn nan_is_explicitly_quiet(&mut self, b: u8) {
+        self.buf.nan_is_explicitly_quiet(&mut self.header, b)
+    }
+
     pub fn parse_ascii(&mut self, ascii: &[u8]) -> Result<(), ParseError> {
         if let Some(remaining_capacity) = self.buf.remaining_capacity() {
             if remaining_capacity < ascii.len() {
@@ -91,6 +95,10 @@ impl<B: TextWriter> NanParser<B> {
                 b's' | b'S' if self.is_at_start() => {
                     self.buf.nan_is_signaling(&mut self.header, *b);
                 }
+                // Uncommon: Skip over the leading `q` in `qnan`
+                b'q' | b'Q' if self.is_at_start() => {
+                    self.buf.nan_is_explicitly_quiet(&mut self.header, *b);
+                }
                 // Begin the payload
                 b'(' if self.buf.expecting(b'(') => {
                     self.payload = Some(self.buf.begin_payload(*b));
@@ -200,6 +208,14 @@ impl<B: TextWriter> NanBuf<B> {
         self.buf.advance_significand(b);
     }
 
+    pub fn nan_is_explicitly_quiet(&mut self, header: &mut ParsedNanHeader, b: u8) {
+        header.is_nan_signaling = false;
+
+        // The `q` takes the place of the `s` in `snan`
+        self.expecting = &self.expecting[1..];
+        self.buf.advance_significand(b);
+    }
+
     pub fn begin_payload(&mut self, b: u8) -> ParsedSignificand {
         self.expecting = &self.expecting[1..];
         self.buf.advance_significand(b);
@@ -0,0 +1,195 @@
+/*!
+Run General Decimal Arithmetic `.decTest` vectors against the encoding and decoding of decimals.
+
+Each vector either decodes a big-endian hex encoding, like `#A23003D0`, and checks its
+`to_gda_string` matches, or encodes a number with `try_parse_str` and checks its `as_le_bytes`
+match the hex encoding. Vectors that are `Inexact` can't be encoded without rounding, which this
+library doesn't do, so they're expected to fail to parse instead. Vectors that are `Clamped` have
+their exponent clamped, which this library only does in `parse_with_status`, so they're encoded
+with that instead. Vectors that are only `Rounded` drop trailing fractional zeroes, which this
+library only does when streaming a number through `try_parse`, so they're encoded with that.
+
+The vectors in `tests/dectest` use the General Decimal Arithmetic `.decTest` format, but were
+written for this library rather than copied from the official test suite.
+*/
+
+#![cfg(feature = "alloc")]
+
+use decstr::{
+    Bitstring128,
+    Bitstring32,
+    Bitstring64,
+};
+
+#[test]
+fn ds_encode() {
+    run(
+        include_str!("dectest/dsEncode.decTest"),
+        |bytes| {
+            Bitstring32::from_le_bytes(bytes.try_into().expect("invalid encoding width"))
+                .to_gda_string()
+        },
+        |s, conditions| {
+            if conditions.contains(&"Clamped") {
+                Bitstring32::parse_with_status(s).map(|(d, _)| d)
+            } else if conditions.contains(&"Rounded") {
+                Bitstring32::try_parse(s)
+            } else {
+                Bitstring32::try_parse_str(s)
+            }
+            .ok()
+            .map(|d| d.as_le_bytes().to_vec())
+        },
+    );
+}
+
+#[test]
+fn dd_encode() {
+    run(
+        include_str!("dectest/ddEncode.decTest"),
+        |bytes| {
+            Bitstring64::from_le_bytes(bytes.try_into().expect("invalid encoding width"))
+                .to_gda_string()
+        },
+        |s, conditions| {
+            if conditions.contains(&"Clamped") {
+                Bitstring64::parse_with_status(s).map(|(d, _)| d)
+            } else if conditions.contains(&"Rounded") {
+                Bitstring64::try_parse(s)
+            } else {
+                Bitstring64::try_parse_str(s)
+            }
+            .ok()
+            .map(|d| d.as_le_bytes().to_vec())
+        },
+    );
+}
+
+#[test]
+fn dq_encode() {
+    run(
+        include_str!("dectest/dqEncode.decTest"),
+        |bytes| {
+            Bitstring128::from_le_bytes(bytes.try_into().expect("invalid encoding width"))
+                .to_gda_string()
+        },
+        |s, conditions| {
+            if conditions.contains(&"Clamped") {
+                Bitstring128::parse_with_status(s).map(|(d, _)| d)
+            } else if conditions.contains(&"Rounded") {
+                Bitstring128::try_parse(s)
+            } else {
+                Bitstring128::try_parse_str(s)
+            }
+            .ok()
+            .map(|d| d.as_le_bytes().to_vec())
+        },
+    );
+}
+
+/**
+A single test vector, like `dece001 apply #A23003D0 -> -7.50`.
+*/
+struct Case<'a> {
+    id: &'a str,
+    operand: &'a str,
+    result: &'a str,
+    conditions: Vec<&'a str>,
+}
+
+fn run(
+    src: &str,
+    decode: impl Fn(&[u8]) -> String,
+    encode: impl Fn(&str, &[&str]) -> Option<Vec<u8>>,
+) {
+    let mut ran = 0;
+
+    for case in cases(src) {
+        match (hex(case.operand), hex(case.result)) {
+            // #A23003D0 -> -7.50
+            (Some(operand), None) => {
+                assert_eq!(case.result, decode(&operand), "{}", case.id);
+            }
+            // -7.50 -> #A23003D0
+            (None, Some(result)) => {
+                let encoded = encode(&parse_input(case.operand), &case.conditions);
+
+                if case.conditions.contains(&"Inexact") {
+                    assert!(encoded.is_none(), "{} should fail", case.id);
+                } else {
+                    assert_eq!(Some(result), encoded, "{}", case.id);
+                }
+            }
+            _ => panic!("{} isn't an encoding or decoding test", case.id),
+        }
+
+        ran += 1;
+    }
+
+    assert_ne!(0, ran, "no test vectors were found");
+}
+
+/**
+Parse the test vectors from a `.decTest` file, skipping comments and directives.
+*/
+fn cases(src: &str) -> impl Iterator<Item = Case<'_>> {
+    src.lines().filter_map(|line| {
+        let line = match line.find("--") {
+            Some(comment) => &line[..comment],
+            None => line,
+        };
+
+        // Directives, like `precision: 7`, don't affect encoding
+        if line.trim().is_empty() || line.contains(':') {
+            return None;
+        }
+
+        let mut parts = line.split_whitespace();
+
+        let id = parts.next().expect("missing id");
+        assert_eq!(Some("apply"), parts.next(), "{} isn't an `apply` test", id);
+        let operand = parts.next().expect("missing operand");
+        assert_eq!(Some("->"), parts.next(), "{} is missing `->`", id);
+        let result = parts.next().expect("missing result");
+
+        Some(Case {
+            id,
+            operand,
+            result,
+            conditions: parts.collect(),
+        })
+    })
+}
+
+/**
+Parse a big-endian hex encoding, like `#A23003D0`, into little-endian bytes.
+*/
+fn hex(s: &str) -> Option<Vec<u8>> {
+    let s = s.strip_prefix('#')?;
+
+    let mut bytes = (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("invalid hex encoding"))
+        .collect::<Vec<_>>();
+
+    bytes.reverse();
+
+    Some(bytes)
+}
+
+/**
+Convert a number from the test suite into a form this library can parse.
+
+The only difference is that NaN payloads are written directly after the `NaN`, like `NaN123`,
+instead of in parentheses, like `nan(123)`.
+*/
+fn parse_input(s: &str) -> String {
+    let lower = s.to_ascii_lowercase();
+
+    match lower.find("nan") {
+        Some(nan) if lower.len() > nan + 3 => {
+            format!("{}({})", &lower[..nan + 3], &lower[nan + 3..])
+        }
+        _ => lower,
+    }
+}